@@ -149,7 +149,7 @@ pub fn create_benchmark_extrinsic(
         )),
         frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
         frame_system::CheckWeight::<runtime::Runtime>::new(),
-        pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+        runtime::ChargeDaoTokenFee::from(0, None),
     );
 
     let raw_payload = runtime::SignedPayload::from_raw(
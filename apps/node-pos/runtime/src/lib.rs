@@ -42,7 +42,9 @@ pub use frame_support::{
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
+use pallet_transaction_payment::{
+    ChargeTransactionPayment, ConstFeeMultiplier, CurrencyAdapter, Multiplier, OnChargeTransaction,
+};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
@@ -51,21 +53,33 @@ pub use sp_runtime::{Perbill, Permill};
 
 use codec::MaxEncodedLen;
 use daoent_assets::{self as daoent_assets, asset_adaper_in_pallet::BasicCurrencyAdapter};
-use daoent_gov::traits::PledgeTrait;
+use daoent_gov::{traits::PledgeTrait, ReferendumIndex};
 use daoent_primitives::{
     traits::AfterCreate,
-    types::{CallId, DaoAssetId},
+    types::{AssetId, CallId, DaoAssetId},
 };
 use sp_runtime::{DispatchError, RuntimeDebug};
 
 use frame_support::{
     codec::{Decode, Encode},
-    traits::Contains,
+    ensure,
+    traits::{
+        tokens::nonfungibles::{Inspect as NftInspect, Transfer as NftTransfer},
+        Contains, EnsureOriginWithArg,
+    },
     PalletId,
 };
-use orml_traits::parameter_type_with_key;
+use frame_system::EnsureRoot;
+use orml_traits::{
+    parameter_type_with_key, BalanceStatus, LockIdentifier, MultiCurrency, MultiLockableCurrency,
+    MultiReservableCurrency,
+};
 pub use scale_info::TypeInfo;
-use sp_runtime::traits::Zero;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension, Zero},
+    transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
+    DispatchResult,
+};
 
 // end DAO pallet.
 
@@ -283,9 +297,235 @@ parameter_types! {
     pub FeeMultiplier: Multiplier = Multiplier::one();
 }
 
+type NativeFeeAdapter = CurrencyAdapter<Balances, ()>;
+
+/// The DAO of a call whose fee the DAO can sponsor.
+/// 可由 DAO 代付手续费的函数所属的 DAO
+fn sponsorable_dao(call: &RuntimeCall) -> Option<DaoAssetId> {
+    match call {
+        RuntimeCall::DAOGov(
+            daoent_gov::Call::create_propose { dao_id, .. }
+            | daoent_gov::Call::recreate { dao_id, .. }
+            | daoent_gov::Call::start_referendum { dao_id, .. }
+            | daoent_gov::Call::vote_for_referendum { dao_id, .. }
+            | daoent_gov::Call::cancel_vote { dao_id, .. }
+            | daoent_gov::Call::run_proposal { dao_id, .. },
+        ) => Some(*dao_id),
+        RuntimeCall::DAOAsset(daoent_assets::Call::transfer { dao_id, .. }) => Some(*dao_id),
+        RuntimeCall::DAOProject(
+            daoent_project::Call::join_task { dao_id, .. }
+            | daoent_project::Call::leave_task { dao_id, .. }
+            | daoent_project::Call::be_task_review { dao_id, .. }
+            | daoent_project::Call::leave_task_review { dao_id, .. }
+            | daoent_project::Call::start_task { dao_id, .. }
+            | daoent_project::Call::requset_review { dao_id, .. }
+            | daoent_project::Call::task_done { dao_id, .. }
+            | daoent_project::Call::make_review { dao_id, .. },
+        ) => Some(*dao_id),
+        _ => None,
+    }
+}
+
+/// Charges fees in native tokens. The DAO's fee sponsor account pays instead of the sender
+/// when the DAO allowlists the call and the budgets allow, tips are never sponsored.
+/// 手续费由 DAO 代付或由用户支付
+pub struct DaoSponsoredFees;
+
+impl OnChargeTransaction<Runtime> for DaoSponsoredFees {
+    type Balance = Balance;
+    type LiquidityInfo = (
+        Option<(DaoAssetId, AccountId)>,
+        <NativeFeeAdapter as OnChargeTransaction<Runtime>>::LiquidityInfo,
+    );
+
+    fn withdraw_fee(
+        who: &AccountId,
+        call: &RuntimeCall,
+        info: &DispatchInfoOf<RuntimeCall>,
+        fee: Balance,
+        tip: Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let sponsor = sponsorable_dao(call)
+            .filter(|_| tip.is_zero())
+            .and_then(|dao_id| {
+                let call_id = CallId::try_from(call.clone()).ok()?;
+                DAOAsset::fee_sponsor_for(dao_id, who, call_id, fee).map(|s| (dao_id, s))
+            });
+        if let Some((dao_id, sponsor)) = sponsor {
+            // 代付账户余额不足时由用户支付
+            if let Ok(imbalance) = NativeFeeAdapter::withdraw_fee(&sponsor, call, info, fee, tip) {
+                return Ok((Some((dao_id, sponsor)), imbalance));
+            }
+        }
+        NativeFeeAdapter::withdraw_fee(who, call, info, fee, tip).map(|imbalance| (None, imbalance))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &AccountId,
+        dispatch_info: &DispatchInfoOf<RuntimeCall>,
+        post_info: &PostDispatchInfoOf<RuntimeCall>,
+        corrected_fee: Balance,
+        tip: Balance,
+        (sponsor, imbalance): Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let payer = sponsor.as_ref().map(|(_, s)| s).unwrap_or(who);
+        NativeFeeAdapter::correct_and_deposit_fee(
+            payer,
+            dispatch_info,
+            post_info,
+            corrected_fee,
+            tip,
+            imbalance,
+        )?;
+        if let Some((dao_id, _)) = sponsor {
+            DAOAsset::note_sponsored_fee(dao_id, who, corrected_fee);
+        }
+        Ok(())
+    }
+}
+
+/// Pays the transaction fee, in native tokens or, when `dao_id` is set, in the token of that
+/// DAO. DAO tokens are converted at the rate set with `set_fee_rate` and burnt, the native fee
+/// is taken from the DAO's pool B.
+/// 用原生通证或 DAO 通证支付手续费
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ChargeDaoTokenFee {
+    #[codec(compact)]
+    tip: Balance,
+    dao_id: Option<DaoAssetId>,
+}
+
+impl ChargeDaoTokenFee {
+    /// Pay a `tip`, and the fee in the token of `dao_id` if set.
+    pub fn from(tip: Balance, dao_id: Option<DaoAssetId>) -> Self {
+        Self { tip, dao_id }
+    }
+
+    /// Native fee of the transaction, tip included.
+    fn fee(&self, info: &DispatchInfoOf<RuntimeCall>, len: usize) -> Balance {
+        TransactionPayment::compute_fee(len as u32, info, self.tip)
+    }
+}
+
+/// What `ChargeDaoTokenFee` took before dispatch, settled after it.
+/// 手续费预扣信息
+pub enum DaoTokenFeePre {
+    Native(<ChargeTransactionPayment<Runtime> as SignedExtension>::Pre),
+    DaoToken {
+        dao_id: DaoAssetId,
+        who: AccountId,
+        tip: Balance,
+        fee: Balance,
+        paid: Balance,
+    },
+}
+
+impl SignedExtension for ChargeDaoTokenFee {
+    const IDENTIFIER: &'static str = "ChargeDaoTokenFee";
+    type AccountId = AccountId;
+    type Call = RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = DaoTokenFeePre;
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &AccountId,
+        call: &RuntimeCall,
+        info: &DispatchInfoOf<RuntimeCall>,
+        len: usize,
+    ) -> TransactionValidity {
+        match self.dao_id {
+            None => {
+                ChargeTransactionPayment::<Runtime>::from(self.tip).validate(who, call, info, len)
+            }
+            Some(dao_id) => {
+                let fee = self.fee(info, len);
+                DAOAsset::ensure_can_pay_fee_in_dao_token(dao_id, who, fee)
+                    .map_err(|_| InvalidTransaction::Payment)?;
+                Ok(ValidTransaction {
+                    priority: ChargeTransactionPayment::<Runtime>::get_priority(
+                        info, len, self.tip, fee,
+                    ),
+                    ..Default::default()
+                })
+            }
+        }
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &AccountId,
+        call: &RuntimeCall,
+        info: &DispatchInfoOf<RuntimeCall>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        match self.dao_id {
+            None => ChargeTransactionPayment::<Runtime>::from(self.tip)
+                .pre_dispatch(who, call, info, len)
+                .map(DaoTokenFeePre::Native),
+            Some(dao_id) => {
+                let fee = self.fee(info, len);
+                let paid = DAOAsset::pay_fee_in_dao_token(dao_id, who, fee)
+                    .map_err(|_| InvalidTransaction::Payment)?;
+                Ok(DaoTokenFeePre::DaoToken {
+                    dao_id,
+                    who: who.clone(),
+                    tip: self.tip,
+                    fee,
+                    paid,
+                })
+            }
+        }
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        info: &DispatchInfoOf<RuntimeCall>,
+        post_info: &PostDispatchInfoOf<RuntimeCall>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        match pre {
+            Some(DaoTokenFeePre::Native(pre)) => {
+                ChargeTransactionPayment::<Runtime>::post_dispatch(
+                    Some(pre),
+                    info,
+                    post_info,
+                    len,
+                    result,
+                )
+            }
+            Some(DaoTokenFeePre::DaoToken {
+                dao_id,
+                who,
+                tip,
+                fee,
+                paid,
+            }) => {
+                let actual =
+                    TransactionPayment::compute_actual_fee(len as u32, info, post_info, tip);
+                DAOAsset::refund_fee_in_dao_token(dao_id, &who, fee, paid, actual);
+                System::deposit_event(
+                    pallet_transaction_payment::Event::<Runtime>::TransactionFeePaid {
+                        who,
+                        actual_fee: actual.min(fee),
+                        tip,
+                    },
+                );
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+    type OnChargeTransaction = DaoSponsoredFees;
     type OperationalFeeMultiplier = ConstU8<5>;
     type WeightToFee = IdentityFee<Balance>;
     type LengthToFee = IdentityFee<Balance>;
@@ -297,63 +537,295 @@ impl pallet_sudo::Config for Runtime {
     type RuntimeCall = RuntimeCall;
 }
 
+/// Membership NFTs of a DAO are the items of the collection whose id is the DAO id.
+pub type NftItemId = u32;
+
+/// Only the DAO account, or root on its behalf, can create the collection of a DAO.
+/// 只有 DAO 账户或 root 可以创建 DAO 的 NFT 集合
+pub struct EnsureDaoCollection;
+
+impl EnsureOriginWithArg<RuntimeOrigin, DaoAssetId> for EnsureDaoCollection {
+    type Success = AccountId;
+
+    fn try_origin(o: RuntimeOrigin, collection: &DaoAssetId) -> Result<AccountId, RuntimeOrigin> {
+        let dao_account = match DAO::try_get_dao_account_id(*collection) {
+            Ok(dao_account) => dao_account,
+            Err(_) => return Err(o),
+        };
+        if frame_system::ensure_root(o.clone()).is_ok() {
+            return Ok(dao_account);
+        }
+        match frame_system::ensure_signed(o.clone()) {
+            Ok(who) if who == dao_account => Ok(who),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_collection: &DaoAssetId) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+parameter_types! {
+    pub const CollectionDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const ItemDeposit: Balance = EXISTENTIAL_DEPOSIT;
+    pub const MetadataDepositBase: Balance = 10 * EXISTENTIAL_DEPOSIT;
+    pub const MetadataDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_uniques::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = DaoAssetId;
+    type ItemId = NftItemId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = EnsureDaoCollection;
+    type Locker = ();
+    type CollectionDeposit = CollectionDeposit;
+    type ItemDeposit = ItemDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type AttributeDepositBase = MetadataDepositBase;
+    type DepositPerByte = MetadataDepositPerByte;
+    type StringLimit = ConstU32<128>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
 /// DAO Start
 type Amount = i128;
 
+/// Blocks a pledge stays locked after voting, unless the pledge asks for longer.
+pub const VOTE_LOCK_PERIOD: BlockNumber = 100;
+
+/// The lock of time-locked pledges. An account has one such lock per DAO token, as large
+/// as its largest pledge, so the same tokens can back several votes at once.
+pub const GOV_LOCK_ID: LockIdentifier = *b"dgovlock";
+
+/// Set the gov lock of `who` to the largest of its time-locked pledges in `dao_id`.
+fn update_gov_lock(who: &AccountId, dao_id: DaoAssetId) -> Result<(), DispatchError> {
+    let amount = daoent_gov::TimeLocksOf::<Runtime>::iter_prefix_values((who.clone(), dao_id))
+        .max()
+        .unwrap_or_else(Zero::zero);
+    if amount.is_zero() {
+        <DAOAsset as MultiLockableCurrency<AccountId>>::remove_lock(GOV_LOCK_ID, dao_id, who)
+    } else {
+        <DAOAsset as MultiLockableCurrency<AccountId>>::set_lock(GOV_LOCK_ID, dao_id, who, amount)
+    }
+}
+
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, Clone, TypeInfo, Copy, MaxEncodedLen)]
-pub enum Pledge<Balance> {
+pub enum Pledge<Balance, BlockNumber> {
+    /// Reserve the DAO's own token.
+    /// 抵押组织通证
     FungToken(Balance),
+    /// Reserve the chain's native token.
+    /// 抵押链上原生通证
+    NativeToken(Balance),
+    /// Lock the DAO's own token for at least the given number of blocks.
+    /// 锁定组织通证
+    TimeLockedToken(Balance, BlockNumber),
+    /// Escrow a membership NFT, the collection id being the DAO id.
+    /// 抵押组织会员 NFT
+    Nft(NftItemId),
+    /// Reserve another asset owned by the DAO.
+    /// 抵押组织的其他资产
+    Asset(AssetId, Balance),
 }
 
-impl Default for Pledge<Balance> {
+impl Default for Pledge<Balance, BlockNumber> {
     fn default() -> Self {
         Pledge::FungToken(0)
     }
 }
-impl PledgeTrait<Balance, AccountId, DaoAssetId, BlockNumber, DispatchError> for Pledge<Balance> {
+
+impl Pledge<Balance, BlockNumber> {
+    /// 1 token = 1 vote, or 1 account = 1 vote when `vote_model` is 1.
+    fn weight_of(amount: Balance, vote_model: u8) -> Balance {
+        if vote_model == 1 {
+            return 1;
+        }
+        amount
+    }
+}
+
+impl PledgeTrait<Balance, AccountId, DaoAssetId, BlockNumber, DispatchError>
+    for Pledge<Balance, BlockNumber>
+{
     fn try_vote(
         &self,
         who: &AccountId,
         dao_id: &DaoAssetId,
+        referendum_index: ReferendumIndex,
         vote_model: u8,
     ) -> Result<(Balance, BlockNumber), DispatchError> {
-        if cfg!(any(feature = "std", feature = "runtime-benchmarks", test)) {
-            return Ok((Default::default(), Default::default()));
-        }
-
         match self {
             Pledge::FungToken(x) => {
                 DAOAsset::reserve(*dao_id, who.clone(), *x)?;
-                if vote_model == 1 {
-                    // 1 account = 1 vote
-                    let amount = 1;
-                    return Ok((amount, 100));
-                }
-                // 1 token = 1 vote
-                let amount = *x;
-                Ok((amount, 100))
-            } // _ => Err(daoent_gov::Error::<Runtime>::PledgeNotEnough)?,
+                Ok((Self::weight_of(*x, vote_model), VOTE_LOCK_PERIOD))
+            }
+            Pledge::NativeToken(x) => {
+                DAOAsset::reserve(daoent_assets::NATIVE_ASSET_ID, who.clone(), *x)?;
+                Ok((Self::weight_of(*x, vote_model), VOTE_LOCK_PERIOD))
+            }
+            Pledge::TimeLockedToken(x, period) => {
+                // locks overlap, so only the free balance has to cover the pledge
+                ensure!(
+                    <DAOAsset as MultiCurrency<AccountId>>::free_balance(*dao_id, who) >= *x,
+                    daoent_gov::Error::<Runtime>::PledgeNotEnough
+                );
+                daoent_gov::TimeLocksOf::<Runtime>::insert(
+                    (who.clone(), *dao_id),
+                    referendum_index,
+                    *x,
+                );
+                update_gov_lock(who, *dao_id)?;
+                Ok((
+                    Self::weight_of(*x, vote_model),
+                    (*period).max(VOTE_LOCK_PERIOD),
+                ))
+            }
+            Pledge::Nft(item) => {
+                let owner = <Uniques as NftInspect<AccountId>>::owner(dao_id, item)
+                    .ok_or(daoent_gov::Error::<Runtime>::PledgeNotEnough)?;
+                ensure!(&owner == who, daoent_gov::Error::<Runtime>::PledgeNotEnough);
+                <Uniques as NftTransfer<AccountId>>::transfer(
+                    dao_id,
+                    item,
+                    &DAO::dao_pledge(*dao_id),
+                )?;
+                // the DAO sets what a membership is worth
+                let weight = DAOGov::gov_config(*dao_id).nft_vote_weight;
+                Ok((Self::weight_of(weight, vote_model), VOTE_LOCK_PERIOD))
+            }
+            Pledge::Asset(asset_id, x) => {
+                DAOAsset::ensure_dao_asset(*dao_id, *asset_id)?;
+                DAOAsset::reserve(*asset_id, who.clone(), *x)?;
+                Ok((Self::weight_of(*x, vote_model), VOTE_LOCK_PERIOD))
+            }
         }
-        // Err(daoent_gov::Error::<Runtime>::PledgeNotEnough)?
     }
 
-    fn vote_end_do(&self, who: &AccountId, dao_id: &DaoAssetId) -> Result<(), DispatchError> {
-        if cfg!(any(feature = "std", feature = "runtime-benchmarks", test)) {
-            return Ok(());
-        }
+    fn vote_end_do(
+        &self,
+        who: &AccountId,
+        dao_id: &DaoAssetId,
+        referendum_index: ReferendumIndex,
+    ) -> Result<(), DispatchError> {
         match self {
             Pledge::FungToken(x) => {
                 DAOAsset::unreserve(*dao_id, who.clone(), *x)?;
                 Ok(())
             }
+            Pledge::NativeToken(x) => {
+                DAOAsset::unreserve(daoent_assets::NATIVE_ASSET_ID, who.clone(), *x)?;
+                Ok(())
+            }
+            Pledge::TimeLockedToken(..) => {
+                daoent_gov::TimeLocksOf::<Runtime>::remove(
+                    (who.clone(), *dao_id),
+                    referendum_index,
+                );
+                update_gov_lock(who, *dao_id)
+            }
+            Pledge::Nft(item) => <Uniques as NftTransfer<AccountId>>::transfer(dao_id, item, who),
+            Pledge::Asset(asset_id, x) => {
+                DAOAsset::unreserve(*asset_id, who.clone(), *x)?;
+                Ok(())
+            }
         }
-        // Err(daoent_gov::Error::<Runtime>::PledgeNotEnough)?
     }
+
+    fn slash_to(
+        &self,
+        who: &AccountId,
+        dao_id: &DaoAssetId,
+        referendum_index: ReferendumIndex,
+        beneficiary: &AccountId,
+    ) -> Result<(), DispatchError> {
+        match self {
+            Pledge::FungToken(x) => {
+                <DAOAsset as MultiReservableCurrency<AccountId>>::repatriate_reserved(
+                    *dao_id,
+                    who,
+                    beneficiary,
+                    *x,
+                    BalanceStatus::Free,
+                )?;
+                Ok(())
+            }
+            Pledge::NativeToken(x) => {
+                <DAOAsset as MultiReservableCurrency<AccountId>>::repatriate_reserved(
+                    daoent_assets::NATIVE_ASSET_ID,
+                    who,
+                    beneficiary,
+                    *x,
+                    BalanceStatus::Free,
+                )?;
+                Ok(())
+            }
+            Pledge::TimeLockedToken(x, _) => {
+                daoent_gov::TimeLocksOf::<Runtime>::remove(
+                    (who.clone(), *dao_id),
+                    referendum_index,
+                );
+                update_gov_lock(who, *dao_id)?;
+                <DAOAsset as MultiCurrency<AccountId>>::transfer(*dao_id, who, beneficiary, *x)
+            }
+            Pledge::Nft(item) => {
+                <Uniques as NftTransfer<AccountId>>::transfer(dao_id, item, beneficiary)
+            }
+            Pledge::Asset(asset_id, x) => {
+                <DAOAsset as MultiReservableCurrency<AccountId>>::repatriate_reserved(
+                    *asset_id,
+                    who,
+                    beneficiary,
+                    *x,
+                    BalanceStatus::Free,
+                )?;
+                Ok(())
+            }
+        }
+    }
+
+    fn dao_tokens(&self) -> Option<Balance> {
+        match self {
+            Pledge::FungToken(x) | Pledge::TimeLockedToken(x, _) => Some(*x),
+            Pledge::NativeToken(_) | Pledge::Nft(_) | Pledge::Asset(..) => None,
+        }
+    }
+}
+
+parameter_types! {
+    pub const MaxPublicPropsLimit: u32 = 100;
+    pub const MinVotingPeriod: BlockNumber = 10 * MINUTES;
+    pub const MaxGovPeriod: BlockNumber = 90 * DAYS;
+    pub const ArchiveAge: BlockNumber = 30 * DAYS;
+    pub const MaxPrunePerBlock: u32 = 10;
+    pub const MaxReapVotes: u32 = 32;
+    pub const ReapReward: Balance = 1_000_000;
+    pub const VetoCoolOff: BlockNumber = 7 * DAYS;
+    pub MaxProposalWeight: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
 }
 
 impl daoent_gov::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type Pledge = Pledge<Balance>;
+    type Pledge = Pledge<Balance, BlockNumber>;
+    type MaxPublicPropsLimit = MaxPublicPropsLimit;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxGovPeriod = MaxGovPeriod;
+    type ArchiveAge = ArchiveAge;
+    type MaxPrunePerBlock = MaxPrunePerBlock;
+    type MaxReapVotes = MaxReapVotes;
+    type ReapReward = ReapReward;
+    type VetoCoolOff = VetoCoolOff;
+    type UnixTime = Timestamp;
+    type MillisecsPerBlock = ConstU64<MILLISECS_PER_BLOCK>;
+    type MaxProposalWeight = MaxProposalWeight;
     type WeightInfo = ();
 }
 
@@ -375,7 +847,7 @@ impl Contains<AccountId> for DustRemovalWhitelist {
 
 impl orml_tokens::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type CurrencyHooks = ();
+    type CurrencyHooks = daoent_assets::HolderHooks<Runtime>;
     type Balance = Balance;
     type Amount = Amount;
     type CurrencyId = DaoAssetId;
@@ -422,6 +894,27 @@ impl TryFrom<RuntimeCall> for CallId {
                 daoent_assets::Call::burn { .. } => Ok(204 as CallId),
                 daoent_assets::Call::transfer { .. } => Ok(205 as CallId),
                 daoent_assets::Call::join_request { .. } => Ok(206 as CallId),
+                daoent_assets::Call::set_join_pricing { .. } => Ok(207 as CallId),
+                daoent_assets::Call::redeem { .. } => Ok(208 as CallId),
+                daoent_assets::Call::set_redeem_rule { .. } => Ok(209 as CallId),
+                daoent_assets::Call::create_vesting { .. } => Ok(210 as CallId),
+                daoent_assets::Call::claim { .. } => Ok(211 as CallId),
+                daoent_assets::Call::revoke_vesting { .. } => Ok(212 as CallId),
+                daoent_assets::Call::issue_asset { .. } => Ok(213 as CallId),
+                daoent_assets::Call::set_fee_sponsor { .. } => Ok(214 as CallId),
+                daoent_assets::Call::fund_fee_sponsor { .. } => Ok(215 as CallId),
+                daoent_assets::Call::set_fee_rate { .. } => Ok(216 as CallId),
+                daoent_assets::Call::approve { .. } => Ok(217 as CallId),
+                daoent_assets::Call::transfer_from { .. } => Ok(218 as CallId),
+                daoent_assets::Call::increase_allowance { .. } => Ok(219 as CallId),
+                daoent_assets::Call::decrease_allowance { .. } => Ok(220 as CallId),
+                daoent_assets::Call::set_transfer_policy { .. } => Ok(221 as CallId),
+                daoent_assets::Call::set_transfer_allowlist { .. } => Ok(222 as CallId),
+                daoent_assets::Call::mint { .. } => Ok(223 as CallId),
+                daoent_assets::Call::set_max_supply { .. } => Ok(224 as CallId),
+                daoent_assets::Call::set_inflation { .. } => Ok(225 as CallId),
+                daoent_assets::Call::distribute { .. } => Ok(226 as CallId),
+                daoent_assets::Call::claim_distribution { .. } => Ok(227 as CallId),
                 _ => Err(()),
             },
             RuntimeCall::DAOGuild(func) => match func {
@@ -438,13 +931,15 @@ impl TryFrom<RuntimeCall> for CallId {
                 daoent_gov::Call::run_proposal { .. } => Ok(406 as CallId),
                 daoent_gov::Call::unlock { .. } => Ok(407 as CallId),
                 daoent_gov::Call::set_min_vote_weight_for_every_call { .. } => Ok(408 as CallId),
-                daoent_gov::Call::set_max_public_props { .. } => Ok(409 as CallId),
-                daoent_gov::Call::set_launch_period { .. } => Ok(410 as CallId),
-                daoent_gov::Call::set_minimum_deposit { .. } => Ok(411 as CallId),
-                daoent_gov::Call::set_voting_period { .. } => Ok(412 as CallId),
-                daoent_gov::Call::set_rerserve_period { .. } => Ok(413 as CallId),
-                daoent_gov::Call::set_runment_period { .. } => Ok(414 as CallId),
-                daoent_gov::Call::update_vote_model { .. } => Ok(415 as CallId),
+                daoent_gov::Call::set_emergency_guild { .. } => Ok(416 as CallId),
+                daoent_gov::Call::set_gov_config { .. } => Ok(417 as CallId),
+                daoent_gov::Call::set_optimistic_rule { .. } => Ok(418 as CallId),
+                daoent_gov::Call::set_commit_reveal_rule { .. } => Ok(419 as CallId),
+                daoent_gov::Call::set_time_periods { .. } => Ok(420 as CallId),
+                daoent_gov::Call::set_bicameral_rule { .. } => Ok(421 as CallId),
+                daoent_gov::Call::set_vote_reward_rule { .. } => Ok(422 as CallId),
+                daoent_gov::Call::fund_reward_pool { .. } => Ok(423 as CallId),
+                daoent_gov::Call::set_proposer_limits { .. } => Ok(424 as CallId),
                 _ => Err(()),
             },
             RuntimeCall::DAOProject(func) => match func {
@@ -462,6 +957,17 @@ impl TryFrom<RuntimeCall> for CallId {
                 daoent_project::Call::make_review { .. } => Ok(512 as CallId),
                 _ => Err(()),
             },
+            RuntimeCall::DAOElections(func) => match func {
+                daoent_elections::Call::set_election_config { .. } => Ok(601 as CallId),
+                daoent_elections::Call::remove_member { .. } => Ok(602 as CallId),
+                _ => Err(()),
+            },
+            RuntimeCall::Uniques(func) => match func {
+                pallet_uniques::Call::create { .. } => Ok(701 as CallId),
+                pallet_uniques::Call::mint { .. } => Ok(702 as CallId),
+                pallet_uniques::Call::burn { .. } => Ok(703 as CallId),
+                _ => Err(()),
+            },
             _ => Err(()),
         }
     }
@@ -508,6 +1014,25 @@ impl daoent_guild::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const ElectionsMaxCandidates: u32 = 64;
+    pub const ElectionsMaxVoters: u32 = 10_000;
+    pub const ElectionsMaxVotesPerVoter: u32 = 16;
+    pub const MaxElectionsPerBlock: u32 = 4;
+    pub const MinTermDuration: BlockNumber = DAYS;
+}
+
+impl daoent_elections::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectiveMembers = DAOGov;
+    type MaxCandidates = ElectionsMaxCandidates;
+    type MaxVoters = ElectionsMaxVoters;
+    type MaxVotesPerVoter = ElectionsMaxVotesPerVoter;
+    type MaxElectionsPerBlock = MaxElectionsPerBlock;
+    type MinTermDuration = MinTermDuration;
+    type WeightInfo = ();
+}
+
 // DAO END
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -526,6 +1051,7 @@ construct_runtime!(
         Balances: pallet_balances,
         TransactionPayment: pallet_transaction_payment,
         Sudo: pallet_sudo,
+        Uniques: pallet_uniques,
 
         // token
         Tokens: orml_tokens,
@@ -536,9 +1062,13 @@ construct_runtime!(
         DAOGuild: daoent_guild,
         DAOProject: daoent_project,
         DAOGov: daoent_gov,
+        DAOElections: daoent_elections,
     }
 );
 
+#[cfg(test)]
+mod tests;
+
 /// The address format for describing accounts.
 pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
 /// Block header type as expected by this runtime.
@@ -554,7 +1084,7 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    ChargeDaoTokenFee,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -734,6 +1264,45 @@ impl_runtime_apis! {
         }
     }
 
+    impl daoent_gov_runtime_api::DaoGovApi<Block, AccountId, Balance, BlockNumber, Hash, Pledge<Balance, BlockNumber>>
+        for Runtime
+    {
+        fn open_proposals(
+            dao_id: DaoAssetId,
+        ) -> Vec<daoent_gov::ProposalSummary<AccountId, Balance, Hash>> {
+            DAOGov::open_proposals(dao_id)
+        }
+        fn referendum_status(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        ) -> Option<daoent_gov::ReferendumSummary<Balance, BlockNumber>> {
+            DAOGov::referendum_summary(dao_id, index)
+        }
+        fn voter_votes(
+            who: AccountId,
+        ) -> daoent_gov::VoterSummary<Pledge<Balance, BlockNumber>, Balance, BlockNumber> {
+            DAOGov::voter_summary(who)
+        }
+        fn dry_run(
+            dao_id: DaoAssetId,
+            target: daoent_gov::DryRunTarget,
+        ) -> Option<daoent_gov::DryRunResult<AccountId, Balance>> {
+            DAOGov::dry_run(dao_id, target)
+        }
+    }
+
+    impl daoent_assets_runtime_api::DaoAssetsApi<Block, Balance> for Runtime {
+        fn quote_join(dao_id: DaoAssetId, deposit: Balance) -> Option<Balance> {
+            DAOAsset::quote_join(dao_id, deposit)
+        }
+        fn quote_redeem(dao_id: DaoAssetId, amount: Balance) -> Option<(Balance, Balance)> {
+            DAOAsset::quote_redeem(dao_id, amount)
+        }
+        fn headroom(asset_id: AssetId) -> Option<Balance> {
+            DAOAsset::headroom(asset_id)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -38,3 +38,440 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod pledge {
+    use super::*;
+    use frame_support::assert_ok;
+    use orml_traits::MultiReservableCurrency;
+
+    pub(super) const DAO_ID: DaoAssetId = 1;
+
+    pub(super) fn alice() -> AccountId {
+        AccountId::new([1u8; 32])
+    }
+
+    pub(super) fn bob() -> AccountId {
+        AccountId::new([2u8; 32])
+    }
+
+    pub(super) fn new_test_ext() -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Runtime>()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    pub(super) fn create_dao() {
+        assert_ok!(DAO::create_dao(
+            RuntimeOrigin::signed(alice()),
+            vec![1; 4],
+            vec![]
+        ));
+        assert_ok!(DAOAsset::create_asset(
+            RuntimeOrigin::signed(alice()),
+            DAO_ID,
+            daoent_assets::DaoAssetMeta {
+                name: "TestA".as_bytes().to_vec(),
+                symbol: "TA".as_bytes().to_vec(),
+                decimals: 10,
+            },
+            10_000,
+            99,
+        ));
+    }
+
+    fn reserved(asset_id: DaoAssetId, who: &AccountId) -> Balance {
+        <DAOAsset as MultiReservableCurrency<AccountId>>::reserved_balance(asset_id, who)
+    }
+
+    #[test]
+    fn fung_token_pledge_reserves_dao_token() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let pledge = Pledge::FungToken(100);
+
+            assert_eq!(
+                pledge.try_vote(&alice(), &DAO_ID, 0, 0),
+                Ok((100, VOTE_LOCK_PERIOD))
+            );
+            assert_eq!(reserved(DAO_ID, &alice()), 100);
+
+            assert_ok!(pledge.vote_end_do(&alice(), &DAO_ID, 0));
+            assert_eq!(reserved(DAO_ID, &alice()), 0);
+
+            // 1 account = 1 vote
+            assert_eq!(
+                pledge.try_vote(&alice(), &DAO_ID, 1, 1),
+                Ok((1, VOTE_LOCK_PERIOD))
+            );
+            // bob holds no DAO token
+            assert!(pledge.try_vote(&bob(), &DAO_ID, 0, 0).is_err());
+        });
+    }
+
+    #[test]
+    fn asset_pledge_reserves_dao_owned_asset() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            assert_ok!(DAOAsset::issue_asset(
+                RuntimeOrigin::signed(DAO::dao_account(DAO_ID)),
+                DAO_ID,
+                daoent_assets::DaoAssetMeta {
+                    name: "Reputation".as_bytes().to_vec(),
+                    symbol: "REP".as_bytes().to_vec(),
+                    decimals: 2,
+                },
+                1_000,
+            ));
+            let asset_id = daoent_assets::NextAssetId::<Runtime>::get() - 1;
+            assert!(DAOAsset::is_dao_asset(DAO_ID, asset_id));
+            assert_ok!(DAOAsset::try_transfer(
+                asset_id,
+                DAO::dao_asset(DAO_ID),
+                alice(),
+                500
+            ));
+
+            let pledge = Pledge::Asset(asset_id, 200);
+            assert_eq!(
+                pledge.try_vote(&alice(), &DAO_ID, 0, 0),
+                Ok((200, VOTE_LOCK_PERIOD))
+            );
+            assert_eq!(reserved(asset_id, &alice()), 200);
+            assert_ok!(pledge.vote_end_do(&alice(), &DAO_ID, 0));
+            assert_eq!(reserved(asset_id, &alice()), 0);
+
+            // the asset must belong to the DAO
+            assert!(Pledge::Asset(DAO_ID + 1, 1)
+                .try_vote(&alice(), &DAO_ID, 0, 0)
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn native_token_pledge_reserves_balances() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let native = daoent_assets::NATIVE_ASSET_ID;
+            let pledge = Pledge::NativeToken(5_000);
+
+            assert_eq!(
+                pledge.try_vote(&bob(), &DAO_ID, 0, 0),
+                Ok((5_000, VOTE_LOCK_PERIOD))
+            );
+            assert_eq!(reserved(native, &bob()), 5_000);
+            assert_eq!(reserved(DAO_ID, &bob()), 0);
+
+            assert_ok!(pledge.vote_end_do(&bob(), &DAO_ID, 0));
+            assert_eq!(reserved(native, &bob()), 0);
+        });
+    }
+
+    #[test]
+    fn time_locked_pledges_share_one_lock() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let pledge = Pledge::TimeLockedToken(6_000, 1_000);
+
+            assert_eq!(pledge.try_vote(&alice(), &DAO_ID, 0, 0), Ok((6_000, 1_000)));
+            // the same tokens may back a vote in another referendum
+            assert_eq!(pledge.try_vote(&alice(), &DAO_ID, 1, 0), Ok((6_000, 1_000)));
+            assert_eq!(Tokens::accounts(alice(), DAO_ID).frozen, 6_000);
+            assert_eq!(reserved(DAO_ID, &alice()), 0);
+            assert!(<DAOAsset as MultiCurrency<AccountId>>::transfer(
+                DAO_ID,
+                &alice(),
+                &bob(),
+                5_000
+            )
+            .is_err());
+
+            // a short period falls back to the default lock period
+            assert_eq!(
+                Pledge::TimeLockedToken(10, 1).try_vote(&alice(), &DAO_ID, 2, 0),
+                Ok((10, VOTE_LOCK_PERIOD))
+            );
+
+            assert_ok!(pledge.vote_end_do(&alice(), &DAO_ID, 0));
+            assert_eq!(Tokens::accounts(alice(), DAO_ID).frozen, 6_000);
+            assert_ok!(pledge.vote_end_do(&alice(), &DAO_ID, 1));
+            assert_ok!(Pledge::TimeLockedToken(10, 1).vote_end_do(&alice(), &DAO_ID, 2));
+            assert_eq!(Tokens::accounts(alice(), DAO_ID).frozen, 0);
+            assert_ok!(<DAOAsset as MultiCurrency<AccountId>>::transfer(
+                DAO_ID,
+                &alice(),
+                &bob(),
+                5_000
+            ));
+
+            // more than the free balance can not be locked
+            assert!(Pledge::TimeLockedToken(5_001, 0)
+                .try_vote(&alice(), &DAO_ID, 3, 0)
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn time_locked_pledges_are_not_limited_by_max_locks() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let votes = MaxLocks::get() + 10;
+
+            for index in 0..votes {
+                assert_ok!(Pledge::TimeLockedToken(100 + index as Balance, 0).try_vote(
+                    &alice(),
+                    &DAO_ID,
+                    index,
+                    0
+                ));
+            }
+            assert_eq!(Tokens::locks(alice(), DAO_ID).len(), 1);
+            assert_eq!(
+                Tokens::accounts(alice(), DAO_ID).frozen,
+                100 + votes as Balance - 1
+            );
+
+            // the lock falls to the largest pledge still voting
+            assert_ok!(
+                Pledge::TimeLockedToken(100 + votes as Balance - 1, 0).vote_end_do(
+                    &alice(),
+                    &DAO_ID,
+                    votes - 1
+                )
+            );
+            assert_eq!(
+                Tokens::accounts(alice(), DAO_ID).frozen,
+                100 + votes as Balance - 2
+            );
+        });
+    }
+
+    #[test]
+    fn only_dao_token_pledges_hold_dao_tokens() {
+        assert_eq!(Pledge::FungToken(100).dao_tokens(), Some(100));
+        assert_eq!(Pledge::TimeLockedToken(100, 10).dao_tokens(), Some(100));
+        assert_eq!(Pledge::NativeToken(100).dao_tokens(), None);
+        assert_eq!(Pledge::Nft(1).dao_tokens(), None);
+        assert_eq!(Pledge::Asset(DAO_ID + 100, 100).dao_tokens(), None);
+    }
+
+    #[test]
+    fn nft_pledge_escrows_membership_item() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let dao_account = DAO::dao_account(DAO_ID);
+            let _ = <Balances as frame_support::traits::Currency<AccountId>>::deposit_creating(
+                &dao_account,
+                1_000_000,
+            );
+            // only the DAO account can create the collection of the DAO
+            assert!(
+                Uniques::create(RuntimeOrigin::signed(alice()), DAO_ID, alice().into()).is_err()
+            );
+            assert_ok!(Uniques::create(
+                RuntimeOrigin::signed(dao_account.clone()),
+                DAO_ID,
+                dao_account.clone().into()
+            ));
+            assert_ok!(Uniques::mint(
+                RuntimeOrigin::signed(dao_account),
+                DAO_ID,
+                7,
+                alice().into()
+            ));
+            let pledge = Pledge::Nft(7);
+
+            assert!(pledge.try_vote(&bob(), &DAO_ID, 0, 0).is_err());
+            daoent_gov::GovConfigOf::<Runtime>::mutate(DAO_ID, |c| c.nft_vote_weight = 50);
+            assert_eq!(
+                pledge.try_vote(&alice(), &DAO_ID, 0, 0),
+                Ok((50, VOTE_LOCK_PERIOD))
+            );
+            assert_eq!(
+                <Uniques as NftInspect<AccountId>>::owner(&DAO_ID, &7),
+                Some(DAO::dao_pledge(DAO_ID))
+            );
+
+            assert_ok!(pledge.vote_end_do(&alice(), &DAO_ID, 0));
+            assert_eq!(
+                <Uniques as NftInspect<AccountId>>::owner(&DAO_ID, &7),
+                Some(alice())
+            );
+
+            // 1 account = 1 vote
+            assert_eq!(
+                pledge.try_vote(&alice(), &DAO_ID, 1, 1),
+                Ok((1, VOTE_LOCK_PERIOD))
+            );
+        });
+    }
+
+    #[test]
+    fn gov_vote_reserves_through_pledge() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let proposal =
+                RuntimeCall::DAOGov(daoent_gov::Call::set_min_vote_weight_for_every_call {
+                    dao_id: DAO_ID,
+                    call_id: 0,
+                    min_vote_weight: 100,
+                });
+            assert_ok!(DAOGov::create_propose(
+                RuntimeOrigin::signed(alice()),
+                DAO_ID,
+                daoent_gov::MemmberData::GLOBAL,
+                Box::new(proposal),
+                0
+            ));
+            System::set_block_number(2);
+            assert_ok!(DAOGov::start_referendum(
+                RuntimeOrigin::signed(alice()),
+                DAO_ID,
+                0
+            ));
+
+            assert_ok!(DAOGov::vote_for_referendum(
+                RuntimeOrigin::signed(alice()),
+                DAO_ID,
+                0,
+                Pledge::FungToken(300),
+                daoent_gov::Opinion::YES,
+            ));
+            assert_eq!(reserved(DAO_ID, &alice()), 300);
+
+            assert_ok!(DAOGov::cancel_vote(
+                RuntimeOrigin::signed(alice()),
+                DAO_ID,
+                0
+            ));
+            assert_eq!(reserved(DAO_ID, &alice()), 0);
+        });
+    }
+}
+
+#[cfg(test)]
+mod council {
+    use super::pledge::{alice, bob, create_dao, new_test_ext, DAO_ID};
+    use super::*;
+    use frame_support::{assert_ok, traits::Hooks};
+
+    #[test]
+    fn elected_council_holds_emergency_powers() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let dao_account = DAO::dao_account(DAO_ID);
+            assert_ok!(DAOGov::set_emergency_guild(
+                RuntimeOrigin::signed(dao_account.clone()),
+                DAO_ID,
+                daoent_gov::COUNCIL_GUILD,
+                1
+            ));
+            assert_ok!(DAO::try_add_member(DAO_ID, bob()));
+            assert_ok!(DAOAsset::try_transfer(DAO_ID, alice(), bob(), 1_000));
+            assert_ok!(DAOElections::set_election_config(
+                RuntimeOrigin::signed(dao_account),
+                DAO_ID,
+                daoent_elections::ElectionConfig {
+                    desired_members: 1,
+                    desired_runners_up: 0,
+                    term_duration: DAYS,
+                    candidacy_bond: 10,
+                }
+            ));
+            assert_ok!(DAOElections::submit_candidacy(
+                RuntimeOrigin::signed(bob()),
+                DAO_ID
+            ));
+            assert_ok!(DAOElections::vote(
+                RuntimeOrigin::signed(alice()),
+                DAO_ID,
+                vec![bob()],
+                100
+            ));
+
+            let at = DAOElections::next_election(DAO_ID).unwrap();
+            System::set_block_number(at);
+            DAOElections::on_initialize(at);
+
+            assert_eq!(DAOGov::council_of(DAO_ID).into_inner(), vec![bob()]);
+            // the core team guild is left alone
+            assert_eq!(
+                daoent_dao::GuildMembers::<Runtime>::get(DAO_ID, 0).into_inner(),
+                vec![alice()]
+            );
+            assert!(DAOGov::check_auth_for_emergency(DAO_ID, alice()).is_err());
+            assert_ok!(DAOGov::check_auth_for_emergency(DAO_ID, bob()));
+        });
+    }
+}
+
+#[cfg(test)]
+mod fee {
+    use super::pledge::{alice, create_dao, new_test_ext, DAO_ID};
+    use super::*;
+    use frame_support::{
+        assert_ok,
+        dispatch::{DispatchInfo, PostDispatchInfo},
+    };
+    use orml_traits::MultiCurrency;
+
+    fn tokens(who: &AccountId) -> Balance {
+        <DAOAsset as MultiCurrency<AccountId>>::free_balance(DAO_ID, who)
+    }
+
+    #[test]
+    fn dao_token_fee_validate_is_side_effect_free() {
+        new_test_ext().execute_with(|| {
+            create_dao();
+            let pool = DAO::dao_asset(DAO_ID);
+            assert_ok!(<DAOAsset as MultiCurrency<AccountId>>::deposit(
+                daoent_assets::NATIVE_ASSET_ID,
+                &pool,
+                1_000_000_000_000_000,
+            ));
+            let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+            let info = DispatchInfo::default();
+            let ext = ChargeDaoTokenFee::from(0, Some(DAO_ID));
+
+            // DAO 需先开启 DAO 通证支付手续费
+            assert!(ext.validate(&alice(), &call, &info, 0).is_err());
+            assert_ok!(DAOAsset::set_fee_rate(
+                RuntimeOrigin::signed(DAO::dao_account(DAO_ID)),
+                DAO_ID,
+                daoent_assets::FeeRate::Nav
+            ));
+            let before = (tokens(&alice()), Balances::free_balance(&pool));
+
+            assert_ok!(ext.validate(&alice(), &call, &info, 0));
+            assert_eq!((tokens(&alice()), Balances::free_balance(&pool)), before);
+
+            let pre = ext.pre_dispatch(&alice(), &call, &info, 0).unwrap();
+            assert!(tokens(&alice()) < before.0);
+            assert!(Balances::free_balance(&pool) < before.1);
+
+            assert_ok!(ChargeDaoTokenFee::post_dispatch(
+                Some(pre),
+                &info,
+                &PostDispatchInfo::default(),
+                0,
+                &Ok(()),
+            ));
+            assert!(System::events().iter().any(|r| matches!(
+                &r.event,
+                RuntimeEvent::TransactionPayment(
+                    pallet_transaction_payment::Event::TransactionFeePaid { who, .. }
+                ) if *who == alice()
+            )));
+        });
+    }
+}
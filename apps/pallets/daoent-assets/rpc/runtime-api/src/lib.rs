@@ -0,0 +1,30 @@
+//! Runtime API definition for the assets pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use daoent_primitives::types::{AssetId, DaoAssetId};
+
+sp_api::decl_runtime_apis! {
+    /// DAO asset queries for front-ends.
+    /// DAO 资产查询接口
+    pub trait DaoAssetsApi<Balance> where
+        Balance: Codec,
+    {
+        /// DAO tokens `join_request` would mint for a native deposit, `None` if the DAO
+        /// can not price it.
+        /// 查询加入 DAO 可获得的通证数量
+        fn quote_join(dao_id: DaoAssetId, deposit: Balance) -> Option<Balance>;
+
+        /// Native tokens paid and the exit fee kept when redeeming DAO tokens.
+        /// 查询赎回 DAO 通证可获得的原生通证与退出费
+        fn quote_redeem(dao_id: DaoAssetId, amount: Balance) -> Option<(Balance, Balance)>;
+
+        /// Tokens that can still be minted under the supply cap of an asset, `None` if
+        /// uncapped.
+        /// 查询距离供应上限的剩余额度
+        fn headroom(asset_id: AssetId) -> Option<Balance>;
+    }
+}
@@ -0,0 +1,122 @@
+//! RPC interface for the assets pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use daoent_primitives::types::{AssetId, DaoAssetId};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use daoent_assets_runtime_api::DaoAssetsApi as DaoAssetsRuntimeApi;
+
+#[rpc(client, server)]
+pub trait DaoAssetsApi<BlockHash, Balance> {
+    /// DAO tokens `join_request` would mint for a native deposit.
+    #[method(name = "daoAssets_quoteJoin")]
+    fn quote_join(
+        &self,
+        dao_id: DaoAssetId,
+        deposit: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Balance>>;
+
+    /// Native payout and exit fee for redeeming DAO tokens.
+    #[method(name = "daoAssets_quoteRedeem")]
+    fn quote_redeem(
+        &self,
+        dao_id: DaoAssetId,
+        amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(Balance, Balance)>>;
+
+    /// Tokens that can still be minted under the supply cap of an asset.
+    #[method(name = "daoAssets_headroom")]
+    fn headroom(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<Option<Balance>>;
+}
+
+/// Provides RPC methods to query DAO assets.
+pub struct DaoAssets<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> DaoAssets<C, P> {
+    /// Creates a new instance of the DaoAssets Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        message,
+        Some(format!("{:?}", e)),
+    ))
+    .into()
+}
+
+impl<C, Block, Balance> DaoAssetsApiServer<<Block as BlockT>::Hash, Balance> for DaoAssets<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DaoAssetsRuntimeApi<Block, Balance>,
+    Balance: Codec,
+{
+    fn quote_join(
+        &self,
+        dao_id: DaoAssetId,
+        deposit: Balance,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.quote_join(&at, dao_id, deposit)
+            .map_err(|e| runtime_error("Unable to quote the deposit.", e))
+    }
+
+    fn quote_redeem(
+        &self,
+        dao_id: DaoAssetId,
+        amount: Balance,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<(Balance, Balance)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.quote_redeem(&at, dao_id, amount)
+            .map_err(|e| runtime_error("Unable to quote the redemption.", e))
+    }
+
+    fn headroom(&self, asset_id: AssetId, at: Option<Block::Hash>) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.headroom(&at, asset_id)
+            .map_err(|e| runtime_error("Unable to query the headroom.", e))
+    }
+}
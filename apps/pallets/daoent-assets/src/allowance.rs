@@ -0,0 +1,57 @@
+use super::*;
+
+/// DAO tokens a spender may transfer on behalf of an owner.
+/// 授权额度
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct Allowance<Balance, BlockNumber> {
+    /// Tokens left to spend.
+    /// 剩余额度
+    pub amount: Balance,
+    /// The block the allowance expires at, `None` for never.
+    /// 过期区块
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<Balance, BlockNumber: PartialOrd> Allowance<Balance, BlockNumber> {
+    /// Whether the allowance can still be spent at `now`.
+    /// 是否仍然有效
+    pub fn is_live(&self, now: BlockNumber) -> bool {
+        self.expires_at.as_ref().map_or(true, |e| now < *e)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The allowance of `spender` over the tokens of `owner`, if any and not expired.
+    /// 获取有效的授权额度
+    pub fn allowance(
+        dao_id: DaoAssetId,
+        owner: &T::AccountId,
+        spender: &T::AccountId,
+    ) -> Option<Allowance<BalanceOf<T>, T::BlockNumber>> {
+        Allowances::<T>::get(dao_id, (owner, spender))
+            .filter(|a| a.is_live(frame_system::Pallet::<T>::block_number()))
+    }
+
+    /// Store an allowance, removing it once nothing is left, and emit `Approval`.
+    /// 保存授权额度
+    pub(crate) fn set_allowance(
+        dao_id: DaoAssetId,
+        owner: T::AccountId,
+        spender: T::AccountId,
+        allowance: Allowance<BalanceOf<T>, T::BlockNumber>,
+    ) {
+        if allowance.amount.is_zero() {
+            Allowances::<T>::remove(dao_id, (&owner, &spender));
+        } else {
+            Allowances::<T>::insert(dao_id, (&owner, &spender), allowance);
+        }
+        Self::deposit_event(Event::Approval {
+            dao_id,
+            owner,
+            spender,
+            amount: allowance.amount,
+            expires_at: allowance.expires_at,
+        });
+    }
+}
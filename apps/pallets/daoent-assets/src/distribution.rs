@@ -0,0 +1,197 @@
+use super::*;
+use orml_traits::{
+    currency::{MutationHooks, OnDeposit, OnTransfer},
+    Happened,
+};
+use sp_runtime::{FixedPointNumber, SaturatedConversion};
+
+impl<T: Config> Pallet<T> {
+    /// DAO tokens sharing a distribution: the issuance not held by the DAO's own accounts.
+    /// 参与分红的 DAO 通证数量
+    pub fn distribution_shares(dao_id: DaoAssetId) -> BalanceOf<T> {
+        daoent_dao::Pallet::<T>::dao_accounts(dao_id).iter().fold(
+            <Self as MultiCurrency<T::AccountId>>::total_issuance(dao_id),
+            |shares, account| {
+                shares.saturating_sub(<Self as MultiCurrency<T::AccountId>>::total_balance(
+                    dao_id, account,
+                ))
+            },
+        )
+    }
+
+    /// Rewards in `reward_asset` that `who` can claim from the distributions of `dao_id`.
+    /// 查询可领取的分红
+    pub fn pending_distribution(
+        dao_id: DaoAssetId,
+        reward_asset: DaoAssetId,
+        who: &T::AccountId,
+    ) -> BalanceOf<T> {
+        let acc = RewardPerShare::<T>::get(dao_id, reward_asset);
+        let (last, owed) = HolderRewards::<T>::get((dao_id, reward_asset), who);
+        owed.saturating_add(Self::accrued(dao_id, who, acc, last))
+    }
+
+    /// Rewards earned by the DAO tokens of `who` while the accumulator grew from `last` to `acc`.
+    fn accrued(
+        dao_id: DaoAssetId,
+        who: &T::AccountId,
+        acc: FixedU128,
+        last: FixedU128,
+    ) -> BalanceOf<T> {
+        if acc <= last || daoent_dao::Pallet::<T>::is_dao_account(dao_id, who) {
+            return Zero::zero();
+        }
+        let balance = <Self as MultiCurrency<T::AccountId>>::total_balance(dao_id, who);
+        acc.saturating_sub(last)
+            .saturating_mul_int(balance.saturated_into::<u128>())
+            .saturated_into()
+    }
+
+    /// Book the rewards `who` earned so far, before the DAO tokens of `who` change. Transfers
+    /// and deposits are settled by `SettleDistributions`, the other balance changes by the
+    /// `MultiCurrency` implementation of this pallet.
+    /// 在余额变化前结算分红
+    pub(crate) fn settle_distributions(asset_id: DaoAssetId, who: &T::AccountId) {
+        if asset_id == NATIVE_ASSET_ID {
+            return;
+        }
+        for reward_asset in [NATIVE_ASSET_ID, asset_id] {
+            let acc = RewardPerShare::<T>::get(asset_id, reward_asset);
+            if acc.is_zero() {
+                continue;
+            }
+            HolderRewards::<T>::mutate((asset_id, reward_asset), who, |(last, owed)| {
+                if *last != acc {
+                    *owed = owed.saturating_add(Self::accrued(asset_id, who, acc, *last));
+                    *last = acc;
+                }
+            });
+        }
+    }
+
+    /// Share `amount` of `reward_asset` from the DAO account among the current holders.
+    /// 按持有比例分配
+    pub(crate) fn do_distribute(
+        dao_id: DaoAssetId,
+        from: &T::AccountId,
+        reward_asset: DaoAssetId,
+        amount: BalanceOf<T>,
+    ) -> result::Result<FixedU128, DispatchError> {
+        ensure!(
+            reward_asset == NATIVE_ASSET_ID || reward_asset == dao_id,
+            Error::<T>::InvalidRewardAsset
+        );
+        let shares = Self::distribution_shares(dao_id);
+        ensure!(!shares.is_zero(), Error::<T>::NoHolders);
+        let per_share = FixedU128::checked_from_rational(
+            amount.saturated_into::<u128>(),
+            shares.saturated_into::<u128>(),
+        )
+        .filter(|p| !p.is_zero())
+        .ok_or(Error::<T>::DistributionTooSmall)?;
+
+        <Self as MultiCurrency<T::AccountId>>::transfer(
+            reward_asset,
+            from,
+            &daoent_dao::Pallet::<T>::dao_distribution(dao_id),
+            amount,
+        )?;
+        RewardPerShare::<T>::mutate(dao_id, reward_asset, |acc| {
+            *acc = acc.saturating_add(per_share)
+        });
+
+        Ok(per_share)
+    }
+
+    /// Pay `who` the rewards it earned from the distributions of `dao_id`.
+    /// 领取分红
+    pub(crate) fn do_claim_distribution(
+        dao_id: DaoAssetId,
+        reward_asset: DaoAssetId,
+        who: &T::AccountId,
+    ) -> result::Result<BalanceOf<T>, DispatchError> {
+        Self::settle_distributions(dao_id, who);
+        let amount = HolderRewards::<T>::mutate((dao_id, reward_asset), who, |(_, owed)| {
+            sp_std::mem::take(owed)
+        });
+        ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+        <Self as MultiCurrency<T::AccountId>>::transfer(
+            reward_asset,
+            &daoent_dao::Pallet::<T>::dao_distribution(dao_id),
+            who,
+            amount,
+        )?;
+
+        Ok(amount)
+    }
+}
+
+/// Keeps `UsersNumber` in step with the token accounts of every asset, enforces the
+/// transfer policy of each asset and settles distributions before DAO tokens move.
+/// 统计资产的持有人数，检查转账策略并结算分红
+pub struct HolderHooks<T>(marker::PhantomData<T>);
+
+/// Settles the distributions of the holders before their DAO tokens change, whether the
+/// change comes through this pallet or the tokens pallet.
+/// 在 orml-tokens 转账及存入前结算分红
+pub struct SettleDistributions<T>(marker::PhantomData<T>);
+
+impl<T: Config, Balance> OnTransfer<T::AccountId, DaoAssetId, Balance> for SettleDistributions<T> {
+    fn on_transfer(
+        asset_id: DaoAssetId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        _amount: Balance,
+    ) -> DispatchResult {
+        Pallet::<T>::settle_distributions(asset_id, from);
+        Pallet::<T>::settle_distributions(asset_id, to);
+        Ok(())
+    }
+}
+
+impl<T: Config, Balance> OnDeposit<T::AccountId, DaoAssetId, Balance> for SettleDistributions<T> {
+    fn on_deposit(asset_id: DaoAssetId, who: &T::AccountId, _amount: Balance) -> DispatchResult {
+        Pallet::<T>::settle_distributions(asset_id, who);
+        Ok(())
+    }
+}
+
+/// 新增持有人
+pub struct OnNewHolder<T>(marker::PhantomData<T>);
+
+impl<T: Config> Happened<(T::AccountId, DaoAssetId)> for OnNewHolder<T> {
+    fn happened((who, asset_id): &(T::AccountId, DaoAssetId)) {
+        UsersNumber::<T>::mutate(asset_id, |n| *n = n.saturating_add(1));
+        // 新账户只参与此后的分红
+        if *asset_id == NATIVE_ASSET_ID {
+            return;
+        }
+        for reward_asset in [NATIVE_ASSET_ID, *asset_id] {
+            let acc = RewardPerShare::<T>::get(asset_id, reward_asset);
+            if !acc.is_zero() {
+                HolderRewards::<T>::mutate((asset_id, reward_asset), who, |(last, _)| *last = acc);
+            }
+        }
+    }
+}
+
+/// 减少持有人
+pub struct OnKilledHolder<T>(marker::PhantomData<T>);
+
+impl<T: Config> Happened<(T::AccountId, DaoAssetId)> for OnKilledHolder<T> {
+    fn happened((_, asset_id): &(T::AccountId, DaoAssetId)) {
+        UsersNumber::<T>::mutate(asset_id, |n| *n = n.saturating_sub(1));
+    }
+}
+
+impl<T: Config, Balance: Copy> MutationHooks<T::AccountId, DaoAssetId, Balance> for HolderHooks<T> {
+    type OnDust = ();
+    type OnSlash = ();
+    type PreDeposit = SettleDistributions<T>;
+    type PostDeposit = ();
+    type PreTransfer = (CheckTransferPolicy<T>, SettleDistributions<T>);
+    type PostTransfer = ();
+    type OnNewTokenAccount = OnNewHolder<T>;
+    type OnKilledTokenAccount = OnKilledHolder<T>;
+}
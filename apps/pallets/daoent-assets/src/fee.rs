@@ -0,0 +1,142 @@
+use super::*;
+use sp_core::U256;
+use sp_runtime::{FixedPointNumber, SaturatedConversion};
+
+/// How fees paid in a DAO token are converted from native tokens.
+/// DAO 通证支付手续费的汇率
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum FeeRate {
+    /// Fees can not be paid in the DAO token.
+    /// 不允许用 DAO 通证支付手续费
+    Disabled,
+    /// The native balance of pool B per DAO token.
+    /// 按净值兑换
+    Nav,
+    /// Native tokens per DAO token, both in their smallest units.
+    /// 固定价格
+    Fixed(FixedU128),
+}
+
+impl Default for FeeRate {
+    fn default() -> Self {
+        FeeRate::Disabled
+    }
+}
+
+/// `value * numerator / denominator`, rounded up when `ceil`.
+fn mul_div(value: u128, numerator: u128, denominator: u128, ceil: bool) -> Option<u128> {
+    if denominator.is_zero() {
+        return None;
+    }
+    let product = U256::from(value).checked_mul(U256::from(numerator))?;
+    let mut quotient = product / U256::from(denominator);
+    if ceil && !(product % U256::from(denominator)).is_zero() {
+        quotient = quotient.checked_add(U256::one())?;
+    }
+    u128::try_from(quotient).ok()
+}
+
+impl<T: Config> Pallet<T> {
+    /// DAO tokens paying a native `fee` right now, rounded up in favour of the DAO.
+    /// 查询支付 `fee` 原生通证手续费所需的 DAO 通证
+    pub fn quote_fee(dao_id: DaoAssetId, fee: BalanceOf<T>) -> Option<BalanceOf<T>> {
+        if !Self::is_exists_metadata(dao_id) || dao_id == NATIVE_ASSET_ID {
+            return None;
+        }
+        let (native, tokens) = match FeeRateOf::<T>::get(dao_id) {
+            FeeRate::Disabled => return None,
+            FeeRate::Nav => (
+                <Self as MultiCurrency<T::AccountId>>::total_balance(
+                    NATIVE_ASSET_ID,
+                    &daoent_dao::Pallet::<T>::dao_asset(dao_id),
+                )
+                .saturated_into::<u128>(),
+                <Self as MultiCurrency<T::AccountId>>::total_issuance(dao_id)
+                    .saturated_into::<u128>(),
+            ),
+            FeeRate::Fixed(price) => (price.into_inner(), FixedU128::DIV),
+        };
+        let amount = mul_div(fee.saturated_into(), tokens, native, true)?;
+        BalanceOf::<T>::try_from(amount).ok()
+    }
+
+    /// Check, without changing any state, that `who` can pay a native `fee` in the token of
+    /// `dao_id`. Returns the DAO tokens it would take.
+    /// 检查能否用 DAO 通证支付手续费，不修改状态
+    pub fn ensure_can_pay_fee_in_dao_token(
+        dao_id: DaoAssetId,
+        who: &T::AccountId,
+        fee: BalanceOf<T>,
+    ) -> result::Result<BalanceOf<T>, DispatchError> {
+        let amount = Self::quote_fee(dao_id, fee).ok_or(Error::<T>::FeeUnpayable)?;
+        let pool = daoent_dao::Pallet::<T>::dao_asset(dao_id);
+
+        ensure!(
+            <Self as MultiCurrency<T::AccountId>>::free_balance(dao_id, who) >= amount,
+            Error::<T>::BalanceTooLow
+        );
+        <Self as MultiCurrency<T::AccountId>>::ensure_can_withdraw(dao_id, who, amount)?;
+        <Self as MultiCurrency<T::AccountId>>::ensure_can_withdraw(NATIVE_ASSET_ID, &pool, fee)?;
+
+        Ok(amount)
+    }
+
+    /// Burn the DAO tokens for a native `fee` from `who`, and burn the fee from the native
+    /// tokens of pool B, so the NAV of the DAO token does not drop. Returns the DAO tokens burnt.
+    /// 用 DAO 通证支付手续费，销毁用户的 DAO 通证，原生通证从 B 池扣除
+    pub fn pay_fee_in_dao_token(
+        dao_id: DaoAssetId,
+        who: &T::AccountId,
+        fee: BalanceOf<T>,
+    ) -> result::Result<BalanceOf<T>, DispatchError> {
+        let amount = Self::ensure_can_pay_fee_in_dao_token(dao_id, who, fee)?;
+        let pool = daoent_dao::Pallet::<T>::dao_asset(dao_id);
+
+        <Self as MultiCurrency<T::AccountId>>::withdraw(dao_id, who, amount)?;
+        <Self as MultiCurrency<T::AccountId>>::withdraw(NATIVE_ASSET_ID, &pool, fee)?;
+
+        Ok(amount)
+    }
+
+    /// Settle a fee taken with `pay_fee_in_dao_token`. Only `actual` of the native `fee` was
+    /// used, the rest goes back to pool B and the matching DAO tokens of `paid` are minted back
+    /// to `who`.
+    /// 结算 DAO 通证支付的手续费，退还多收的部分
+    pub fn refund_fee_in_dao_token(
+        dao_id: DaoAssetId,
+        who: &T::AccountId,
+        fee: BalanceOf<T>,
+        paid: BalanceOf<T>,
+        actual: BalanceOf<T>,
+    ) {
+        let pool = daoent_dao::Pallet::<T>::dao_asset(dao_id);
+        let refund = fee.saturating_sub(actual);
+        let back = mul_div(
+            paid.saturated_into(),
+            refund.saturated_into(),
+            fee.saturated_into(),
+            false,
+        )
+        .and_then(|b| BalanceOf::<T>::try_from(b).ok())
+        .unwrap_or_else(Zero::zero);
+
+        if !refund.is_zero() {
+            let _ = <Self as MultiCurrency<T::AccountId>>::deposit(NATIVE_ASSET_ID, &pool, refund);
+        }
+        let back = if back.is_zero()
+            || <Self as MultiCurrency<T::AccountId>>::deposit(dao_id, who, back).is_err()
+        {
+            Zero::zero()
+        } else {
+            back
+        };
+
+        Self::deposit_event(Event::FeePaidInDaoToken {
+            dao_id,
+            who: who.clone(),
+            fee: fee.saturating_sub(refund),
+            amount: paid.saturating_sub(back),
+        });
+    }
+}
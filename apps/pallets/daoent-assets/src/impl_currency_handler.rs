@@ -61,6 +61,7 @@ impl<T: Config>
             DaoAssetInfo {
                 owner: user.clone(),
                 metadata,
+                max_supply: None,
             },
         );
         Self::deposit_event(Event::CreateAsset(user, asset_id, amount));
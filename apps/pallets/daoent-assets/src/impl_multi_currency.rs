@@ -91,6 +91,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
         if asset_id == NATIVE_ASSET_ID {
             T::NativeAsset::deposit(who, amount)?;
         } else {
+            Self::ensure_within_cap(asset_id, amount)?;
             <T as pallet::Config>::MultiAsset::deposit(asset_id, who, amount)?;
         }
 
@@ -109,6 +110,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
         if asset_id == NATIVE_ASSET_ID {
             T::NativeAsset::withdraw(who, amount)?;
         } else {
+            Self::settle_distributions(asset_id, who);
             <T as pallet::Config>::MultiAsset::withdraw(asset_id, who, amount)?;
         }
         Self::deposit_event(Event::Withdrawn(asset_id, who.clone(), amount));
@@ -131,6 +133,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
         if asset_id == NATIVE_ASSET_ID {
             T::NativeAsset::slash(who, amount)
         } else {
+            Self::settle_distributions(asset_id, who);
             <T as pallet::Config>::MultiAsset::slash(asset_id, who, amount)
         }
     }
@@ -147,6 +150,7 @@ impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
         if asset_id == NATIVE_ASSET_ID {
             T::NativeAsset::update_balance(who, by_amount)?;
         } else {
+            Self::settle_distributions(asset_id, who);
             <T as pallet::Config>::MultiAsset::update_balance(asset_id, who, by_amount)?;
         }
         Self::deposit_event(Event::BalanceUpdated(asset_id, who.clone(), by_amount));
@@ -213,6 +217,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
         if asset_id == NATIVE_ASSET_ID {
             T::NativeAsset::slash_reserved(who, value)
         } else {
+            Self::settle_distributions(asset_id, who);
             <T as pallet::Config>::MultiAsset::slash_reserved(asset_id, who, value)
         }
     }
@@ -259,6 +264,8 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
         if asset_id == NATIVE_ASSET_ID {
             T::NativeAsset::repatriate_reserved(slashed, beneficiary, value, status)
         } else {
+            Self::settle_distributions(asset_id, slashed);
+            Self::settle_distributions(asset_id, beneficiary);
             <T as pallet::Config>::MultiAsset::repatriate_reserved(
                 asset_id,
                 slashed,
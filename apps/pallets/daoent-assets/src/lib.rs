@@ -22,12 +22,15 @@ use orml_traits::{
 use scale_info::TypeInfo;
 
 use daoent_dao::{self as dao};
-use daoent_primitives::types::DaoAssetId;
+use daoent_primitives::types::{AssetId, CallId, DaoAssetId};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-    traits::{CheckedSub, MaybeSerializeDeserialize, StaticLookup, Zero},
-    RuntimeDebug,
+    traits::{
+        AtLeast32BitUnsigned, CheckedMul, CheckedSub, MaybeSerializeDeserialize, Saturating,
+        StaticLookup, Zero,
+    },
+    FixedU128, Perbill, RuntimeDebug,
 };
 use sp_std::vec::Vec;
 use sp_std::{
@@ -40,6 +43,21 @@ pub mod asset_adaper_in_pallet;
 mod asset_in_pallet;
 mod impl_currency_handler;
 mod impl_multi_currency;
+pub mod migrations;
+mod pricing;
+pub use pricing::{JoinPricing, MAX_CURVE_STEPS};
+mod sponsor;
+pub use sponsor::SponsorRule;
+mod fee;
+pub use fee::FeeRate;
+mod allowance;
+pub use allowance::Allowance;
+mod policy;
+pub use policy::{CheckTransferPolicy, TransferPolicy};
+mod mint;
+pub use mint::InflationRule;
+mod distribution;
+pub use distribution::{HolderHooks, OnKilledHolder, OnNewHolder, SettleDistributions};
 
 pub use pallet::*;
 
@@ -57,6 +75,10 @@ use traits::CurrenciesHandler;
 
 pub const NATIVE_ASSET_ID: DaoAssetId = 0;
 
+/// Lock on DAO tokens that have not vested yet.
+/// 未解锁的归属通证的锁
+pub const VESTING_ID: LockIdentifier = *b"daovest ";
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
 pub struct DaoAssetMeta {
@@ -73,9 +95,75 @@ pub struct DaoAssetMeta {
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
-pub struct DaoAssetInfo<AccountId, DaoAssetMeta> {
+pub struct DaoAssetInfo<AccountId, DaoAssetMeta, Balance> {
     pub owner: AccountId,
     pub metadata: DaoAssetMeta,
+    /// Hard cap on the total issuance, `None` for no cap.
+    /// 供应上限
+    pub max_supply: Option<Balance>,
+}
+
+/// Limits on redeeming DAO tokens for the native tokens in pool B.
+/// 赎回规则
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct RedeemRule<Balance, BlockNumber> {
+    /// Share of the payout kept in pool B.
+    /// 退出费
+    pub exit_fee: Perbill,
+    /// Length of an epoch in blocks.
+    /// 周期长度
+    pub epoch: BlockNumber,
+    /// Redemptions are open for the first `window` blocks of every epoch, zero for the whole
+    /// epoch.
+    /// 每个周期开放赎回的区块数
+    pub window: BlockNumber,
+    /// Most native tokens paid out in an epoch, zero for no cap.
+    /// 每个周期最多赎回的原生通证
+    pub epoch_cap: Balance,
+}
+
+/// DAO tokens that unlock `per_period` every `period` blocks from `start`, nothing unlocks
+/// before `start + cliff`.
+/// 通证归属计划
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct VestingSchedule<Balance, BlockNumber> {
+    /// 开始区块
+    pub start: BlockNumber,
+    /// 悬崖期
+    pub cliff: BlockNumber,
+    /// 每期区块数
+    pub period: BlockNumber,
+    /// 期数
+    pub period_count: u32,
+    /// 每期解锁数量
+    pub per_period: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy, BlockNumber: AtLeast32BitUnsigned + Copy>
+    VestingSchedule<Balance, BlockNumber>
+{
+    /// Total amount of the schedule.
+    /// 总数量
+    pub fn total(&self) -> Option<Balance> {
+        self.per_period.checked_mul(&self.period_count.into())
+    }
+
+    /// Amount still locked at `now`.
+    /// 当前仍锁定的数量
+    pub fn locked_at(&self, now: BlockNumber) -> Balance {
+        let total = self.total().unwrap_or_else(Zero::zero);
+        if now < self.start.saturating_add(self.cliff) {
+            return total;
+        }
+        let passed = (now - self.start) / self.period;
+        let passed: u32 = passed
+            .min(self.period_count.into())
+            .try_into()
+            .unwrap_or(self.period_count);
+        total.saturating_sub(self.per_period.saturating_mul(passed.into()))
+    }
 }
 
 #[frame_support::pallet]
@@ -139,6 +227,30 @@ pub mod pallet {
         DepositTooLow,
         DepositNotZero,
         DepositRateError,
+        InvalidPricing,
+        QuoteUnavailable,
+        SlippageExceeded,
+        InvalidRedeemRule,
+        RedeemClosed,
+        RedeemCapExceeded,
+        InvalidVesting,
+        VestingExists,
+        NoVesting,
+        NotDaoAsset,
+        InvalidSponsorRule,
+        InvalidFeeRate,
+        FeeUnpayable,
+        InvalidExpiry,
+        AllowanceExpired,
+        AllowanceExceeded,
+        TransferRestricted,
+        SupplyCapExceeded,
+        InvalidSupplyCap,
+        InvalidInflation,
+        InvalidRewardAsset,
+        NoHolders,
+        DistributionTooSmall,
+        NothingToClaim,
     }
 
     #[pallet::event]
@@ -163,13 +275,136 @@ pub mod pallet {
             dao_id: DaoAssetId,
             existenial_deposit: BalanceOf<T>,
         },
+        SetJoinPricing {
+            dao_id: DaoAssetId,
+            pricing: Option<JoinPricing<BalanceOf<T>>>,
+        },
+        Joined {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            deposit: BalanceOf<T>,
+            shares: BalanceOf<T>,
+        },
+        SetRedeemRule {
+            dao_id: DaoAssetId,
+            rule: Option<RedeemRule<BalanceOf<T>, T::BlockNumber>>,
+        },
+        Redeemed {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            payout: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+        VestingCreated {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            schedule: VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+        },
+        VestingClaimed {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            locked: BalanceOf<T>,
+        },
+        VestingRevoked {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            unvested: BalanceOf<T>,
+        },
+        AssetIssued {
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            amount: BalanceOf<T>,
+        },
+        SetFeeSponsor {
+            dao_id: DaoAssetId,
+            rule: Option<SponsorRule<BalanceOf<T>, T::BlockNumber>>,
+        },
+        FeeSponsorFunded {
+            dao_id: DaoAssetId,
+            amount: BalanceOf<T>,
+        },
+        FeeSponsored {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            fee: BalanceOf<T>,
+        },
+        SetFeeRate {
+            dao_id: DaoAssetId,
+            rate: FeeRate,
+        },
+        FeePaidInDaoToken {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            fee: BalanceOf<T>,
+            amount: BalanceOf<T>,
+        },
+        Approval {
+            dao_id: DaoAssetId,
+            owner: T::AccountId,
+            spender: T::AccountId,
+            amount: BalanceOf<T>,
+            expires_at: Option<T::BlockNumber>,
+        },
+        TransferredFrom {
+            dao_id: DaoAssetId,
+            owner: T::AccountId,
+            spender: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SetTransferPolicy {
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            policy: TransferPolicy,
+        },
+        SetTransferAllowlist {
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            who: T::AccountId,
+            allowed: bool,
+        },
+        Minted {
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SetMaxSupply {
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            max_supply: BalanceOf<T>,
+        },
+        SetInflation {
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            rule: Option<InflationRule<BalanceOf<T>, T::BlockNumber>>,
+        },
+        Distributed {
+            dao_id: DaoAssetId,
+            reward_asset: DaoAssetId,
+            amount: BalanceOf<T>,
+            per_share: FixedU128,
+        },
+        DistributionClaimed {
+            dao_id: DaoAssetId,
+            reward_asset: DaoAssetId,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::storage]
     #[pallet::getter(fn asset_info)]
-    pub type DaoAssetsInfo<T: Config> =
-        StorageMap<_, Blake2_128Concat, DaoAssetId, DaoAssetInfo<T::AccountId, DaoAssetMeta>>;
-
+    pub type DaoAssetsInfo<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        DaoAssetId,
+        DaoAssetInfo<T::AccountId, DaoAssetMeta, BalanceOf<T>>,
+    >;
+
+    /// Number of accounts holding an asset, kept by `HolderHooks`.
+    /// 资产的持有人数
     #[pallet::storage]
     #[pallet::getter(fn users_number)]
     pub type UsersNumber<T: Config> = StorageMap<_, Identity, DaoAssetId, u32, ValueQuery>;
@@ -179,12 +414,183 @@ pub mod pallet {
     pub type ExistentDeposits<T: Config> =
         StorageMap<_, Identity, DaoAssetId, BalanceOf<T>, ValueQuery>;
 
+    /// How `join_request` prices DAO tokens, `Nav` when not set.
+    /// 加入 DAO 的定价方式
+    #[pallet::storage]
+    #[pallet::getter(fn join_pricing)]
+    pub type JoinPricingOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, JoinPricing<BalanceOf<T>>>;
+
+    /// Redemption limits of each DAO, redemptions are free and always open when not set.
+    /// 赎回规则
+    #[pallet::storage]
+    #[pallet::getter(fn redeem_rule)]
+    pub type RedeemRuleOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, RedeemRule<BalanceOf<T>, T::BlockNumber>>;
+
+    /// Native tokens redeemed in the current epoch. [epoch, paid]
+    /// 当前周期已赎回的原生通证
+    #[pallet::storage]
+    #[pallet::getter(fn redeemed_in_epoch)]
+    pub type RedeemedInEpoch<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, (T::BlockNumber, BalanceOf<T>), ValueQuery>;
+
+    /// Vesting schedule of each account.
+    /// 账户的归属计划
+    #[pallet::storage]
+    #[pallet::getter(fn vesting)]
+    pub type Vesting<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+    >;
+
+    /// The DAO owning each asset.
+    /// 资产所属的 DAO
+    #[pallet::storage]
+    #[pallet::getter(fn asset_owner)]
+    pub type AssetOwner<T: Config> = StorageMap<_, Identity, AssetId, DaoAssetId>;
+
+    /// Assets owned by each DAO.
+    /// DAO 拥有的资产
+    #[pallet::storage]
+    #[pallet::getter(fn assets_of)]
+    pub type AssetsOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, AssetId, (), OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultForMaxCreatableId<T: Config>() -> AssetId {
+        T::MaxCreatableId::get()
+    }
+
+    /// The next id of an additional asset, ids below `MaxCreatableId` belong to DAO tokens.
+    /// 下一个附加资产的ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_asset_id)]
+    pub type NextAssetId<T: Config> =
+        StorageValue<_, AssetId, ValueQuery, DefaultForMaxCreatableId<T>>;
+
+    /// Which calls the DAO pays the fees of, and the budgets.
+    /// 手续费代付规则
+    #[pallet::storage]
+    #[pallet::getter(fn fee_sponsor)]
+    pub type SponsorRuleOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, SponsorRule<BalanceOf<T>, T::BlockNumber>>;
+
+    /// Fees sponsored by a DAO in the current period. [period, spent]
+    /// 当前周期 DAO 已代付的手续费
+    #[pallet::storage]
+    #[pallet::getter(fn sponsored_in_period)]
+    pub type SponsoredInPeriod<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, (T::BlockNumber, BalanceOf<T>), ValueQuery>;
+
+    /// Fees sponsored for a member in the current period. [period, spent]
+    /// 当前周期为成员代付的手续费
+    #[pallet::storage]
+    #[pallet::getter(fn member_sponsored)]
+    pub type MemberSponsored<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::BlockNumber, BalanceOf<T>),
+        ValueQuery,
+    >;
+
+    /// How fees paid in the DAO token are converted from native tokens.
+    /// DAO 通证支付手续费的汇率
+    #[pallet::storage]
+    #[pallet::getter(fn fee_rate)]
+    pub type FeeRateOf<T: Config> = StorageMap<_, Identity, DaoAssetId, FeeRate, ValueQuery>;
+
+    /// DAO tokens a spender may transfer on behalf of an owner. [(owner, spender)]
+    /// 授权额度
+    #[pallet::storage]
+    pub type Allowances<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Blake2_128Concat,
+        (T::AccountId, T::AccountId),
+        Allowance<BalanceOf<T>, T::BlockNumber>,
+    >;
+
+    /// Who an asset can be transferred to.
+    /// 资产的转账策略
+    #[pallet::storage]
+    #[pallet::getter(fn transfer_policy)]
+    pub type TransferPolicyOf<T: Config> =
+        StorageMap<_, Identity, AssetId, TransferPolicy, ValueQuery>;
+
+    /// Recipients allowed under `TransferPolicy::Allowlist`.
+    /// 转账白名单
+    #[pallet::storage]
+    pub type TransferAllowlist<T: Config> =
+        StorageDoubleMap<_, Identity, AssetId, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Inflation minted to the DAO treasury.
+    /// 通胀增发规则
+    #[pallet::storage]
+    #[pallet::getter(fn inflation)]
+    pub type InflationOf<T: Config> =
+        StorageMap<_, Identity, AssetId, InflationRule<BalanceOf<T>, T::BlockNumber>>;
+
+    /// The block the next inflation of an asset is minted at.
+    /// 下一次通胀增发的区块
+    #[pallet::storage]
+    #[pallet::getter(fn next_inflation_at)]
+    pub type NextInflationAt<T: Config> = StorageMap<_, Identity, AssetId, T::BlockNumber>;
+
+    /// Assets whose inflation is minted at a block.
+    /// 每个区块待执行的通胀增发
+    #[pallet::storage]
+    pub type InflationQueue<T: Config> =
+        StorageDoubleMap<_, Identity, T::BlockNumber, Identity, AssetId, ()>;
+
+    /// Rewards distributed per DAO token so far. [dao_id, reward_asset]
+    /// 每个 DAO 通证累计的分红
+    #[pallet::storage]
+    #[pallet::getter(fn reward_per_share)]
+    pub type RewardPerShare<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, DaoAssetId, FixedU128, ValueQuery>;
+
+    /// The `RewardPerShare` a holder was last settled at, and the rewards owed to it.
+    /// [(dao_id, reward_asset), holder]
+    /// 持有人上次结算时的累计分红与待领取的分红
+    #[pallet::storage]
+    pub type HolderRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (DaoAssetId, DaoAssetId),
+        Blake2_128Concat,
+        T::AccountId,
+        (FixedU128, BalanceOf<T>),
+        ValueQuery,
+    >;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::mint_inflation(now)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::migrate::<T>()
+                .saturating_add(migrations::v2::migrate::<T>())
+                .saturating_add(migrations::v3::migrate::<T>())
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -206,6 +612,7 @@ pub mod pallet {
 
             let user = ensure_signed(origin)?;
             Self::do_create(user.clone(), dao_id, metadata, amount, false)?;
+            Self::register_asset(dao_id, dao_id);
 
             // 将资金转入资金池B池
             <Self as MultiCurrency<T::AccountId>>::transfer(
@@ -332,16 +739,7 @@ pub mod pallet {
                 Error::<T>::AssetNotExists
             );
 
-            // 从DAO转出手续费 TODO
-            // match daoent_dao::Daos::<T>::get(dao_id) {
-            //     Some(dao) => {
-            //         let _dao_account = dao.dao_account_id;
-            //     }
-            // };
-
-            if let Some(dao) = daoent_dao::Daos::<T>::get(dao_id) {
-                let _dao_account = dao.dao_account_id;
-            }
+            // 手续费可由 DAO 代付，见 `set_fee_sponsor`
 
             ensure!(
                 Self::is_exists_metadata(dao_id),
@@ -352,13 +750,15 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Deposit native tokens into pool B and become a member, minting DAO tokens at the
+        /// DAO's join pricing. Fails if fewer than `min_shares` would be minted.
         /// 成为会员
         #[pallet::call_index(007)]
         #[pallet::weight(1_500_000_000)]
         pub fn join_request(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            share_expect: u32,
+            min_shares: BalanceOf<T>,
             #[pallet::compact] existenial_deposit: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
@@ -380,13 +780,11 @@ pub mod pallet {
                 <Self as MultiCurrency<T::AccountId>>::total_balance(NATIVE_ASSET_ID, &pool_b);
             ensure!(pool_b_total > zero, Error::<T>::DepositTooLow);
 
-            // 判断用户期望share是否符合当前汇率
-            let share_expect_b: BalanceOf<T> = share_expect.into();
-            ensure!(
-                <Self as MultiCurrency<T::AccountId>>::total_issuance(dao_id) / pool_b_total
-                    >= share_expect_b / existenial_deposit,
-                Error::<T>::DepositRateError
-            );
+            // 按定价计算 share，并检查滑点
+            let shares =
+                Self::quote_join(dao_id, existenial_deposit).ok_or(Error::<T>::QuoteUnavailable)?;
+            ensure!(!shares.is_zero(), Error::<T>::DepositRateError);
+            ensure!(shares >= min_shares, Error::<T>::SlippageExceeded);
 
             // 将资金转入资金池B池
             <Self as MultiCurrency<T::AccountId>>::transfer(
@@ -397,8 +795,674 @@ pub mod pallet {
             )?;
 
             // 设置为会员，并且为用户添加 share
-            daoent_dao::Pallet::<T>::try_add_member(dao_id, who.clone()).unwrap();
-            <Self as MultiCurrency<T::AccountId>>::deposit(dao_id, &who, share_expect.into())?;
+            daoent_dao::Pallet::<T>::try_add_member(dao_id, who.clone())?;
+            <Self as MultiCurrency<T::AccountId>>::deposit(dao_id, &who, shares)?;
+            Self::deposit_event(Event::Joined {
+                dao_id,
+                who,
+                deposit: existenial_deposit,
+                shares,
+            });
+
+            Ok(().into())
+        }
+
+        /// Set how `join_request` prices DAO tokens, `None` falls back to `Nav`.
+        /// 设置加入 DAO 的定价方式
+        #[pallet::call_index(008)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_join_pricing())]
+        pub fn set_join_pricing(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            pricing: Option<JoinPricing<BalanceOf<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+
+            match pricing {
+                Some(p) => {
+                    ensure!(p.is_valid(), Error::<T>::InvalidPricing);
+                    JoinPricingOf::<T>::insert(dao_id, p);
+                }
+                None => JoinPricingOf::<T>::remove(dao_id),
+            }
+            Self::deposit_event(Event::SetJoinPricing { dao_id, pricing });
+
+            Ok(().into())
+        }
+
+        /// Burn DAO tokens and receive their share of the native tokens in pool B, less the
+        /// exit fee. Fails if the payout would be less than `min_payout`.
+        /// 赎回 DAO 通证
+        #[pallet::call_index(009)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::redeem())]
+        pub fn redeem(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            #[pallet::compact] amount: BalanceOf<T>,
+            min_payout: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::BalanceTooLow);
+
+            let (payout, fee) =
+                Self::quote_redeem(dao_id, amount).ok_or(Error::<T>::QuoteUnavailable)?;
+            ensure!(payout >= min_payout, Error::<T>::SlippageExceeded);
+
+            // 检查赎回窗口与周期上限
+            if let Some(rule) = RedeemRuleOf::<T>::get(dao_id) {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(
+                    rule.window.is_zero() || now % rule.epoch < rule.window,
+                    Error::<T>::RedeemClosed
+                );
+                let epoch = now / rule.epoch;
+                let (last, paid) = RedeemedInEpoch::<T>::get(dao_id);
+                let paid = if last == epoch { paid } else { Zero::zero() };
+                let paid = paid.saturating_add(payout);
+                ensure!(
+                    rule.epoch_cap.is_zero() || paid <= rule.epoch_cap,
+                    Error::<T>::RedeemCapExceeded
+                );
+                RedeemedInEpoch::<T>::insert(dao_id, (epoch, paid));
+            }
+
+            <Self as MultiCurrency<T::AccountId>>::withdraw(dao_id, &who, amount)?;
+            <Self as MultiCurrency<T::AccountId>>::transfer(
+                NATIVE_ASSET_ID,
+                &daoent_dao::Pallet::<T>::dao_asset(dao_id),
+                &who,
+                payout,
+            )?;
+            Self::deposit_event(Event::Redeemed {
+                dao_id,
+                who,
+                amount,
+                payout,
+                fee,
+            });
+
+            Ok(().into())
+        }
+
+        /// Set the exit fee, redemption window and per-epoch cap, `None` removes them.
+        /// 设置赎回规则
+        #[pallet::call_index(010)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_redeem_rule())]
+        pub fn set_redeem_rule(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            rule: Option<RedeemRule<BalanceOf<T>, T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+
+            match rule {
+                Some(ref r) => {
+                    ensure!(
+                        !r.epoch.is_zero() && r.window <= r.epoch,
+                        Error::<T>::InvalidRedeemRule
+                    );
+                    RedeemRuleOf::<T>::insert(dao_id, r);
+                }
+                None => RedeemRuleOf::<T>::remove(dao_id),
+            }
+            RedeemedInEpoch::<T>::remove(dao_id);
+            Self::deposit_event(Event::SetRedeemRule { dao_id, rule });
+
+            Ok(().into())
+        }
+
+        /// Move DAO tokens from the `dao_asset` account to `dest`, locked until they vest.
+        /// 从 DAO 资产账户创建归属计划
+        #[pallet::call_index(011)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_vesting())]
+        pub fn create_vesting(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            dest: <T::Lookup as StaticLookup>::Source,
+            schedule: VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+            let who = T::Lookup::lookup(dest)?;
+
+            ensure!(
+                !Vesting::<T>::contains_key(dao_id, &who),
+                Error::<T>::VestingExists
+            );
+            ensure!(
+                !schedule.period.is_zero() && schedule.period_count > 0,
+                Error::<T>::InvalidVesting
+            );
+            let total = schedule.total().ok_or(Error::<T>::InvalidVesting)?;
+            ensure!(!total.is_zero(), Error::<T>::InvalidVesting);
+
+            Self::try_transfer(
+                dao_id,
+                daoent_dao::Pallet::<T>::dao_asset(dao_id),
+                who.clone(),
+                total,
+            )?;
+            let locked = schedule.locked_at(frame_system::Pallet::<T>::block_number());
+            Self::update_vesting_lock(dao_id, &who, locked)?;
+            if !locked.is_zero() {
+                Vesting::<T>::insert(dao_id, &who, &schedule);
+            }
+            Self::deposit_event(Event::VestingCreated {
+                dao_id,
+                who,
+                schedule,
+            });
+
+            Ok(().into())
+        }
+
+        /// Unlock the DAO tokens that have vested.
+        /// 解锁已归属的通证
+        #[pallet::call_index(012)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim())]
+        pub fn claim(origin: OriginFor<T>, dao_id: DaoAssetId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let schedule = Vesting::<T>::get(dao_id, &who).ok_or(Error::<T>::NoVesting)?;
+
+            let locked = schedule.locked_at(frame_system::Pallet::<T>::block_number());
+            Self::update_vesting_lock(dao_id, &who, locked)?;
+            if locked.is_zero() {
+                Vesting::<T>::remove(dao_id, &who);
+            }
+            Self::deposit_event(Event::VestingClaimed {
+                dao_id,
+                who,
+                locked,
+            });
+
+            Ok(().into())
+        }
+
+        /// Return the unvested DAO tokens of an account to the `dao_asset` account, the
+        /// vested part is unlocked.
+        /// 撤销未归属的通证
+        #[pallet::call_index(013)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_vesting())]
+        pub fn revoke_vesting(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            who: <T::Lookup as StaticLookup>::Source,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+            let who = T::Lookup::lookup(who)?;
+            let schedule = Vesting::<T>::take(dao_id, &who).ok_or(Error::<T>::NoVesting)?;
+
+            let unvested = schedule.locked_at(frame_system::Pallet::<T>::block_number());
+            Self::update_vesting_lock(dao_id, &who, Zero::zero())?;
+            Self::try_transfer(
+                dao_id,
+                who.clone(),
+                daoent_dao::Pallet::<T>::dao_asset(dao_id),
+                unvested,
+            )?;
+            Self::deposit_event(Event::VestingRevoked {
+                dao_id,
+                who,
+                unvested,
+            });
+
+            Ok(().into())
+        }
+
+        /// Issue an additional asset owned by the DAO, such as a governance token, reputation
+        /// points or a voucher. The supply goes to the `dao_asset` account.
+        /// 发行 DAO 的附加资产
+        #[pallet::call_index(014)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::issue_asset())]
+        pub fn issue_asset(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            metadata: DaoAssetMeta,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            let dao_account = daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+
+            ensure!(
+                metadata.name.len() > 2
+                    && metadata.symbol.len() > 1
+                    && metadata.decimals > 0u8
+                    && metadata.decimals < 19,
+                Error::<T>::MetadataErr
+            );
+
+            let asset_id = NextAssetId::<T>::get();
+            ensure!(
+                !Self::is_exists_metadata(asset_id),
+                Error::<T>::AssetAlreadyExists
+            );
+            NextAssetId::<T>::put(
+                asset_id
+                    .checked_add(1)
+                    .ok_or(Error::<T>::CurrencyIdTooLarge)?,
+            );
+
+            DaoAssetsInfo::<T>::insert(
+                asset_id,
+                DaoAssetInfo {
+                    owner: dao_account,
+                    metadata,
+                    max_supply: None,
+                },
+            );
+            Self::register_asset(dao_id, asset_id);
+            <Self as MultiCurrency<T::AccountId>>::deposit(
+                asset_id,
+                &daoent_dao::Pallet::<T>::dao_asset(dao_id),
+                amount,
+            )?;
+            Self::deposit_event(Event::AssetIssued {
+                dao_id,
+                asset_id,
+                amount,
+            });
+
+            Ok(().into())
+        }
+
+        /// Let the DAO's fee sponsor account pay the fees of allowlisted calls made by its
+        /// members, within a per-member and a per-period budget. `None` stops sponsoring.
+        /// 设置手续费代付规则
+        #[pallet::call_index(015)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_sponsor())]
+        pub fn set_fee_sponsor(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            rule: Option<SponsorRule<BalanceOf<T>, T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+
+            match rule {
+                Some(ref r) => {
+                    ensure!(
+                        !r.period.is_zero() && !r.calls.is_empty(),
+                        Error::<T>::InvalidSponsorRule
+                    );
+                    SponsorRuleOf::<T>::insert(dao_id, r);
+                }
+                None => SponsorRuleOf::<T>::remove(dao_id),
+            }
+            Self::deposit_event(Event::SetFeeSponsor { dao_id, rule });
+
+            Ok(().into())
+        }
+
+        /// Move native tokens from the DAO account to its fee sponsor account.
+        /// 为手续费代付账户充值
+        #[pallet::call_index(016)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::fund_fee_sponsor())]
+        pub fn fund_fee_sponsor(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao_account = daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+
+            <Self as MultiCurrency<T::AccountId>>::transfer(
+                NATIVE_ASSET_ID,
+                &dao_account,
+                &daoent_dao::Pallet::<T>::dao_fee_sponsor(dao_id),
+                amount,
+            )?;
+            Self::deposit_event(Event::FeeSponsorFunded { dao_id, amount });
+
+            Ok(().into())
+        }
+
+        /// Set how fees paid in the DAO token are converted: at the net asset value of pool B,
+        /// at a fixed price, or not at all.
+        /// 设置 DAO 通证支付手续费的汇率
+        #[pallet::call_index(017)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_rate())]
+        pub fn set_fee_rate(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            rate: FeeRate,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+
+            if let FeeRate::Fixed(price) = rate {
+                ensure!(!price.is_zero(), Error::<T>::InvalidFeeRate);
+            }
+            FeeRateOf::<T>::insert(dao_id, rate);
+            Self::deposit_event(Event::SetFeeRate { dao_id, rate });
+
+            Ok(().into())
+        }
+
+        /// Allow `spender` to transfer up to `amount` DAO tokens of the caller until
+        /// `expires_at`, replacing any earlier allowance. Zero removes the allowance.
+        /// 授权他人转出资产
+        #[pallet::call_index(018)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            spender: <T::Lookup as StaticLookup>::Source,
+            #[pallet::compact] amount: BalanceOf<T>,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            let spender = T::Lookup::lookup(spender)?;
+            ensure!(
+                Self::is_exists_metadata(dao_id),
+                Error::<T>::MetadataNotExists
+            );
+            let allowance = Allowance { amount, expires_at };
+            ensure!(
+                allowance.is_live(frame_system::Pallet::<T>::block_number()),
+                Error::<T>::InvalidExpiry
+            );
+
+            Self::set_allowance(dao_id, owner, spender, allowance);
+
+            Ok(().into())
+        }
+
+        /// Transfer DAO tokens of `owner` to `dest` out of the caller's allowance.
+        /// 使用授权额度转出资产
+        #[pallet::call_index(019)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_from())]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            owner: <T::Lookup as StaticLookup>::Source,
+            dest: <T::Lookup as StaticLookup>::Source,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let spender = ensure_signed(origin)?;
+            let owner = T::Lookup::lookup(owner)?;
+            let to = T::Lookup::lookup(dest)?;
+
+            let mut allowance =
+                Self::allowance(dao_id, &owner, &spender).ok_or(Error::<T>::AllowanceExpired)?;
+            allowance.amount = allowance
+                .amount
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::AllowanceExceeded)?;
+
+            Self::try_transfer(dao_id, owner.clone(), to.clone(), amount)?;
+            Self::set_allowance(dao_id, owner.clone(), spender.clone(), allowance);
+            Self::deposit_event(Event::TransferredFrom {
+                dao_id,
+                owner,
+                spender,
+                to,
+                amount,
+            });
+
+            Ok(().into())
+        }
+
+        /// Raise the allowance of `spender` by `added`, keeping its expiry.
+        /// 增加授权额度
+        #[pallet::call_index(020)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve())]
+        pub fn increase_allowance(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            spender: <T::Lookup as StaticLookup>::Source,
+            #[pallet::compact] added: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            let spender = T::Lookup::lookup(spender)?;
+            ensure!(
+                Self::is_exists_metadata(dao_id),
+                Error::<T>::MetadataNotExists
+            );
+
+            let mut allowance = match Allowances::<T>::get(dao_id, (&owner, &spender)) {
+                Some(a) => {
+                    ensure!(
+                        a.is_live(frame_system::Pallet::<T>::block_number()),
+                        Error::<T>::AllowanceExpired
+                    );
+                    a
+                }
+                None => Default::default(),
+            };
+            allowance.amount = allowance.amount.saturating_add(added);
+            Self::set_allowance(dao_id, owner, spender, allowance);
+
+            Ok(().into())
+        }
+
+        /// Lower the allowance of `spender` by `subtracted`, removing it at zero.
+        /// 减少授权额度
+        #[pallet::call_index(021)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve())]
+        pub fn decrease_allowance(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            spender: <T::Lookup as StaticLookup>::Source,
+            #[pallet::compact] subtracted: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            let spender = T::Lookup::lookup(spender)?;
+
+            let mut allowance = Allowances::<T>::get(dao_id, (&owner, &spender))
+                .ok_or(Error::<T>::AllowanceExceeded)?;
+            allowance.amount = allowance.amount.saturating_sub(subtracted);
+            Self::set_allowance(dao_id, owner, spender, allowance);
+
+            Ok(().into())
+        }
+
+        /// Set who an asset of the DAO can be transferred to.
+        /// 设置资产的转账策略
+        #[pallet::call_index(022)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_transfer_policy())]
+        pub fn set_transfer_policy(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            policy: TransferPolicy,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+            Self::ensure_dao_asset(dao_id, asset_id)?;
+
+            TransferPolicyOf::<T>::insert(asset_id, policy);
+            Self::deposit_event(Event::SetTransferPolicy {
+                dao_id,
+                asset_id,
+                policy,
+            });
+
+            Ok(().into())
+        }
+
+        /// Add an account to or remove it from the transfer allowlist of an asset of the DAO.
+        /// 设置转账白名单
+        #[pallet::call_index(023)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_transfer_policy())]
+        pub fn set_transfer_allowlist(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            who: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResultWithPostInfo {
+            let root = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(root, dao_id)?;
+            Self::ensure_dao_asset(dao_id, asset_id)?;
+
+            if allowed {
+                TransferAllowlist::<T>::insert(asset_id, &who, ());
+            } else {
+                TransferAllowlist::<T>::remove(asset_id, &who);
+            }
+            Self::deposit_event(Event::SetTransferAllowlist {
+                dao_id,
+                asset_id,
+                who,
+                allowed,
+            });
+
+            Ok(().into())
+        }
+
+        /// Mint tokens of an asset of the DAO, within its supply cap.
+        /// DAO 增发资产
+        #[pallet::call_index(024)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::mint())]
+        pub fn mint(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            dest: <T::Lookup as StaticLookup>::Source,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+            Self::ensure_dao_asset(dao_id, asset_id)?;
+            let to = T::Lookup::lookup(dest)?;
+
+            <Self as MultiCurrency<T::AccountId>>::deposit(asset_id, &to, amount)?;
+            Self::deposit_event(Event::Minted {
+                dao_id,
+                asset_id,
+                to,
+                amount,
+            });
+
+            Ok(().into())
+        }
+
+        /// Cap the total issuance of an asset of the DAO. The cap can not be below the current
+        /// issuance, and once set it can only be lowered.
+        /// 设置资产的供应上限
+        #[pallet::call_index(025)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_max_supply())]
+        pub fn set_max_supply(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            #[pallet::compact] max_supply: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+            Self::ensure_dao_asset(dao_id, asset_id)?;
+
+            DaoAssetsInfo::<T>::try_mutate(asset_id, |info| -> DispatchResult {
+                let info = info.as_mut().ok_or(Error::<T>::MetadataNotExists)?;
+                ensure!(
+                    max_supply >= <Self as MultiCurrency<T::AccountId>>::total_issuance(asset_id)
+                        && info.max_supply.map_or(true, |cap| max_supply <= cap),
+                    Error::<T>::InvalidSupplyCap
+                );
+                info.max_supply = Some(max_supply);
+                Ok(())
+            })?;
+            Self::deposit_event(Event::SetMaxSupply {
+                dao_id,
+                asset_id,
+                max_supply,
+            });
+
+            Ok(().into())
+        }
+
+        /// Mint `amount` tokens of an asset of the DAO to the DAO treasury every `period`
+        /// blocks, starting one period from now. `None` stops the inflation.
+        /// 设置资产的通胀增发
+        #[pallet::call_index(026)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_inflation())]
+        pub fn set_inflation(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            asset_id: AssetId,
+            rule: Option<InflationRule<BalanceOf<T>, T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+            Self::ensure_dao_asset(dao_id, asset_id)?;
+
+            if let Some(at) = NextInflationAt::<T>::take(asset_id) {
+                InflationQueue::<T>::remove(at, asset_id);
+            }
+            match rule {
+                Some(ref r) => {
+                    ensure!(
+                        !r.amount.is_zero() && !r.period.is_zero(),
+                        Error::<T>::InvalidInflation
+                    );
+                    InflationOf::<T>::insert(asset_id, r);
+                    Self::schedule_inflation(
+                        asset_id,
+                        frame_system::Pallet::<T>::block_number().saturating_add(r.period),
+                    );
+                }
+                None => InflationOf::<T>::remove(asset_id),
+            }
+            Self::deposit_event(Event::SetInflation {
+                dao_id,
+                asset_id,
+                rule,
+            });
+
+            Ok(().into())
+        }
+
+        /// Share `amount` of native tokens or DAO tokens from the DAO account among the DAO
+        /// token holders, pro rata to their balances now. The DAO's own accounts get no share.
+        /// Holders claim their share with `claim_distribution`. The accumulator is settled
+        /// lazily and keeps no snapshot of the holders, so past balances at a given block can
+        /// not be queried.
+        /// 向 DAO 通证持有人按比例分红，不保存持有人快照
+        #[pallet::call_index(027)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::distribute())]
+        pub fn distribute(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            reward_asset: DaoAssetId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao_account = daoent_dao::Pallet::<T>::ensrue_dao_root(who, dao_id)?;
+
+            let per_share = Self::do_distribute(dao_id, &dao_account, reward_asset, amount)?;
+            Self::deposit_event(Event::Distributed {
+                dao_id,
+                reward_asset,
+                amount,
+                per_share,
+            });
+
+            Ok(().into())
+        }
+
+        /// Pay `who` its share of the distributions of the DAO in `reward_asset`. Anyone can
+        /// claim for any holder.
+        /// 领取分红
+        #[pallet::call_index(028)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_distribution())]
+        pub fn claim_distribution(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            reward_asset: DaoAssetId,
+            who: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let amount = Self::do_claim_distribution(dao_id, reward_asset, &who)?;
+            Self::deposit_event(Event::DistributionClaimed {
+                dao_id,
+                reward_asset,
+                who,
+                amount,
+            });
 
             Ok(().into())
         }
@@ -479,6 +1543,27 @@ impl<T: Config> Pallet<T> {
         false
     }
 
+    /// Whether `asset_id` is owned by `dao_id`.
+    /// 资产是否属于该 DAO
+    pub fn is_dao_asset(dao_id: DaoAssetId, asset_id: AssetId) -> bool {
+        AssetOwner::<T>::get(asset_id) == Some(dao_id)
+    }
+
+    /// Ensure `asset_id` is owned by `dao_id`.
+    /// 确保资产属于该 DAO
+    pub fn ensure_dao_asset(dao_id: DaoAssetId, asset_id: AssetId) -> DispatchResult {
+        ensure!(
+            Self::is_dao_asset(dao_id, asset_id),
+            Error::<T>::NotDaoAsset
+        );
+        Ok(())
+    }
+
+    fn register_asset(dao_id: DaoAssetId, asset_id: AssetId) {
+        AssetOwner::<T>::insert(asset_id, dao_id);
+        AssetsOf::<T>::insert(dao_id, asset_id, ());
+    }
+
     pub fn is_owner(dao_id: DaoAssetId, who: &T::AccountId) -> bool {
         if let Some(info) = DaoAssetsInfo::<T>::get(dao_id).as_ref() {
             return &info.owner == who;
@@ -486,6 +1571,18 @@ impl<T: Config> Pallet<T> {
         false
     }
 
+    fn update_vesting_lock(
+        dao_id: DaoAssetId,
+        who: &T::AccountId,
+        locked: BalanceOf<T>,
+    ) -> DispatchResult {
+        if locked.is_zero() {
+            <Self as MultiLockableCurrency<T::AccountId>>::remove_lock(VESTING_ID, dao_id, who)
+        } else {
+            <Self as MultiLockableCurrency<T::AccountId>>::set_lock(VESTING_ID, dao_id, who, locked)
+        }
+    }
+
     fn is_asset_id_too_large(dao_id: DaoAssetId) -> bool {
         if dao_id >= T::MaxCreatableId::get() {
             return true;
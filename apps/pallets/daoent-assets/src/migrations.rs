@@ -0,0 +1,87 @@
+use super::*;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+pub mod v1 {
+    use super::*;
+
+    /// Register every existing DAO token in `AssetOwner` and `AssetsOf`, a DAO token shares
+    /// the id of its DAO.
+    /// 将已有的 DAO 通证登记到所属的 DAO
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut count: u64 = 0;
+        for asset_id in DaoAssetsInfo::<T>::iter_keys() {
+            AssetOwner::<T>::insert(asset_id, asset_id);
+            AssetsOf::<T>::insert(asset_id, asset_id, ());
+            count += 1;
+        }
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(count + 1, count * 2 + 1)
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// `DaoAssetInfo` before `max_supply` was added.
+    /// v1 版本的资产信息
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OldDaoAssetInfo<AccountId, DaoAssetMeta> {
+        pub owner: AccountId,
+        pub metadata: DaoAssetMeta,
+    }
+
+    /// Add `max_supply` to every `DaoAssetInfo`, uncapped.
+    /// 为资产信息添加供应上限
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 2 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut count: u64 = 0;
+        crate::DaoAssetsInfo::<T>::translate::<OldDaoAssetInfo<T::AccountId, DaoAssetMeta>, _>(
+            |_, old| {
+                count += 1;
+                Some(DaoAssetInfo {
+                    owner: old.owner,
+                    metadata: old.metadata,
+                    max_supply: None,
+                })
+            },
+        );
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// Seed `NextAssetId` past every additional asset issued so far.
+    /// 初始化下一个附加资产的ID
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 3 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut reads: u64 = 2;
+        if !NextAssetId::<T>::exists() {
+            let mut next = T::MaxCreatableId::get();
+            for asset_id in DaoAssetsInfo::<T>::iter_keys() {
+                reads += 1;
+                if asset_id >= next {
+                    next = asset_id.saturating_add(1);
+                }
+            }
+            NextAssetId::<T>::put(next);
+        }
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(reads, 2)
+    }
+}
@@ -0,0 +1,77 @@
+use super::*;
+
+/// Tokens minted to the DAO treasury every `period` blocks, `1` for every block.
+/// 通胀增发规则
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct InflationRule<Balance, BlockNumber> {
+    /// Tokens minted each period.
+    /// 每周期增发数量
+    pub amount: Balance,
+    /// Length of a period in blocks.
+    /// 周期
+    pub period: BlockNumber,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Tokens that can still be minted under the supply cap of `asset_id`, `None` if uncapped.
+    /// 查询距离供应上限的剩余额度
+    pub fn headroom(asset_id: AssetId) -> Option<BalanceOf<T>> {
+        let cap = DaoAssetsInfo::<T>::get(asset_id)?.max_supply?;
+        Some(
+            cap.saturating_sub(<Self as MultiCurrency<T::AccountId>>::total_issuance(
+                asset_id,
+            )),
+        )
+    }
+
+    /// Ensure minting `amount` of `asset_id` stays within its supply cap.
+    /// 确保增发不超过供应上限
+    pub fn ensure_within_cap(asset_id: AssetId, amount: BalanceOf<T>) -> DispatchResult {
+        ensure!(
+            Self::headroom(asset_id).map_or(true, |h| amount <= h),
+            Error::<T>::SupplyCapExceeded
+        );
+        Ok(())
+    }
+
+    /// Mint the inflation of every asset scheduled at `now` to its DAO treasury.
+    /// 执行到期的通胀增发
+    pub(crate) fn mint_inflation(now: T::BlockNumber) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        for (asset_id, _) in InflationQueue::<T>::drain_prefix(now) {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 5));
+            let rule = match InflationOf::<T>::get(asset_id) {
+                Some(rule) => rule,
+                None => continue,
+            };
+            let dao_id = AssetOwner::<T>::get(asset_id).unwrap_or(asset_id);
+            let amount = Self::headroom(asset_id).map_or(rule.amount, |h| h.min(rule.amount));
+            if amount.is_zero() {
+                // 达到供应上限，停止通胀
+                InflationOf::<T>::remove(asset_id);
+                NextInflationAt::<T>::remove(asset_id);
+                continue;
+            }
+
+            let treasury = daoent_dao::Pallet::<T>::dao_treasury(dao_id);
+            if <Self as MultiCurrency<T::AccountId>>::deposit(asset_id, &treasury, amount).is_ok() {
+                Self::deposit_event(Event::Minted {
+                    dao_id,
+                    asset_id,
+                    to: treasury,
+                    amount,
+                });
+            }
+            Self::schedule_inflation(asset_id, now.saturating_add(rule.period));
+        }
+        weight
+    }
+
+    /// Schedule the next inflation of `asset_id` at block `at`.
+    /// 安排下一次通胀增发
+    pub(crate) fn schedule_inflation(asset_id: AssetId, at: T::BlockNumber) {
+        InflationQueue::<T>::insert(at, asset_id, ());
+        NextInflationAt::<T>::insert(asset_id, at);
+    }
+}
@@ -54,7 +54,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 
 impl orml_tokens::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type CurrencyHooks = ();
+    type CurrencyHooks = daoent_assets::HolderHooks<Test>;
     type Balance = Balance;
     type Amount = Amount;
     type CurrencyId = DaoAssetId;
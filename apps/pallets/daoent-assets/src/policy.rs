@@ -0,0 +1,74 @@
+use super::*;
+use orml_traits::currency::OnTransfer;
+
+/// Who an asset can be transferred to. Transfers to or from the accounts of the owning DAO,
+/// as well as minting and burning, are always allowed.
+/// 资产的转账策略
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum TransferPolicy {
+    /// Freely transferable.
+    /// 自由转账
+    Free,
+    /// Only to members of the owning DAO.
+    /// 只能转给 DAO 成员
+    MembersOnly,
+    /// Only to accounts in the allowlist of the asset.
+    /// 只能转给白名单账户
+    Allowlist,
+    /// Not transferable, the DAO can only mint and burn it.
+    /// 不可转账
+    NonTransferable,
+}
+
+impl Default for TransferPolicy {
+    fn default() -> Self {
+        TransferPolicy::Free
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Ensure the transfer policy of `asset_id` allows moving it from `from` to `to`.
+    /// 检查转账是否符合资产的转账策略
+    pub fn ensure_transfer_allowed(
+        asset_id: DaoAssetId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+    ) -> DispatchResult {
+        let policy = TransferPolicyOf::<T>::get(asset_id);
+        if policy == TransferPolicy::Free {
+            return Ok(());
+        }
+        let dao_id = AssetOwner::<T>::get(asset_id).unwrap_or(asset_id);
+        if daoent_dao::Pallet::<T>::is_dao_account(dao_id, from)
+            || daoent_dao::Pallet::<T>::is_dao_account(dao_id, to)
+        {
+            return Ok(());
+        }
+
+        let allowed = match policy {
+            TransferPolicy::Free => true,
+            TransferPolicy::MembersOnly => daoent_dao::Members::<T>::get(dao_id).contains(to),
+            TransferPolicy::Allowlist => TransferAllowlist::<T>::contains_key(asset_id, to),
+            TransferPolicy::NonTransferable => false,
+        };
+        ensure!(allowed, Error::<T>::TransferRestricted);
+        Ok(())
+    }
+}
+
+/// Enforces the transfer policy on every transfer of the tokens pallet, including the
+/// transfers dispatched to it directly.
+/// 在 orml-tokens 转账前检查转账策略
+pub struct CheckTransferPolicy<T>(marker::PhantomData<T>);
+
+impl<T: Config, Balance> OnTransfer<T::AccountId, DaoAssetId, Balance> for CheckTransferPolicy<T> {
+    fn on_transfer(
+        asset_id: DaoAssetId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        _amount: Balance,
+    ) -> DispatchResult {
+        Pallet::<T>::ensure_transfer_allowed(asset_id, from, to)
+    }
+}
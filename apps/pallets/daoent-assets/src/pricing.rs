@@ -0,0 +1,189 @@
+use super::*;
+use sp_core::U256;
+use sp_runtime::{FixedPointNumber, PerThing, SaturatedConversion};
+
+/// The most price steps an exponential curve walks through in one join.
+/// 指数曲线单次加入最多跨越的价格阶梯数
+pub const MAX_CURVE_STEPS: u32 = 64;
+
+/// How `join_request` prices new DAO tokens against the native deposit.
+/// Prices are native tokens per DAO token, both in their smallest units.
+/// 加入 DAO 时的定价方式
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum JoinPricing<Balance> {
+    /// `shares` DAO tokens for every `native` native tokens.
+    /// 固定汇率
+    Fixed { native: Balance, shares: Balance },
+    /// The price grows by `slope` for every DAO token issued.
+    /// 线性联合曲线
+    Linear {
+        base_price: FixedU128,
+        slope: FixedU128,
+    },
+    /// The price grows by `growth` for every `step` DAO tokens issued.
+    /// 指数联合曲线
+    Exponential {
+        base_price: FixedU128,
+        growth: Perbill,
+        step: Balance,
+    },
+    /// Priced at the net asset value, the native balance of pool B per DAO token.
+    /// 按净值定价
+    Nav,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> JoinPricing<Balance> {
+    /// Whether the parameters can price a deposit.
+    /// 参数是否有效
+    pub fn is_valid(&self) -> bool {
+        match self {
+            JoinPricing::Fixed { native, shares } => !native.is_zero() && !shares.is_zero(),
+            JoinPricing::Linear { base_price, .. } => !base_price.is_zero(),
+            JoinPricing::Exponential {
+                base_price, step, ..
+            } => !base_price.is_zero() && !step.is_zero(),
+            JoinPricing::Nav => true,
+        }
+    }
+
+    /// DAO tokens minted for `deposit` when `supply` tokens are issued and pool B holds
+    /// `pool`. Every step rounds down, in favour of the DAO.
+    /// 计算存入 `deposit` 可获得的 DAO 通证数量
+    pub fn shares_for(&self, deposit: Balance, supply: Balance, pool: Balance) -> Option<Balance> {
+        let deposit = to_u256(deposit);
+        let supply = to_u256(supply);
+        let shares = match *self {
+            JoinPricing::Fixed { native, shares } => deposit
+                .checked_mul(to_u256(shares))?
+                .checked_div(to_u256(native))?,
+            JoinPricing::Linear { base_price, slope } => {
+                let unit = U256::from(FixedU128::DIV);
+                let slope = U256::from(slope.into_inner());
+                // 当前价格
+                let price =
+                    U256::from(base_price.into_inner()).checked_add(slope.checked_mul(supply)?)?;
+                let paid = deposit.checked_mul(unit)?;
+                if slope.is_zero() {
+                    paid.checked_div(price)?
+                } else {
+                    // slope / 2 * n^2 + price * n = deposit
+                    let delta = price
+                        .checked_mul(price)?
+                        .checked_add(slope.checked_mul(paid)?.checked_mul(2u32.into())?)?;
+                    (delta.integer_sqrt() - price) / slope
+                }
+            }
+            JoinPricing::Exponential {
+                base_price,
+                growth,
+                step,
+            } => {
+                let unit = U256::from(FixedU128::DIV);
+                let factor = unit
+                    + U256::from(growth.deconstruct()) * U256::from(FixedU128::DIV / 1_000_000_000);
+                let step = to_u256(step);
+                let mut price = scaled_pow(
+                    U256::from(base_price.into_inner()),
+                    factor,
+                    unit,
+                    supply / step,
+                )?;
+                let mut room = step - supply % step;
+                let mut left = deposit;
+                let mut shares = U256::zero();
+                let mut steps = 0u32;
+                loop {
+                    // 买满当前阶梯的费用，向上取整
+                    let cost = price.checked_mul(room)?.checked_add(unit - 1)? / unit;
+                    if left < cost {
+                        shares = shares.checked_add(left.checked_mul(unit)? / price)?;
+                        break;
+                    }
+                    steps += 1;
+                    if steps > MAX_CURVE_STEPS {
+                        return None;
+                    }
+                    shares = shares.checked_add(room)?;
+                    left -= cost;
+                    price = price.checked_mul(factor)? / unit;
+                    room = step;
+                }
+                shares
+            }
+            JoinPricing::Nav => {
+                let pool = to_u256(pool);
+                if supply.is_zero() {
+                    return None;
+                }
+                deposit.checked_mul(supply)?.checked_div(pool)?
+            }
+        };
+        u128::try_from(shares)
+            .ok()
+            .and_then(|s| Balance::try_from(s).ok())
+    }
+}
+
+fn to_u256<Balance: AtLeast32BitUnsigned>(value: Balance) -> U256 {
+    U256::from(value.saturated_into::<u128>())
+}
+
+/// `base * (factor / unit) ^ exp`, with `factor` scaled by `unit`.
+fn scaled_pow(mut base: U256, mut factor: U256, unit: U256, mut exp: U256) -> Option<U256> {
+    while !exp.is_zero() {
+        if exp.bit(0) {
+            base = base.checked_mul(factor)? / unit;
+        }
+        exp = exp >> 1usize;
+        if !exp.is_zero() {
+            factor = factor.checked_mul(factor)? / unit;
+        }
+    }
+    Some(base)
+}
+
+impl<T: Config> Pallet<T> {
+    /// DAO tokens `join_request` would mint for a native `deposit` right now.
+    /// 查询存入 `deposit` 可获得的 DAO 通证数量
+    pub fn quote_join(dao_id: DaoAssetId, deposit: BalanceOf<T>) -> Option<BalanceOf<T>> {
+        if !Self::is_exists_metadata(dao_id) || dao_id == NATIVE_ASSET_ID {
+            return None;
+        }
+        let pricing = JoinPricingOf::<T>::get(dao_id).unwrap_or(JoinPricing::Nav);
+        let supply = <Self as MultiCurrency<T::AccountId>>::total_issuance(dao_id);
+        let pool = <Self as MultiCurrency<T::AccountId>>::total_balance(
+            NATIVE_ASSET_ID,
+            &daoent_dao::Pallet::<T>::dao_asset(dao_id),
+        );
+        pricing.shares_for(deposit, supply, pool)
+    }
+
+    /// Native tokens paid and the exit fee kept in pool B when `amount` DAO tokens are
+    /// redeemed right now. The payout rounds down and the fee rounds up.
+    /// 查询赎回 `amount` DAO 通证可获得的原生通证与退出费
+    pub fn quote_redeem(
+        dao_id: DaoAssetId,
+        amount: BalanceOf<T>,
+    ) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+        if !Self::is_exists_metadata(dao_id) || dao_id == NATIVE_ASSET_ID {
+            return None;
+        }
+        let supply = <Self as MultiCurrency<T::AccountId>>::total_issuance(dao_id);
+        let pool = <Self as MultiCurrency<T::AccountId>>::total_balance(
+            NATIVE_ASSET_ID,
+            &daoent_dao::Pallet::<T>::dao_asset(dao_id),
+        );
+        if amount > supply {
+            return None;
+        }
+        let gross = to_u256(amount)
+            .checked_mul(to_u256(pool))?
+            .checked_div(to_u256(supply))?;
+        let gross = BalanceOf::<T>::try_from(u128::try_from(gross).ok()?).ok()?;
+        let fee = RedeemRuleOf::<T>::get(dao_id)
+            .map(|rule| rule.exit_fee.mul_ceil(gross))
+            .unwrap_or_else(Zero::zero);
+        Some((gross.saturating_sub(fee), fee))
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+/// Calls whose fees a DAO pays for its members, and how much it pays.
+/// DAO 代付手续费的规则
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct SponsorRule<Balance, BlockNumber> {
+    /// Allowlisted calls.
+    /// 可代付的函数
+    pub calls: Vec<CallId>,
+    /// Most fees paid for one member in a period, zero for no limit.
+    /// 每个成员每周期的预算
+    pub per_member: Balance,
+    /// Most fees paid for all members in a period, zero for no limit.
+    /// 每周期的总预算
+    pub per_period: Balance,
+    /// Length of a budget period in blocks.
+    /// 预算周期
+    pub period: BlockNumber,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account paying the `fee` of `call_id` sent by `who`, if the DAO sponsors it and the
+    /// budgets allow.
+    /// 获取代付手续费的账户
+    pub fn fee_sponsor_for(
+        dao_id: DaoAssetId,
+        who: &T::AccountId,
+        call_id: CallId,
+        fee: BalanceOf<T>,
+    ) -> Option<T::AccountId> {
+        let rule = SponsorRuleOf::<T>::get(dao_id)?;
+        if !rule.calls.contains(&call_id) || !daoent_dao::Members::<T>::get(dao_id).contains(who) {
+            return None;
+        }
+
+        let period = frame_system::Pallet::<T>::block_number() / rule.period;
+        let spent = |(last, spent): (T::BlockNumber, BalanceOf<T>)| {
+            if last == period {
+                spent.saturating_add(fee)
+            } else {
+                fee
+            }
+        };
+        let within =
+            |budget: BalanceOf<T>, spent: BalanceOf<T>| budget.is_zero() || spent <= budget;
+        if !within(rule.per_period, spent(SponsoredInPeriod::<T>::get(dao_id)))
+            || !within(
+                rule.per_member,
+                spent(MemberSponsored::<T>::get(dao_id, who)),
+            )
+        {
+            return None;
+        }
+
+        Some(daoent_dao::Pallet::<T>::dao_fee_sponsor(dao_id))
+    }
+
+    /// Charge a sponsored `fee` to the budgets of the DAO and the member.
+    /// 记录代付的手续费
+    pub fn note_sponsored_fee(dao_id: DaoAssetId, who: &T::AccountId, fee: BalanceOf<T>) {
+        let period = match SponsorRuleOf::<T>::get(dao_id) {
+            Some(rule) => frame_system::Pallet::<T>::block_number() / rule.period,
+            None => return,
+        };
+        let add = |(last, spent): &mut (T::BlockNumber, BalanceOf<T>)| {
+            if *last != period {
+                *last = period;
+                *spent = Zero::zero();
+            }
+            *spent = spent.saturating_add(fee);
+        };
+        SponsoredInPeriod::<T>::mutate(dao_id, add);
+        MemberSponsored::<T>::mutate(dao_id, who, add);
+        Self::deposit_event(Event::FeeSponsored {
+            dao_id,
+            who: who.clone(),
+            fee,
+        });
+    }
+}
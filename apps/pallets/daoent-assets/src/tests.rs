@@ -3,9 +3,14 @@
 
 use crate as daoent_assets;
 use crate::mock::*;
+use crate::{
+    Error, FeeRate, InflationRule, JoinPricing, RedeemRule, SponsorRule, TransferPolicy,
+    VestingSchedule,
+};
 use daoent_primitives::types::AccountIdType;
 use daoent_primitives::types::DaoAssetId;
 use frame_support::{assert_noop, assert_ok, debug, log::debug};
+use sp_runtime::{FixedPointNumber, FixedU128, Perbill};
 
 pub fn create_asset() -> DaoAssetId {
     let dao_id = daoent_dao::Pallet::<Test>::next_dao_id();
@@ -108,5 +113,1433 @@ pub fn test_asset_join() {
         assert_eq!(bob_dao, 100);
         assert_eq!(bob, 9900);
         assert_eq!(dao_b, 10100);
+
+        // 已是会员时返回错误，而不是中止
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::join_request(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                100,
+                100
+            ),
+            daoent_dao::Error::<Test>::InVailCall
+        );
+    })
+}
+
+#[test]
+pub fn test_join_pricing() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+
+        // 默认按净值定价，(10000 + 99) / 10000
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::quote_join(dao_id, 1000),
+            Some(1009)
+        );
+
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_join_pricing(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                Some(JoinPricing::Fixed {
+                    native: 0,
+                    shares: 3
+                })
+            ),
+            Error::<Test>::InvalidPricing
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_join_pricing(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            Some(JoinPricing::Fixed {
+                native: 2,
+                shares: 3
+            })
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::quote_join(dao_id, 101),
+            Some(151)
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::join_request(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                152,
+                101
+            ),
+            Error::<Test>::SlippageExceeded
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::join_request(
+            RuntimeOrigin::signed(BOB),
+            dao_id,
+            151,
+            101
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(dao_id, BOB).unwrap(),
+            151
+        );
+
+        // 线性曲线：n + n^2 / 2 = 50
+        let linear = JoinPricing::Linear {
+            base_price: FixedU128::saturating_from_integer(1u32),
+            slope: FixedU128::saturating_from_integer(1u32),
+        };
+        assert_eq!(linear.shares_for(50u64, 0, 0), Some(9));
+        // 价格随供应上升
+        assert_eq!(linear.shares_for(50u64, 10, 0), Some(3));
+
+        // 指数曲线：每 10 个通证价格翻倍，10 + 20 + 5 / 4
+        let exponential = JoinPricing::Exponential {
+            base_price: FixedU128::saturating_from_integer(1u32),
+            growth: Perbill::one(),
+            step: 10u64,
+        };
+        assert_eq!(exponential.shares_for(35u64, 0, 0), Some(21));
+        assert_eq!(exponential.shares_for(35u64, 15, 0), Some(11));
+        // 单次跨越的价格阶梯过多
+        let slow = JoinPricing::Exponential {
+            base_price: FixedU128::saturating_from_integer(1u32),
+            growth: Perbill::from_percent(1),
+            step: 1u64,
+        };
+        assert_eq!(slow.shares_for(1000u64, 0, 0), None);
+
+        assert_ok!(daoent_assets::Pallet::<Test>::set_join_pricing(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            None
+        ));
+        assert_eq!(daoent_assets::Pallet::<Test>::join_pricing(dao_id), None);
+    })
+}
+
+#[test]
+pub fn test_redeem() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let native = || daoent_assets::Pallet::<Test>::get_balance(0, ALICE).unwrap();
+
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_redeem_rule(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                Some(RedeemRule {
+                    exit_fee: Perbill::from_percent(10),
+                    epoch: 10,
+                    window: 11,
+                    epoch_cap: 1000,
+                })
+            ),
+            Error::<Test>::InvalidRedeemRule
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_redeem_rule(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            Some(RedeemRule {
+                exit_fee: Perbill::from_percent(10),
+                epoch: 10,
+                window: 5,
+                epoch_cap: 1000,
+            })
+        ));
+
+        // 1010 * 10000 / 10099 = 1000，退出费 100
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::quote_redeem(dao_id, 1010),
+            Some((900, 100))
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::redeem(RuntimeOrigin::signed(ALICE), dao_id, 1010, 901),
+            Error::<Test>::SlippageExceeded
+        );
+        let before = native();
+        assert_ok!(daoent_assets::Pallet::<Test>::redeem(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            1010,
+            900
+        ));
+        assert_eq!(native(), before + 900);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(dao_id, ALICE).unwrap(),
+            8990
+        );
+        let pool = daoent_dao::Pallet::<Test>::dao_asset(dao_id);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(0, pool).unwrap(),
+            9100
+        );
+
+        // 本周期已赎回 900，再赎回 180 超过上限
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::redeem(RuntimeOrigin::signed(ALICE), dao_id, 200, 0),
+            Error::<Test>::RedeemCapExceeded
+        );
+        System::set_block_number(17);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::redeem(RuntimeOrigin::signed(ALICE), dao_id, 200, 0),
+            Error::<Test>::RedeemClosed
+        );
+        System::set_block_number(22);
+        assert_ok!(daoent_assets::Pallet::<Test>::redeem(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            200,
+            0
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::redeemed_in_epoch(dao_id),
+            (2, 180)
+        );
+    })
+}
+
+#[test]
+pub fn test_redeem_errors() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let redeem = |amount| {
+            daoent_assets::Pallet::<Test>::redeem(RuntimeOrigin::signed(ALICE), dao_id, amount, 0)
+        };
+
+        assert_noop!(redeem(0), Error::<Test>::BalanceTooLow);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::redeem(RuntimeOrigin::signed(ALICE), dao_id + 1, 10, 0),
+            Error::<Test>::QuoteUnavailable
+        );
+
+        assert_ok!(daoent_assets::Pallet::<Test>::set_redeem_rule(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            Some(RedeemRule {
+                exit_fee: Perbill::zero(),
+                epoch: 10,
+                window: 5,
+                epoch_cap: 100,
+            })
+        ));
+
+        // 单次赎回超过周期上限：102 * 10000 / 10099 = 101，失败时不计入本周期
+        System::set_block_number(4);
+        assert_noop!(redeem(102), Error::<Test>::RedeemCapExceeded);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::redeemed_in_epoch(dao_id),
+            (0, 0)
+        );
+
+        // 累计超过周期上限：49 + 59 > 100
+        assert_ok!(redeem(50));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::redeemed_in_epoch(dao_id),
+            (0, 49)
+        );
+        assert_noop!(redeem(60), Error::<Test>::RedeemCapExceeded);
+
+        // 窗口在第 5 个区块关闭
+        System::set_block_number(5);
+        assert_noop!(redeem(1), Error::<Test>::RedeemClosed);
+        System::set_block_number(9);
+        assert_noop!(redeem(1), Error::<Test>::RedeemClosed);
+
+        // 新周期重新计数
+        System::set_block_number(10);
+        assert_ok!(redeem(60));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::redeemed_in_epoch(dao_id),
+            (1, 59)
+        );
+    })
+}
+
+#[test]
+pub fn test_vesting() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let pool = daoent_dao::Pallet::<Test>::dao_asset(dao_id);
+        let balance = |who| daoent_assets::Pallet::<Test>::get_balance(dao_id, who).unwrap();
+        let schedule = VestingSchedule {
+            start: 10,
+            cliff: 5,
+            period: 5,
+            period_count: 4,
+            per_period: 20,
+        };
+
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::create_vesting(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                BOB,
+                VestingSchedule {
+                    period: 0,
+                    ..schedule.clone()
+                }
+            ),
+            Error::<Test>::InvalidVesting
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::create_vesting(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            BOB,
+            schedule.clone()
+        ));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::create_vesting(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                BOB,
+                schedule
+            ),
+            Error::<Test>::VestingExists
+        );
+        assert_eq!(balance(BOB), 80);
+        assert_eq!(balance(pool), 19);
+        assert!(daoent_assets::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            dao_id,
+            1
+        )
+        .is_err());
+
+        // 悬崖期后解锁第一期
+        System::set_block_number(15);
+        assert_ok!(daoent_assets::Pallet::<Test>::claim(
+            RuntimeOrigin::signed(BOB),
+            dao_id
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            dao_id,
+            20
+        ));
+        assert!(daoent_assets::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            dao_id,
+            1
+        )
+        .is_err());
+
+        // 撤销时第二期已归属，剩余 40 退回
+        System::set_block_number(20);
+        assert_ok!(daoent_assets::Pallet::<Test>::revoke_vesting(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            BOB
+        ));
+        assert_eq!(balance(BOB), 20);
+        assert_eq!(balance(pool), 59);
+        assert_ok!(daoent_assets::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            dao_id,
+            20
+        ));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::claim(RuntimeOrigin::signed(BOB), dao_id),
+            Error::<Test>::NoVesting
+        );
+    })
+}
+
+#[test]
+pub fn test_issue_asset() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let pool = daoent_dao::Pallet::<Test>::dao_asset(dao_id);
+        let meta = daoent_assets::DaoAssetMeta {
+            name: "Reputation".as_bytes().to_vec(),
+            symbol: "REP".as_bytes().to_vec(),
+            decimals: 2,
+        };
+        assert!(daoent_assets::Pallet::<Test>::is_dao_asset(dao_id, dao_id));
+
+        assert!(daoent_assets::Pallet::<Test>::issue_asset(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            meta.clone(),
+            500
+        )
+        .is_err());
+        assert_ok!(daoent_assets::Pallet::<Test>::issue_asset(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            meta.clone(),
+            500
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::issue_asset(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            meta,
+            300
+        ));
+
+        // 附加资产的ID从 MaxCreatableId 开始
+        let first = MaxCreatableId::get();
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::asset_owner(first),
+            Some(dao_id)
+        );
+        assert!(daoent_assets::Pallet::<Test>::is_dao_asset(
+            dao_id,
+            first + 1
+        ));
+        assert_eq!(
+            daoent_assets::AssetsOf::<Test>::iter_key_prefix(dao_id).count(),
+            3
+        );
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(first, pool).unwrap(),
+            500
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            first + 1,
+            pool,
+            BOB,
+            100
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(first + 1, BOB).unwrap(),
+            100
+        );
+    })
+}
+
+#[test]
+pub fn test_fee_sponsor() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let sponsor = daoent_dao::Pallet::<Test>::dao_fee_sponsor(dao_id);
+        let rule = SponsorRule {
+            calls: vec![205, 404],
+            per_member: 30,
+            per_period: 50,
+            period: 10,
+        };
+
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 205, 10),
+            None
+        );
+        assert!(daoent_assets::Pallet::<Test>::set_fee_sponsor(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            Some(rule.clone())
+        )
+        .is_err());
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_fee_sponsor(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                Some(SponsorRule {
+                    period: 0,
+                    ..rule.clone()
+                })
+            ),
+            Error::<Test>::InvalidSponsorRule
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_fee_sponsor(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            Some(rule)
+        ));
+
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            0, ALICE, root, 1000
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::fund_fee_sponsor(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            400
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(0, sponsor).unwrap(),
+            400
+        );
+
+        // 只代付成员调用的白名单函数
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 205, 10),
+            Some(sponsor)
+        );
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 201, 10),
+            None
+        );
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &BOB, 205, 10),
+            None
+        );
+
+        // 成员预算
+        daoent_assets::Pallet::<Test>::note_sponsored_fee(dao_id, &ALICE, 25);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 404, 5),
+            Some(sponsor)
+        );
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 404, 6),
+            None
+        );
+
+        // 周期总预算
+        assert_ok!(daoent_dao::Pallet::<Test>::try_add_member(dao_id, BOB));
+        daoent_assets::Pallet::<Test>::note_sponsored_fee(dao_id, &BOB, 20);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &BOB, 205, 5),
+            Some(sponsor)
+        );
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &BOB, 205, 6),
+            None
+        );
+
+        // 新周期重置预算
+        System::set_block_number(System::block_number() + 10);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 205, 30),
+            Some(sponsor)
+        );
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_sponsor_for(dao_id, &ALICE, 205, 31),
+            None
+        );
+    })
+}
+
+#[test]
+pub fn test_fee_in_dao_token() {
+    use orml_traits::MultiCurrency;
+
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let pool = daoent_dao::Pallet::<Test>::dao_asset(dao_id);
+        let tokens = |who| daoent_assets::Pallet::<Test>::get_balance(dao_id, who).unwrap();
+        let native = || daoent_assets::Pallet::<Test>::get_balance(0, pool).unwrap();
+        let issuance = || daoent_assets::Pallet::<Test>::total_issuance(dao_id);
+        let pool_tokens = tokens(pool);
+
+        // 默认不允许用 DAO 通证支付手续费
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::fee_rate(dao_id),
+            FeeRate::Disabled
+        );
+        assert_eq!(daoent_assets::Pallet::<Test>::quote_fee(dao_id, 100), None);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::pay_fee_in_dao_token(dao_id, &ALICE, 100),
+            Error::<Test>::FeeUnpayable
+        );
+
+        assert!(daoent_assets::Pallet::<Test>::set_fee_rate(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            FeeRate::Nav
+        )
+        .is_err());
+        assert_ok!(daoent_assets::Pallet::<Test>::set_fee_rate(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            FeeRate::Nav
+        ));
+
+        // 按净值兑换：100 * 10099 / 10000，向上取整
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::quote_fee(dao_id, 100),
+            Some(101)
+        );
+        assert_eq!(daoent_assets::Pallet::<Test>::quote_fee(0, 100), None);
+
+        // 用户的 DAO 通证被销毁，B 池的 DAO 通证不变
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::pay_fee_in_dao_token(dao_id, &ALICE, 100),
+            Ok(101)
+        );
+        assert_eq!(tokens(ALICE), 10000 - 101);
+        assert_eq!(tokens(pool), pool_tokens);
+        assert_eq!(issuance(), 10000 + pool_tokens - 101);
+        assert_eq!(native(), 10000 - 100);
+
+        // 只用了 40，退还 60 原生通证给 B 池，并为用户重新铸造 60 DAO 通证
+        daoent_assets::Pallet::<Test>::refund_fee_in_dao_token(dao_id, &ALICE, 100, 101, 40);
+        assert_eq!(tokens(ALICE), 10000 - 41);
+        assert_eq!(tokens(pool), pool_tokens);
+        assert_eq!(issuance(), 10000 + pool_tokens - 41);
+        assert_eq!(native(), 10000 - 40);
+
+        // 余额不足
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::pay_fee_in_dao_token(dao_id, &BOB, 100),
+            Error::<Test>::BalanceTooLow
+        );
+
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_fee_rate(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                FeeRate::Fixed(FixedU128::from_inner(0))
+            ),
+            Error::<Test>::InvalidFeeRate
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_fee_rate(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            FeeRate::Fixed(FixedU128::saturating_from_integer(2))
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::quote_fee(dao_id, 101),
+            Some(51)
+        );
+
+        assert_ok!(daoent_assets::Pallet::<Test>::set_fee_rate(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            FeeRate::Disabled
+        ));
+        assert_eq!(daoent_assets::Pallet::<Test>::quote_fee(dao_id, 100), None);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::pay_fee_in_dao_token(dao_id, &ALICE, 100),
+            Error::<Test>::FeeUnpayable
+        );
+    })
+}
+
+#[test]
+pub fn test_allowance() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let tokens = |who| daoent_assets::Pallet::<Test>::get_balance(dao_id, who).unwrap();
+        System::set_block_number(5);
+
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::approve(
+                RuntimeOrigin::signed(ALICE),
+                dao_id + 1,
+                BOB,
+                100,
+                None
+            ),
+            Error::<Test>::MetadataNotExists
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::approve(
+                RuntimeOrigin::signed(ALICE),
+                dao_id,
+                BOB,
+                100,
+                Some(5)
+            ),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::approve(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            100,
+            Some(20)
+        ));
+
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer_from(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                ALICE,
+                103,
+                101
+            ),
+            Error::<Test>::AllowanceExceeded
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer_from(
+                RuntimeOrigin::signed(103),
+                dao_id,
+                ALICE,
+                103,
+                1
+            ),
+            Error::<Test>::AllowanceExpired
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::transfer_from(
+            RuntimeOrigin::signed(BOB),
+            dao_id,
+            ALICE,
+            103,
+            60
+        ));
+        assert_eq!(tokens(ALICE), 10000 - 60);
+        assert_eq!(tokens(103), 60);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::allowance(dao_id, &ALICE, &BOB).map(|a| a.amount),
+            Some(40)
+        );
+
+        assert_ok!(daoent_assets::Pallet::<Test>::increase_allowance(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            10
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::decrease_allowance(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            20
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::allowance(dao_id, &ALICE, &BOB),
+            Some(daoent_assets::Allowance {
+                amount: 30,
+                expires_at: Some(20)
+            })
+        );
+
+        // 额度用完后删除
+        assert_ok!(daoent_assets::Pallet::<Test>::transfer_from(
+            RuntimeOrigin::signed(BOB),
+            dao_id,
+            ALICE,
+            BOB,
+            30
+        ));
+        assert!(daoent_assets::Allowances::<Test>::get(dao_id, (ALICE, BOB)).is_none());
+
+        // 过期后不可使用
+        assert_ok!(daoent_assets::Pallet::<Test>::increase_allowance(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            103,
+            50
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::approve(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            50,
+            Some(10)
+        ));
+        System::set_block_number(10);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::allowance(dao_id, &ALICE, &BOB),
+            None
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer_from(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                ALICE,
+                BOB,
+                1
+            ),
+            Error::<Test>::AllowanceExpired
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::increase_allowance(
+                RuntimeOrigin::signed(ALICE),
+                dao_id,
+                BOB,
+                1
+            ),
+            Error::<Test>::AllowanceExpired
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::transfer_from(
+            RuntimeOrigin::signed(103),
+            dao_id,
+            ALICE,
+            103,
+            50
+        ));
+        assert_eq!(tokens(103), 110);
+    })
+}
+
+#[test]
+pub fn test_allowance_errors() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let allowance =
+            || daoent_assets::Pallet::<Test>::allowance(dao_id, &ALICE, &BOB).map(|a| a.amount);
+
+        // 没有授权额度时不能减少或使用
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::decrease_allowance(
+                RuntimeOrigin::signed(ALICE),
+                dao_id,
+                BOB,
+                1
+            ),
+            Error::<Test>::AllowanceExceeded
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer_from(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                ALICE,
+                BOB,
+                1
+            ),
+            Error::<Test>::AllowanceExpired
+        );
+
+        // 减少的额度超过剩余额度时归零并删除，不会下溢
+        assert_ok!(daoent_assets::Pallet::<Test>::approve(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            10,
+            None
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::decrease_allowance(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            11
+        ));
+        assert_eq!(allowance(), None);
+        assert!(daoent_assets::Allowances::<Test>::get(dao_id, (ALICE, BOB)).is_none());
+
+        // 额度足够但余额不足时，额度不被扣除
+        assert_ok!(daoent_assets::Pallet::<Test>::approve(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            20000,
+            None
+        ));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer_from(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                ALICE,
+                BOB,
+                10001
+            ),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+        assert_eq!(allowance(), Some(20000));
+    })
+}
+
+#[test]
+pub fn test_transfer_policy() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let pool = daoent_dao::Pallet::<Test>::dao_asset(dao_id);
+        let set_policy = |policy| {
+            daoent_assets::Pallet::<Test>::set_transfer_policy(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id,
+                policy,
+            )
+        };
+
+        assert!(daoent_assets::Pallet::<Test>::set_transfer_policy(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            dao_id,
+            TransferPolicy::MembersOnly
+        )
+        .is_err());
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_transfer_policy(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id + 1,
+                TransferPolicy::MembersOnly
+            ),
+            Error::<Test>::NotDaoAsset
+        );
+
+        // 只能转给成员
+        assert_ok!(set_policy(TransferPolicy::MembersOnly));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer(RuntimeOrigin::signed(ALICE), BOB, dao_id, 10),
+            Error::<Test>::TransferRestricted
+        );
+        assert_ok!(daoent_dao::Pallet::<Test>::try_add_member(dao_id, BOB));
+        assert_ok!(daoent_assets::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            dao_id,
+            10
+        ));
+
+        // 白名单
+        assert_ok!(set_policy(TransferPolicy::Allowlist));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::try_transfer(dao_id, ALICE, 103, 10),
+            Error::<Test>::TransferRestricted
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_transfer_allowlist(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            103,
+            true
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, ALICE, 103, 10
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::set_transfer_allowlist(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            103,
+            false
+        ));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::try_transfer(dao_id, ALICE, 103, 10),
+            Error::<Test>::TransferRestricted
+        );
+
+        // 不可转账，只有 DAO 的账户可以转入转出
+        assert_ok!(set_policy(TransferPolicy::NonTransferable));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::try_transfer(dao_id, BOB, ALICE, 5),
+            Error::<Test>::TransferRestricted
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::approve(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            BOB,
+            5,
+            None
+        ));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::transfer_from(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                ALICE,
+                BOB,
+                5
+            ),
+            Error::<Test>::TransferRestricted
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, ALICE, pool, 5
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, pool, 103, 5
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::get_balance(dao_id, 103).unwrap(),
+            15
+        );
+
+        // 原生通证不受影响
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            0, ALICE, BOB, 5
+        ));
+    })
+}
+
+#[test]
+pub fn test_transfer_policy_applies_to_tokens_pallet() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        assert_ok!(daoent_assets::Pallet::<Test>::set_transfer_policy(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            TransferPolicy::NonTransferable
+        ));
+
+        // 绕过本模块直接调用 orml-tokens 转账同样受限
+        assert_noop!(
+            orml_tokens::Pallet::<Test>::transfer(RuntimeOrigin::signed(ALICE), BOB, dao_id, 10),
+            Error::<Test>::TransferRestricted
+        );
+        assert_noop!(
+            orml_tokens::Pallet::<Test>::transfer_all(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                dao_id,
+                false
+            ),
+            Error::<Test>::TransferRestricted
+        );
+    })
+}
+
+#[test]
+pub fn test_mint() {
+    use frame_support::traits::Hooks;
+    use orml_traits::MultiCurrency;
+
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let treasury = daoent_dao::Pallet::<Test>::dao_treasury(dao_id);
+        let balance = |who| daoent_assets::Pallet::<Test>::get_balance(dao_id, who).unwrap();
+        System::set_block_number(1);
+
+        assert!(daoent_assets::Pallet::<Test>::mint(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            dao_id,
+            BOB,
+            100
+        )
+        .is_err());
+        assert_ok!(daoent_assets::Pallet::<Test>::mint(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            BOB,
+            100
+        ));
+        assert_eq!(balance(BOB), 100);
+        System::assert_last_event(RuntimeEvent::DAOAsset(daoent_assets::Event::Minted {
+            dao_id,
+            asset_id: dao_id,
+            to: BOB,
+            amount: 100,
+        }));
+
+        // 供应上限
+        let issuance = daoent_assets::Pallet::<Test>::total_issuance(dao_id);
+        assert_eq!(daoent_assets::Pallet::<Test>::headroom(dao_id), None);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_max_supply(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id,
+                issuance - 1
+            ),
+            Error::<Test>::InvalidSupplyCap
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_max_supply(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            issuance + 50
+        ));
+        assert_eq!(daoent_assets::Pallet::<Test>::headroom(dao_id), Some(50));
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_max_supply(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id,
+                issuance + 51
+            ),
+            Error::<Test>::InvalidSupplyCap
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::mint(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id,
+                BOB,
+                51
+            ),
+            Error::<Test>::SupplyCapExceeded
+        );
+
+        // 通胀增发到国库，直到达到上限
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::set_inflation(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id,
+                Some(InflationRule {
+                    amount: 20,
+                    period: 0
+                })
+            ),
+            Error::<Test>::InvalidInflation
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::set_inflation(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            Some(InflationRule {
+                amount: 20,
+                period: 3
+            })
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::next_inflation_at(dao_id),
+            Some(4)
+        );
+
+        for n in 2..=13 {
+            System::set_block_number(n);
+            daoent_assets::Pallet::<Test>::on_initialize(n);
+            let minted = match n {
+                2 | 3 => 0,
+                4..=6 => 20,
+                7..=9 => 40,
+                _ => 50,
+            };
+            assert_eq!(balance(treasury), minted);
+        }
+        assert_eq!(daoent_assets::Pallet::<Test>::headroom(dao_id), Some(0));
+        assert_eq!(daoent_assets::Pallet::<Test>::inflation(dao_id), None);
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::next_inflation_at(dao_id),
+            None
+        );
+    })
+}
+
+#[test]
+pub fn test_users_number() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let holders = daoent_assets::Pallet::<Test>::users_number(dao_id);
+        assert!(holders > 0);
+
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, ALICE, BOB, 100
+        ));
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::users_number(dao_id),
+            holders + 1
+        );
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, BOB, ALICE, 100
+        ));
+        assert_eq!(daoent_assets::Pallet::<Test>::users_number(dao_id), holders);
+    })
+}
+
+#[test]
+pub fn test_distribute() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let escrow = daoent_dao::Pallet::<Test>::dao_distribution(dao_id);
+        let native = |who| daoent_assets::Pallet::<Test>::get_balance(0, who).unwrap();
+        let tokens = |who| daoent_assets::Pallet::<Test>::get_balance(dao_id, who).unwrap();
+        let pending =
+            |asset, who| daoent_assets::Pallet::<Test>::pending_distribution(dao_id, asset, &who);
+
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            0, ALICE, root, 5000
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, ALICE, BOB, 3000
+        ));
+        // DAO 自身的账户不参与分红
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::distribution_shares(dao_id),
+            10000
+        );
+
+        assert!(daoent_assets::Pallet::<Test>::distribute(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            0,
+            1000
+        )
+        .is_err());
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::distribute(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                dao_id + 1,
+                1000
+            ),
+            Error::<Test>::InvalidRewardAsset
+        );
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::distribute(RuntimeOrigin::signed(root), dao_id, 0, 0),
+            Error::<Test>::DistributionTooSmall
+        );
+
+        assert_ok!(daoent_assets::Pallet::<Test>::distribute(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            0,
+            1000
+        ));
+        assert_eq!(native(escrow), 1000);
+        assert_eq!(pending(0, ALICE), 700);
+        assert_eq!(pending(0, BOB), 300);
+
+        // 余额变化前先结算，后续分红按新余额计算
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            dao_id, BOB, 103, 1000
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::distribute(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            0,
+            1000
+        ));
+        assert_eq!(pending(0, ALICE), 1400);
+        assert_eq!(pending(0, BOB), 500);
+        assert_eq!(pending(0, 103), 100);
+
+        let bob_native = native(BOB);
+        assert_ok!(daoent_assets::Pallet::<Test>::claim_distribution(
+            RuntimeOrigin::signed(103),
+            dao_id,
+            0,
+            BOB
+        ));
+        assert_eq!(native(BOB), bob_native + 500);
+        assert_eq!(pending(0, BOB), 0);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::claim_distribution(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                0,
+                BOB
+            ),
+            Error::<Test>::NothingToClaim
+        );
+
+        // 以 DAO 通证分红
+        assert_ok!(daoent_assets::Pallet::<Test>::mint(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            root,
+            1000
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::distribute(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            dao_id,
+            1000
+        ));
+        assert_eq!(pending(dao_id, ALICE), 700);
+        assert_ok!(daoent_assets::Pallet::<Test>::claim_distribution(
+            RuntimeOrigin::signed(ALICE),
+            dao_id,
+            dao_id,
+            ALICE
+        ));
+        assert_eq!(tokens(ALICE), 7000 + 700);
+        assert_eq!(tokens(escrow), 300);
+        assert_eq!(pending(0, ALICE), 1400);
+    })
+}
+
+#[test]
+pub fn test_distribute_with_tokens_transfer() {
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let native = |who| daoent_assets::Pallet::<Test>::get_balance(0, who).unwrap();
+        let pending =
+            |asset, who| daoent_assets::Pallet::<Test>::pending_distribution(dao_id, asset, &who);
+
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            0, ALICE, root, 5000
+        ));
+        assert_ok!(daoent_assets::Pallet::<Test>::distribute(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            0,
+            1000
+        ));
+        assert_eq!(pending(0, ALICE), 1000);
+
+        // 直接通过 orml-tokens 转给新账户，新账户不分得此前的分红
+        assert_ok!(orml_tokens::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            dao_id,
+            3000
+        ));
+        assert_eq!(pending(0, ALICE), 1000);
+        assert_eq!(pending(0, BOB), 0);
+        assert_noop!(
+            daoent_assets::Pallet::<Test>::claim_distribution(
+                RuntimeOrigin::signed(BOB),
+                dao_id,
+                0,
+                BOB
+            ),
+            Error::<Test>::NothingToClaim
+        );
+
+        assert_ok!(daoent_assets::Pallet::<Test>::distribute(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            0,
+            1000
+        ));
+        let bob_native = native(BOB);
+        assert_ok!(daoent_assets::Pallet::<Test>::claim_distribution(
+            RuntimeOrigin::signed(BOB),
+            dao_id,
+            0,
+            BOB
+        ));
+        assert_eq!(native(BOB), bob_native + 300);
+        assert_eq!(pending(0, ALICE), 1700);
+    })
+}
+
+#[test]
+pub fn test_distribute_settles_every_balance_change() {
+    use orml_traits::{
+        BalanceStatus, MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency,
+    };
+    type Assets = daoent_assets::Pallet<Test>;
+
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        let pending = |who| Assets::pending_distribution(dao_id, 0, &who);
+        let distribute = |amount| {
+            assert_ok!(Assets::distribute(
+                RuntimeOrigin::signed(root),
+                dao_id,
+                0,
+                amount
+            ));
+        };
+
+        assert_ok!(Assets::try_transfer(0, ALICE, root, 5000));
+        assert_ok!(Assets::try_transfer(dao_id, ALICE, BOB, 5000));
+        distribute(1000);
+        assert_eq!((pending(ALICE), pending(BOB)), (500, 500));
+
+        // withdraw、slash 与 update_balance 前先结算，已得的分红不变
+        assert_ok!(<Assets as MultiCurrency<AccountId>>::withdraw(
+            dao_id, &ALICE, 1000
+        ));
+        assert_eq!(
+            <Assets as MultiCurrency<AccountId>>::slash(dao_id, &BOB, 1000),
+            0
+        );
+        assert_ok!(
+            <Assets as MultiCurrencyExtended<AccountId>>::update_balance(dao_id, &ALICE, -1000)
+        );
+        assert_eq!((pending(ALICE), pending(BOB)), (500, 500));
+
+        // 之后的分红按新余额计算：ALICE 3000，BOB 4000
+        distribute(700);
+        assert_eq!((pending(ALICE), pending(BOB)), (800, 900));
+
+        // slash_reserved 与 repatriate_reserved 前同样先结算
+        assert_ok!(<Assets as MultiReservableCurrency<AccountId>>::reserve(
+            dao_id, &ALICE, 1000
+        ));
+        assert_eq!(
+            <Assets as MultiReservableCurrency<AccountId>>::slash_reserved(dao_id, &ALICE, 1000),
+            0
+        );
+        assert_ok!(<Assets as MultiReservableCurrency<AccountId>>::reserve(
+            dao_id, &BOB, 1000
+        ));
+        assert_eq!(
+            <Assets as MultiReservableCurrency<AccountId>>::repatriate_reserved(
+                dao_id,
+                &BOB,
+                &103,
+                1000,
+                BalanceStatus::Free
+            ),
+            Ok(0)
+        );
+        assert_eq!((pending(ALICE), pending(BOB), pending(103)), (800, 900, 0));
+
+        // ALICE 2000，BOB 3000，103 1000
+        distribute(600);
+        assert_eq!(
+            (pending(ALICE), pending(BOB), pending(103)),
+            (1000, 1200, 100)
+        );
+    })
+}
+
+#[test]
+pub fn test_migrate_to_v1() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        StorageVersion::new(0).put::<daoent_assets::Pallet<Test>>();
+        let _ = daoent_assets::AssetOwner::<Test>::clear(u32::MAX, None);
+        let _ = daoent_assets::AssetsOf::<Test>::clear(u32::MAX, None);
+
+        daoent_assets::migrations::v1::migrate::<Test>();
+
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::asset_owner(dao_id),
+            Some(dao_id)
+        );
+        assert!(daoent_assets::Pallet::<Test>::is_dao_asset(dao_id, dao_id));
+        assert_eq!(daoent_assets::Pallet::<Test>::on_chain_storage_version(), 1);
+    })
+}
+
+#[test]
+pub fn test_migrate_to_v2() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let meta = daoent_assets::Pallet::<Test>::asset_info(dao_id)
+            .unwrap()
+            .metadata;
+        StorageVersion::new(1).put::<daoent_assets::Pallet<Test>>();
+        // 按 v1 的编码写入资产信息
+        frame_support::storage::unhashed::put(
+            &daoent_assets::DaoAssetsInfo::<Test>::hashed_key_for(dao_id),
+            &daoent_assets::migrations::v2::OldDaoAssetInfo {
+                owner: ALICE,
+                metadata: meta.clone(),
+            },
+        );
+
+        daoent_assets::migrations::v2::migrate::<Test>();
+
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::asset_info(dao_id),
+            Some(daoent_assets::DaoAssetInfo {
+                owner: ALICE,
+                metadata: meta,
+                max_supply: None,
+            })
+        );
+        assert_eq!(daoent_assets::Pallet::<Test>::on_chain_storage_version(), 2);
+    })
+}
+
+#[test]
+pub fn test_migrate_to_v3() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        let dao_id = create_asset();
+        let root = daoent_dao::Pallet::<Test>::dao_account(dao_id);
+        assert_ok!(daoent_assets::Pallet::<Test>::issue_asset(
+            RuntimeOrigin::signed(root),
+            dao_id,
+            daoent_assets::DaoAssetMeta {
+                name: "Reputation".as_bytes().to_vec(),
+                symbol: "REP".as_bytes().to_vec(),
+                decimals: 2,
+            },
+            500
+        ));
+        StorageVersion::new(2).put::<daoent_assets::Pallet<Test>>();
+        daoent_assets::NextAssetId::<Test>::kill();
+
+        daoent_assets::migrations::v3::migrate::<Test>();
+
+        assert_eq!(
+            daoent_assets::Pallet::<Test>::next_asset_id(),
+            MaxCreatableId::get() + 1
+        );
+        assert_eq!(daoent_assets::Pallet::<Test>::on_chain_storage_version(), 3);
     })
 }
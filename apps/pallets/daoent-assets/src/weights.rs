@@ -14,6 +14,24 @@ pub trait WeightInfo {
 	fn transfer() -> Weight;
 	fn transfer_native_currency() -> Weight;
 	fn update_balance() -> Weight;
+	fn set_join_pricing() -> Weight;
+	fn redeem() -> Weight;
+	fn set_redeem_rule() -> Weight;
+	fn create_vesting() -> Weight;
+	fn claim() -> Weight;
+	fn revoke_vesting() -> Weight;
+	fn issue_asset() -> Weight;
+	fn set_fee_sponsor() -> Weight;
+	fn fund_fee_sponsor() -> Weight;
+	fn set_fee_rate() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn set_transfer_policy() -> Weight;
+	fn mint() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn set_inflation() -> Weight;
+	fn distribute() -> Weight;
+	fn claim_distribution() -> Weight;
 }
 
 /// Weights for pallet_currencies using the Substrate node and recommended hardware.
@@ -51,6 +69,125 @@ impl<T: frame_system::Config> WeightInfo for DicoWeight<T> {
 	fn update_balance() -> Weight {
 		Weight::from_ref_time(20_0000_0000)
 	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies JoinPricingOf (r:0 w:1)
+	fn set_join_pricing() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies DicoAssetsInfo (r:1 w:0)
+	// Storage: Currencies RedeemRuleOf (r:1 w:0)
+	// Storage: Currencies RedeemedInEpoch (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn redeem() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies RedeemRuleOf (r:0 w:1)
+	// Storage: Currencies RedeemedInEpoch (r:0 w:1)
+	fn set_redeem_rule() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies Vesting (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Locks (r:1 w:1)
+	fn create_vesting() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies Vesting (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	fn claim() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies Vesting (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Locks (r:1 w:1)
+	fn revoke_vesting() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies NextAssetId (r:1 w:1)
+	// Storage: Currencies DicoAssetsInfo (r:1 w:1)
+	// Storage: Currencies AssetOwner (r:0 w:1)
+	// Storage: Currencies AssetsOf (r:0 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn issue_asset() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies SponsorRuleOf (r:0 w:1)
+	fn set_fee_sponsor() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn fund_fee_sponsor() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies FeeRateOf (r:0 w:1)
+	fn set_fee_rate() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies DaoAssetsInfo (r:1 w:0)
+	// Storage: Currencies Allowances (r:1 w:1)
+	fn approve() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies DaoAssetsInfo (r:1 w:0)
+	// Storage: Currencies Allowances (r:1 w:1)
+	// Storage: Currencies TransferPolicyOf (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn transfer_from() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies TransferPolicyOf (r:0 w:1)
+	fn set_transfer_policy() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies DaoAssetsInfo (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn mint() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies DaoAssetsInfo (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	fn set_max_supply() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies NextInflationAt (r:1 w:1)
+	// Storage: Currencies InflationQueue (r:0 w:2)
+	// Storage: Currencies InflationOf (r:0 w:1)
+	fn set_inflation() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Storage: Tokens Accounts (r:8 w:2)
+	// Storage: Currencies RewardPerShare (r:1 w:1)
+	fn distribute() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies RewardPerShare (r:2 w:0)
+	// Storage: Currencies HolderRewards (r:2 w:2)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn claim_distribution() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
 }
 
 // For backwards compatibility and tests
@@ -87,4 +224,123 @@ impl WeightInfo for () {
 	fn update_balance() -> Weight {
 		Weight::from_ref_time(20_0000_0000)
 	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies JoinPricingOf (r:0 w:1)
+	fn set_join_pricing() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies DicoAssetsInfo (r:1 w:0)
+	// Storage: Currencies RedeemRuleOf (r:1 w:0)
+	// Storage: Currencies RedeemedInEpoch (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn redeem() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies RedeemRuleOf (r:0 w:1)
+	// Storage: Currencies RedeemedInEpoch (r:0 w:1)
+	fn set_redeem_rule() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies Vesting (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Locks (r:1 w:1)
+	fn create_vesting() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies Vesting (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	fn claim() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies Vesting (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Locks (r:1 w:1)
+	fn revoke_vesting() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies NextAssetId (r:1 w:1)
+	// Storage: Currencies DicoAssetsInfo (r:1 w:1)
+	// Storage: Currencies AssetOwner (r:0 w:1)
+	// Storage: Currencies AssetsOf (r:0 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn issue_asset() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies SponsorRuleOf (r:0 w:1)
+	fn set_fee_sponsor() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn fund_fee_sponsor() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies FeeRateOf (r:0 w:1)
+	fn set_fee_rate() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies DaoAssetsInfo (r:1 w:0)
+	// Storage: Currencies Allowances (r:1 w:1)
+	fn approve() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies DaoAssetsInfo (r:1 w:0)
+	// Storage: Currencies Allowances (r:1 w:1)
+	// Storage: Currencies TransferPolicyOf (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn transfer_from() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies TransferPolicyOf (r:0 w:1)
+	fn set_transfer_policy() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies DaoAssetsInfo (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn mint() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies DaoAssetsInfo (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	fn set_max_supply() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Currencies AssetOwner (r:1 w:0)
+	// Storage: Currencies NextInflationAt (r:1 w:1)
+	// Storage: Currencies InflationQueue (r:0 w:2)
+	// Storage: Currencies InflationOf (r:0 w:1)
+	fn set_inflation() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: CreateDao Daos (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Storage: Tokens Accounts (r:8 w:2)
+	// Storage: Currencies RewardPerShare (r:1 w:1)
+	fn distribute() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
+	// Storage: Currencies RewardPerShare (r:2 w:0)
+	// Storage: Currencies HolderRewards (r:2 w:2)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn claim_distribution() -> Weight {
+		Weight::from_ref_time(20_0000_0000)
+	}
 }
\ No newline at end of file
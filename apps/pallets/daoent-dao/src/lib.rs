@@ -372,6 +372,47 @@ pub mod pallet {
             T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t: 2 })
         }
 
+        /// 获取DAO投票抵押托管账户
+        pub fn dao_pledge(dao_id: DaoAssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t: 3 })
+        }
+
+        /// 获取DAO投票奖励池账户
+        pub fn dao_reward_pool(dao_id: DaoAssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t: 4 })
+        }
+
+        /// 获取DAO手续费代付账户
+        pub fn dao_fee_sponsor(dao_id: DaoAssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t: 5 })
+        }
+
+        /// 获取DAO国库账户，接收通胀增发的通证
+        pub fn dao_treasury(dao_id: DaoAssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t: 6 })
+        }
+
+        /// 获取DAO分红托管账户
+        pub fn dao_distribution(dao_id: DaoAssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t: 7 })
+        }
+
+        /// The DAO account and the accounts derived from it above.
+        /// DAO自身的全部账户
+        pub fn dao_accounts(dao_id: DaoAssetId) -> Vec<T::AccountId> {
+            sp_std::iter::once(Self::dao_account(dao_id))
+                .chain((1..=7u8).map(|t| {
+                    T::PalletId::get().into_sub_account_truncating(DaoAssetAccount { dao_id, t })
+                }))
+                .collect()
+        }
+
+        /// Whether `who` is one of the `dao_accounts`.
+        /// 是否为DAO自身的账户
+        pub fn is_dao_account(dao_id: DaoAssetId, who: &T::AccountId) -> bool {
+            Self::dao_accounts(dao_id).contains(who)
+        }
+
         /// 获取DAO项目账户
         pub fn dao_project(dao_id: DaoAssetId, p_id: ProjectId) -> T::AccountId {
             T::PalletId::get().into_sub_account_truncating(DaoProjectAccount {
@@ -0,0 +1,764 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::type_complexity)]
+
+/// Elections Module
+/// 每个 DAO 的理事会选举
+use codec::{Decode, Encode};
+use frame_support::{inherent::Vec, weights::Weight, RuntimeDebug};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_npos_elections::seq_phragmen;
+use sp_runtime::{
+    traits::{BlockNumberProvider, Saturating},
+    DispatchError, Perbill, SaturatedConversion,
+};
+use sp_std::result;
+
+use daoent_dao::{self};
+use daoent_primitives::{traits::SetCollectiveMembers, types::DaoAssetId};
+use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod weights;
+pub use weights::WeightInfo;
+
+pub use pallet::*;
+
+/// Election parameters of a DAO.
+/// DAO 的选举参数
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ElectionConfig<BlockNumber, Balance> {
+    /// Seats of the council.
+    /// 理事会席位数
+    pub desired_members: u32,
+    /// Runners-up kept to replace members who leave.
+    /// 候补人数
+    pub desired_runners_up: u32,
+    /// Blocks between two elections.
+    /// 任期
+    pub term_duration: BlockNumber,
+    /// DAO tokens a candidate reserves to stand.
+    /// 候选人押金
+    pub candidacy_bond: Balance,
+}
+
+/// A council member or runner-up.
+/// 理事或候补
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SeatHolder<AccountId, Balance> {
+    pub who: AccountId,
+    /// Stake backing the seat in the last election.
+    /// 上次选举中获得的支持
+    pub stake: Balance,
+    /// Reserved candidacy bond.
+    /// 押金
+    pub deposit: Balance,
+}
+
+/// Votes of a member.
+/// 成员的投票
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Voter<AccountId, Balance> {
+    /// Candidates voted for.
+    /// 支持的候选人
+    pub votes: Vec<AccountId>,
+    /// Reserved DAO tokens backing the votes.
+    /// 抵押的 DAO 代币
+    pub stake: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+
+    pub(crate) type BalanceOf<T> = <<T as daoent_assets::Config>::MultiAsset as MultiCurrency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    pub(crate) type SeatHolderOf<T> =
+        SeatHolder<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + daoent_dao::Config + daoent_assets::Config {
+        /// pallet event
+        /// 组件消息
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The collective the elected council is pushed into.
+        /// 接收选举结果的理事会
+        type CollectiveMembers: SetCollectiveMembers<Self::AccountId, DispatchError>;
+
+        /// The most candidates, members and runners-up standing in one election.
+        /// 每次选举的最大候选人数
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
+
+        /// The most voters of a DAO.
+        /// 每个 DAO 的最大投票人数
+        #[pallet::constant]
+        type MaxVoters: Get<u32>;
+
+        /// The most candidates a voter can vote for.
+        /// 每个投票人最多支持的候选人数
+        #[pallet::constant]
+        type MaxVotesPerVoter: Get<u32>;
+
+        /// The most elections run in one block, later ones move to the next block.
+        /// 每个区块最多进行的选举数
+        #[pallet::constant]
+        type MaxElectionsPerBlock: Get<u32>;
+
+        /// The shortest term a DAO can configure.
+        /// 最短任期
+        #[pallet::constant]
+        type MinTermDuration: Get<Self::BlockNumber>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    /// Election parameters of each DAO.
+    /// 每个 DAO 的选举参数
+    #[pallet::storage]
+    #[pallet::getter(fn election_config)]
+    pub type ElectionConfigOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, ElectionConfig<T::BlockNumber, BalanceOf<T>>>;
+
+    /// Candidates of the next election and their bonds, sorted by account.
+    /// 下次选举的候选人
+    #[pallet::storage]
+    #[pallet::getter(fn candidates)]
+    pub type Candidates<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, Vec<(T::AccountId, BalanceOf<T>)>, ValueQuery>;
+
+    /// Council members, sorted by account.
+    /// 理事会成员
+    #[pallet::storage]
+    #[pallet::getter(fn members)]
+    pub type Members<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, Vec<SeatHolderOf<T>>, ValueQuery>;
+
+    /// Runners-up, sorted by stake with the best last.
+    /// 候补成员
+    #[pallet::storage]
+    #[pallet::getter(fn runners_up)]
+    pub type RunnersUp<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, Vec<SeatHolderOf<T>>, ValueQuery>;
+
+    /// Votes of each member.
+    /// 每个成员的投票
+    #[pallet::storage]
+    #[pallet::getter(fn voting)]
+    pub type Voting<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        Voter<T::AccountId, BalanceOf<T>>,
+    >;
+
+    /// Number of voters of each DAO.
+    /// 每个 DAO 的投票人数
+    #[pallet::storage]
+    #[pallet::getter(fn voter_count)]
+    pub type VoterCount<T: Config> = StorageMap<_, Identity, DaoAssetId, u32, ValueQuery>;
+
+    /// Elections held in each DAO.
+    /// 已进行的选举轮数
+    #[pallet::storage]
+    #[pallet::getter(fn election_rounds)]
+    pub type ElectionRounds<T: Config> = StorageMap<_, Identity, DaoAssetId, u32, ValueQuery>;
+
+    /// When the next election of each DAO runs.
+    /// 下次选举的区块
+    #[pallet::storage]
+    #[pallet::getter(fn next_election)]
+    pub type NextElectionAt<T: Config> = StorageMap<_, Identity, DaoAssetId, T::BlockNumber>;
+
+    /// DAOs electing in each block.
+    /// 每个区块进行选举的 DAO
+    #[pallet::storage]
+    pub type ScheduledElections<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<DaoAssetId>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Set the election parameters of a DAO.
+        SetElectionConfig {
+            dao_id: DaoAssetId,
+            config: ElectionConfig<T::BlockNumber, BalanceOf<T>>,
+        },
+        /// A candidate stood for the next election.
+        CandidateSubmitted {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            bond: BalanceOf<T>,
+        },
+        /// A candidate, member or runner-up renounced.
+        Renounced {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+        },
+        /// A member voted or changed their votes.
+        Voted {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            votes: Vec<T::AccountId>,
+            stake: BalanceOf<T>,
+        },
+        /// A voter removed their votes.
+        VoterRemoved {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+        },
+        /// A new term started with the given members and their backing.
+        NewTerm {
+            dao_id: DaoAssetId,
+            members: Vec<(T::AccountId, BalanceOf<T>)>,
+        },
+        /// No one stood, the council is empty.
+        EmptyTerm { dao_id: DaoAssetId },
+        /// The election failed, the council is unchanged.
+        ElectionError { dao_id: DaoAssetId },
+        /// A member left the council, the best runner-up took the seat.
+        MemberRemoved {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            replacement: Option<T::AccountId>,
+        },
+        /// The bond of a candidate or seat holder was slashed to the DAO account.
+        BondSlashed {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+    }
+
+    // Errors inform users that something went wrong.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The DAO has no election parameters.
+        NotConfigured,
+        /// The election parameters are out of bounds.
+        InvalidConfig,
+        /// Already a candidate.
+        DuplicatedCandidate,
+        /// Members stand in the next election automatically.
+        MemberSubmit,
+        /// Runners-up stand in the next election automatically.
+        RunnerUpSubmit,
+        /// Too many candidates.
+        TooManyCandidates,
+        /// Not a candidate, member or runner-up.
+        NotCandidate,
+        /// Not a council member.
+        NotMember,
+        /// Votes can not be empty.
+        NoVotes,
+        /// Too many candidates voted for.
+        TooManyVotes,
+        /// Only candidates, members and runners-up can be voted for.
+        InvalidVote,
+        /// The stake can not be zero.
+        ZeroStake,
+        /// Too many voters.
+        TooManyVoters,
+        /// Not a voter.
+        NotVoter,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut daos = ScheduledElections::<T>::take(now);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            // 超出每区块上限的选举顺延到之后的区块
+            let max = T::MaxElectionsPerBlock::get() as usize;
+            if daos.len() > max {
+                let next = now.saturating_add(T::BlockNumber::from(1u32));
+                for dao_id in daos.split_off(max) {
+                    Self::schedule(dao_id, next);
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+                }
+            }
+            for dao_id in daos {
+                weight = weight.saturating_add(Self::do_phragmen(dao_id, now));
+            }
+            weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the election parameters of a DAO, the first election runs one term later.
+        /// 设置选举参数
+        #[pallet::call_index(001)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_election_config())]
+        pub fn set_election_config(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            config: ElectionConfig<T::BlockNumber, BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+
+            ensure!(
+                config.desired_members > 0
+                    && config
+                        .desired_members
+                        .saturating_add(config.desired_runners_up)
+                        <= T::MaxCandidates::get()
+                    && config.term_duration >= T::MinTermDuration::get(),
+                Error::<T>::InvalidConfig
+            );
+            ElectionConfigOf::<T>::insert(dao_id, config.clone());
+            if !NextElectionAt::<T>::contains_key(dao_id) {
+                Self::schedule(dao_id, Self::now().saturating_add(config.term_duration));
+            }
+            Self::deposit_event(Event::<T>::SetElectionConfig { dao_id, config });
+
+            Ok(().into())
+        }
+
+        /// Stand for the next election, reserving the candidacy bond.
+        /// 报名参选
+        #[pallet::call_index(002)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_candidacy())]
+        pub fn submit_candidacy(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let config = ElectionConfigOf::<T>::get(dao_id).ok_or(Error::<T>::NotConfigured)?;
+
+            ensure!(!Self::is_member(dao_id, &who), Error::<T>::MemberSubmit);
+            ensure!(
+                !Self::is_runner_up(dao_id, &who),
+                Error::<T>::RunnerUpSubmit
+            );
+            let mut candidates = Candidates::<T>::get(dao_id);
+            let pos = candidates
+                .binary_search_by(|c| c.0.cmp(&who))
+                .err()
+                .ok_or(Error::<T>::DuplicatedCandidate)?;
+            let standing = candidates.len()
+                + Members::<T>::decode_len(dao_id).unwrap_or(0)
+                + RunnersUp::<T>::decode_len(dao_id).unwrap_or(0);
+            ensure!(
+                (standing as u32) < T::MaxCandidates::get(),
+                Error::<T>::TooManyCandidates
+            );
+
+            <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::reserve(
+                dao_id,
+                &who,
+                config.candidacy_bond,
+            )?;
+            candidates.insert(pos, (who.clone(), config.candidacy_bond));
+            Candidates::<T>::insert(dao_id, candidates);
+
+            Self::deposit_event(Event::<T>::CandidateSubmitted {
+                dao_id,
+                who,
+                bond: config.candidacy_bond,
+            });
+            Ok(().into())
+        }
+
+        /// Withdraw from the next election, or leave the council or the runners-up. The bond is
+        /// returned, a member's seat goes to the best runner-up.
+        /// 退出选举或辞去席位
+        #[pallet::call_index(003)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::renounce_candidacy())]
+        pub fn renounce_candidacy(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let deposit = if Self::is_member(dao_id, &who) {
+                let (removed, replacement) = Self::remove_and_replace_member(dao_id, &who)?;
+                Self::deposit_event(Event::<T>::MemberRemoved {
+                    dao_id,
+                    who: who.clone(),
+                    replacement,
+                });
+                removed.deposit
+            } else if Self::is_runner_up(dao_id, &who) {
+                let mut runners_up = RunnersUp::<T>::get(dao_id);
+                let pos = runners_up
+                    .iter()
+                    .position(|r| r.who == who)
+                    .ok_or(Error::<T>::NotCandidate)?;
+                let removed = runners_up.remove(pos);
+                RunnersUp::<T>::insert(dao_id, runners_up);
+                removed.deposit
+            } else {
+                let mut candidates = Candidates::<T>::get(dao_id);
+                let pos = candidates
+                    .binary_search_by(|c| c.0.cmp(&who))
+                    .map_err(|_| Error::<T>::NotCandidate)?;
+                let (_, bond) = candidates.remove(pos);
+                Candidates::<T>::insert(dao_id, candidates);
+                bond
+            };
+            <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::unreserve(
+                dao_id, &who, deposit,
+            );
+
+            Self::deposit_event(Event::<T>::Renounced { dao_id, who });
+            Ok(().into())
+        }
+
+        /// Vote for candidates, members and runners-up with reserved DAO tokens. Voting again
+        /// replaces the votes and adjusts the reserve.
+        /// 投票
+        #[pallet::call_index(004)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote())]
+        pub fn vote(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            votes: Vec<T::AccountId>,
+            stake: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                ElectionConfigOf::<T>::contains_key(dao_id),
+                Error::<T>::NotConfigured
+            );
+            ensure!(!votes.is_empty(), Error::<T>::NoVotes);
+            ensure!(
+                votes.len() as u32 <= T::MaxVotesPerVoter::get(),
+                Error::<T>::TooManyVotes
+            );
+            ensure!(stake > BalanceOf::<T>::from(0u32), Error::<T>::ZeroStake);
+
+            let candidates = Candidates::<T>::get(dao_id);
+            for v in votes.iter() {
+                ensure!(
+                    candidates.binary_search_by(|c| c.0.cmp(v)).is_ok()
+                        || Self::is_member(dao_id, v)
+                        || Self::is_runner_up(dao_id, v),
+                    Error::<T>::InvalidVote
+                );
+            }
+
+            let old_stake = match Voting::<T>::get(dao_id, &who) {
+                Some(voter) => voter.stake,
+                None => {
+                    let count = VoterCount::<T>::get(dao_id);
+                    ensure!(count < T::MaxVoters::get(), Error::<T>::TooManyVoters);
+                    VoterCount::<T>::insert(dao_id, count + 1);
+                    BalanceOf::<T>::from(0u32)
+                }
+            };
+            if stake > old_stake {
+                <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::reserve(
+                    dao_id,
+                    &who,
+                    stake.saturating_sub(old_stake),
+                )?;
+            } else {
+                <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::unreserve(
+                    dao_id,
+                    &who,
+                    old_stake.saturating_sub(stake),
+                );
+            }
+            Voting::<T>::insert(
+                dao_id,
+                &who,
+                Voter {
+                    votes: votes.clone(),
+                    stake,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::Voted {
+                dao_id,
+                who,
+                votes,
+                stake,
+            });
+            Ok(().into())
+        }
+
+        /// Remove all votes and release the stake.
+        /// 撤回投票
+        #[pallet::call_index(005)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_voter())]
+        pub fn remove_voter(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let voter = Voting::<T>::take(dao_id, &who).ok_or(Error::<T>::NotVoter)?;
+            VoterCount::<T>::mutate(dao_id, |c| *c = c.saturating_sub(1));
+            <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::unreserve(
+                dao_id,
+                &who,
+                voter.stake,
+            );
+
+            Self::deposit_event(Event::<T>::VoterRemoved { dao_id, who });
+            Ok(().into())
+        }
+
+        /// Remove a member from the council, optionally slashing their bond to the DAO account.
+        /// The best runner-up takes the seat.
+        /// 罢免理事
+        #[pallet::call_index(006)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_member())]
+        pub fn remove_member(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            slash_bond: bool,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            let dao_account = daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+
+            let (removed, replacement) = Self::remove_and_replace_member(dao_id, &who)?;
+            if slash_bond {
+                Self::slash_bond(dao_id, &who, removed.deposit, &dao_account);
+            } else {
+                <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::unreserve(
+                    dao_id,
+                    &who,
+                    removed.deposit,
+                );
+            }
+
+            Self::deposit_event(Event::<T>::MemberRemoved {
+                dao_id,
+                who,
+                replacement,
+            });
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn now() -> T::BlockNumber {
+            frame_system::Pallet::<T>::current_block_number()
+        }
+
+        pub fn is_member(dao_id: DaoAssetId, who: &T::AccountId) -> bool {
+            Members::<T>::get(dao_id)
+                .binary_search_by(|m| m.who.cmp(who))
+                .is_ok()
+        }
+
+        pub fn is_runner_up(dao_id: DaoAssetId, who: &T::AccountId) -> bool {
+            RunnersUp::<T>::get(dao_id).iter().any(|r| &r.who == who)
+        }
+
+        /// 安排选举，区块已满时顺延
+        fn schedule(dao_id: DaoAssetId, at: T::BlockNumber) {
+            let mut at = at;
+            while ScheduledElections::<T>::decode_len(at).unwrap_or(0) as u32
+                >= T::MaxElectionsPerBlock::get()
+            {
+                at = at.saturating_add(T::BlockNumber::from(1u32));
+            }
+            ScheduledElections::<T>::append(at, dao_id);
+            NextElectionAt::<T>::insert(dao_id, at);
+        }
+
+        /// 罚没押金至 DAO 账户
+        fn slash_bond(
+            dao_id: DaoAssetId,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+            dao_account: &T::AccountId,
+        ) {
+            let missing =
+                <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::repatriate_reserved(
+                    dao_id,
+                    who,
+                    dao_account,
+                    amount,
+                    BalanceStatus::Free,
+                )
+                .unwrap_or(amount);
+            Self::deposit_event(Event::<T>::BondSlashed {
+                dao_id,
+                who: who.clone(),
+                amount: amount.saturating_sub(missing),
+            });
+        }
+
+        /// 将理事会推送至集体
+        fn push_members(dao_id: DaoAssetId, members: &[SeatHolderOf<T>]) -> DispatchResult {
+            let ids = members.iter().map(|m| m.who.clone()).collect::<Vec<_>>();
+            let prime = members
+                .iter()
+                .max_by_key(|m| m.stake)
+                .map(|m| m.who.clone());
+            T::CollectiveMembers::set_members_sorted(dao_id, &ids, prime)
+        }
+
+        /// 移除理事，由最好的候补接替
+        fn remove_and_replace_member(
+            dao_id: DaoAssetId,
+            who: &T::AccountId,
+        ) -> result::Result<(SeatHolderOf<T>, Option<T::AccountId>), DispatchError> {
+            let mut members = Members::<T>::get(dao_id);
+            let pos = members
+                .binary_search_by(|m| m.who.cmp(who))
+                .map_err(|_| Error::<T>::NotMember)?;
+            let removed = members.remove(pos);
+
+            let mut runners_up = RunnersUp::<T>::get(dao_id);
+            let replacement = runners_up.pop();
+            if let Some(ref r) = replacement {
+                let pos = members
+                    .binary_search_by(|m| m.who.cmp(&r.who))
+                    .unwrap_or_else(|p| p);
+                members.insert(pos, r.clone());
+                RunnersUp::<T>::insert(dao_id, runners_up);
+            }
+            Self::push_members(dao_id, &members)?;
+            Members::<T>::insert(dao_id, members);
+
+            Ok((removed, replacement.map(|r| r.who)))
+        }
+
+        /// Run sequential Phragmen over the candidates, members and runners-up. Winners with
+        /// the most backing take the seats, the next ones become runners-up. Losing candidates
+        /// forfeit their bond to the DAO account, outgoing seat holders get theirs back.
+        /// 使用顺序 Phragmen 算法进行选举
+        pub(crate) fn do_phragmen(dao_id: DaoAssetId, now: T::BlockNumber) -> Weight {
+            let config = match ElectionConfigOf::<T>::get(dao_id) {
+                Some(config) => config,
+                None => {
+                    NextElectionAt::<T>::remove(dao_id);
+                    return T::DbWeight::get().reads_writes(1, 1);
+                }
+            };
+            Self::schedule(dao_id, now.saturating_add(config.term_duration));
+
+            let candidates = Candidates::<T>::get(dao_id);
+            let members = Members::<T>::get(dao_id);
+            let runners_up = RunnersUp::<T>::get(dao_id);
+            // 候选人、押金，以及是否为现任
+            let mut standing: Vec<(T::AccountId, BalanceOf<T>, bool)> = candidates
+                .iter()
+                .map(|(who, bond)| (who.clone(), *bond, false))
+                .collect();
+            standing.extend(
+                members
+                    .iter()
+                    .chain(runners_up.iter())
+                    .map(|s| (s.who.clone(), s.deposit, true)),
+            );
+            standing.sort_by(|a, b| a.0.cmp(&b.0));
+            let ids = standing.iter().map(|s| s.0.clone()).collect::<Vec<_>>();
+
+            let voters = Voting::<T>::iter_prefix(dao_id)
+                .map(|(who, voter)| {
+                    let votes = voter
+                        .votes
+                        .into_iter()
+                        .filter(|v| ids.binary_search(v).is_ok())
+                        .collect::<Vec<_>>();
+                    (who, voter.stake.saturated_into::<u64>(), votes)
+                })
+                .collect::<Vec<_>>();
+            let weight = T::DbWeight::get().reads_writes(
+                4u64.saturating_add(voters.len() as u64),
+                4u64.saturating_add(standing.len() as u64),
+            );
+
+            if ids.is_empty() {
+                Self::deposit_event(Event::<T>::EmptyTerm { dao_id });
+                return weight;
+            }
+
+            let to_elect = config
+                .desired_members
+                .saturating_add(config.desired_runners_up) as usize;
+            let result = match seq_phragmen::<T::AccountId, Perbill>(to_elect, ids, voters, None) {
+                Ok(result) => result,
+                Err(_) => {
+                    Self::deposit_event(Event::<T>::ElectionError { dao_id });
+                    return weight;
+                }
+            };
+
+            let mut winners = result
+                .winners
+                .into_iter()
+                .filter(|(_, stake)| *stake > 0)
+                .collect::<Vec<_>>();
+            winners.sort_by(|a, b| b.1.cmp(&a.1));
+
+            let seat = |(who, stake): &(T::AccountId, u128)| -> SeatHolderOf<T> {
+                let deposit = standing
+                    .binary_search_by(|s| s.0.cmp(who))
+                    .map(|i| standing[i].1)
+                    .unwrap_or_default();
+                SeatHolder {
+                    who: who.clone(),
+                    stake: (*stake).saturated_into(),
+                    deposit,
+                }
+            };
+            let split = winners.len().min(config.desired_members as usize);
+            let mut new_members = winners[..split].iter().map(seat).collect::<Vec<_>>();
+            new_members.sort_by(|a, b| a.who.cmp(&b.who));
+            // 候补按支持从小到大排列，最好的在最后
+            let mut new_runners_up = winners[split..].iter().map(seat).collect::<Vec<_>>();
+            new_runners_up.reverse();
+
+            let dao_account = daoent_dao::Pallet::<T>::dao_account(dao_id);
+            for (who, deposit, incumbent) in standing.iter() {
+                if winners.iter().any(|w| &w.0 == who) {
+                    continue;
+                }
+                if *incumbent {
+                    <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::unreserve(
+                        dao_id, who, *deposit,
+                    );
+                } else {
+                    Self::slash_bond(dao_id, who, *deposit, &dao_account);
+                }
+            }
+
+            if Self::push_members(dao_id, &new_members).is_err() {
+                Self::deposit_event(Event::<T>::ElectionError { dao_id });
+            }
+            Candidates::<T>::remove(dao_id);
+            RunnersUp::<T>::insert(dao_id, new_runners_up);
+            Self::deposit_event(Event::<T>::NewTerm {
+                dao_id,
+                members: new_members
+                    .iter()
+                    .map(|m| (m.who.clone(), m.stake))
+                    .collect(),
+            });
+            Members::<T>::insert(dao_id, new_members);
+            ElectionRounds::<T>::mutate(dao_id, |r| *r = r.saturating_add(1));
+
+            weight
+        }
+    }
+}
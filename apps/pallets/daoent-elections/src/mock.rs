@@ -0,0 +1,221 @@
+#![allow(dead_code)]
+use super::*;
+
+use crate as daoent_elections;
+use daoent_assets::{self as daoent_assets, asset_adaper_in_pallet::BasicCurrencyAdapter};
+use daoent_primitives::{
+    traits::SetCollectiveMembers,
+    types::{CallId, DaoAssetId},
+};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Contains},
+    PalletId,
+};
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{IdentityLookup, Zero},
+};
+use std::cell::RefCell;
+
+type Amount = i128;
+type Balance = u64;
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: AccountId = 101;
+pub const BOB: AccountId = 102;
+pub const DAO_ID: DaoAssetId = 1;
+
+construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+
+        DAO: daoent_dao::{ Pallet, Call, Event<T>, Storage },
+        DAOAsset: daoent_assets::{ Pallet, Call, Event<T>, Storage },
+        DAOElections: daoent_elections::{ Pallet, Call, Event<T>, Storage },
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type BlockHashCount = BlockHashCount;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type DbWeight = ();
+    type BaseCallFilter = frame_support::traits::Everything;
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+}
+
+thread_local! {
+    pub static COLLECTIVE: RefCell<(Vec<AccountId>, Option<AccountId>)> = RefCell::new((vec![], None));
+}
+
+pub struct TestCollective;
+impl SetCollectiveMembers<AccountId, DispatchError> for TestCollective {
+    fn set_members_sorted(
+        _dao_id: DaoAssetId,
+        members: &[AccountId],
+        prime: Option<AccountId>,
+    ) -> result::Result<(), DispatchError> {
+        COLLECTIVE.with(|c| *c.borrow_mut() = (members.to_vec(), prime));
+        Ok(())
+    }
+}
+
+pub fn collective() -> (Vec<AccountId>, Option<AccountId>) {
+    COLLECTIVE.with(|c| c.borrow().clone())
+}
+
+parameter_types! {
+    pub const MaxCandidates: u32 = 10;
+    pub const MaxVoters: u32 = 10;
+    pub const MaxVotesPerVoter: u32 = 3;
+    pub const MaxElectionsPerBlock: u32 = 1;
+    pub const MinTermDuration: BlockNumber = 5;
+}
+
+impl daoent_elections::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectiveMembers = TestCollective;
+    type MaxCandidates = MaxCandidates;
+    type MaxVoters = MaxVoters;
+    type MaxVotesPerVoter = MaxVotesPerVoter;
+    type MaxElectionsPerBlock = MaxElectionsPerBlock;
+    type MinTermDuration = MinTermDuration;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const TokensMaxReserves: u32 = 50;
+    pub const MaxLocks: u32 = 50;
+}
+
+pub struct DustRemovalWhitelist;
+impl Contains<AccountId> for DustRemovalWhitelist {
+    fn contains(_a: &AccountId) -> bool {
+        false
+    }
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: u64| -> Balance {
+        Zero::zero()
+    };
+}
+
+impl orml_tokens::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CurrencyHooks = ();
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = DaoAssetId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type MaxLocks = MaxLocks;
+    type MaxReserves = TokensMaxReserves;
+    type ReserveIdentifier = [u8; 8];
+    type DustRemovalWhitelist = DustRemovalWhitelist;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+    pub const DaoPalletId: PalletId = PalletId(*b"ent--dao");
+    pub const MaxCreatableId: DaoAssetId = 100;
+}
+
+impl daoent_dao::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type CallId = CallId;
+    type AfterCreate = ();
+    type WeightInfo = ();
+    type MaxMembers = ConstU32<1000000>;
+    type PalletId = DaoPalletId;
+}
+
+impl TryFrom<RuntimeCall> for CallId {
+    type Error = ();
+    fn try_from(call: RuntimeCall) -> Result<Self, Self::Error> {
+        match call {
+            RuntimeCall::DAOElections(func) => match func {
+                daoent_elections::Call::set_election_config { .. } => Ok(601 as CallId),
+                daoent_elections::Call::remove_member { .. } => Ok(602 as CallId),
+                _ => Err(()),
+            },
+            _ => Err(()),
+        }
+    }
+}
+
+impl daoent_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxCreatableId = MaxCreatableId;
+    type MultiAsset = Tokens;
+    type NativeAsset = BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
+}
+
+pub(crate) fn new_test_run() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, 100000), (BOB, 10000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+    });
+    ext
+}
@@ -0,0 +1,279 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use mock::*;
+
+fn balance(who: AccountId) -> u64 {
+    <daoent_assets::Pallet<Test> as MultiCurrency<AccountId>>::free_balance(DAO_ID, &who)
+}
+
+fn reserved(who: AccountId) -> u64 {
+    <daoent_assets::Pallet<Test> as MultiReservableCurrency<AccountId>>::reserved_balance(
+        DAO_ID, &who,
+    )
+}
+
+pub fn setup() {
+    assert_ok!(daoent_dao::Pallet::<Test>::create_dao(
+        RuntimeOrigin::signed(ALICE),
+        vec![1; 4],
+        vec![]
+    ));
+    for who in [1, 2, 3, 4, 11, 12, 13] {
+        assert_ok!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<AccountId>>::deposit(DAO_ID, &who, 1000)
+        );
+    }
+    let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+    assert_ok!(Pallet::<Test>::set_election_config(
+        RuntimeOrigin::signed(root),
+        DAO_ID,
+        ElectionConfig {
+            desired_members: 2,
+            desired_runners_up: 1,
+            term_duration: 10,
+            candidacy_bond: 10,
+        }
+    ));
+    assert_eq!(Pallet::<Test>::next_election(DAO_ID), Some(11));
+}
+
+pub fn first_term() {
+    setup();
+    for who in [1, 2, 3, 4] {
+        assert_ok!(Pallet::<Test>::submit_candidacy(
+            RuntimeOrigin::signed(who),
+            DAO_ID
+        ));
+    }
+    assert_ok!(Pallet::<Test>::vote(
+        RuntimeOrigin::signed(11),
+        DAO_ID,
+        vec![1, 2],
+        100
+    ));
+    assert_ok!(Pallet::<Test>::vote(
+        RuntimeOrigin::signed(12),
+        DAO_ID,
+        vec![3],
+        50
+    ));
+    assert_ok!(Pallet::<Test>::vote(
+        RuntimeOrigin::signed(13),
+        DAO_ID,
+        vec![2],
+        30
+    ));
+    System::set_block_number(11);
+    Pallet::<Test>::on_initialize(11);
+}
+
+#[test]
+pub fn set_election_config_should_work() {
+    new_test_run().execute_with(|| {
+        assert_ok!(daoent_dao::Pallet::<Test>::create_dao(
+            RuntimeOrigin::signed(ALICE),
+            vec![1; 4],
+            vec![]
+        ));
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        let config = ElectionConfig {
+            desired_members: 0,
+            desired_runners_up: 1,
+            term_duration: 10,
+            candidacy_bond: 10,
+        };
+        assert_noop!(
+            Pallet::<Test>::set_election_config(
+                RuntimeOrigin::signed(root),
+                DAO_ID,
+                config.clone()
+            ),
+            Error::<Test>::InvalidConfig
+        );
+        assert_noop!(
+            Pallet::<Test>::set_election_config(
+                RuntimeOrigin::signed(root),
+                DAO_ID,
+                ElectionConfig {
+                    desired_members: 2,
+                    term_duration: 1,
+                    ..config.clone()
+                }
+            ),
+            Error::<Test>::InvalidConfig
+        );
+        assert!(Pallet::<Test>::set_election_config(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            ElectionConfig {
+                desired_members: 2,
+                ..config
+            }
+        )
+        .is_err());
+    });
+}
+
+#[test]
+pub fn candidacy_should_work() {
+    new_test_run().execute_with(|| {
+        setup();
+        assert_ok!(Pallet::<Test>::submit_candidacy(
+            RuntimeOrigin::signed(1),
+            DAO_ID
+        ));
+        assert_eq!(reserved(1), 10);
+        assert_noop!(
+            Pallet::<Test>::submit_candidacy(RuntimeOrigin::signed(1), DAO_ID),
+            Error::<Test>::DuplicatedCandidate
+        );
+        assert_noop!(
+            Pallet::<Test>::vote(RuntimeOrigin::signed(11), DAO_ID, vec![5], 10),
+            Error::<Test>::InvalidVote
+        );
+        assert_noop!(
+            Pallet::<Test>::vote(RuntimeOrigin::signed(11), DAO_ID, vec![1, 1, 1, 1], 10),
+            Error::<Test>::TooManyVotes
+        );
+
+        assert_ok!(Pallet::<Test>::vote(
+            RuntimeOrigin::signed(11),
+            DAO_ID,
+            vec![1],
+            100
+        ));
+        assert_ok!(Pallet::<Test>::vote(
+            RuntimeOrigin::signed(11),
+            DAO_ID,
+            vec![1],
+            40
+        ));
+        assert_eq!(reserved(11), 40);
+        assert_eq!(Pallet::<Test>::voter_count(DAO_ID), 1);
+
+        assert_ok!(Pallet::<Test>::renounce_candidacy(
+            RuntimeOrigin::signed(1),
+            DAO_ID
+        ));
+        assert_eq!(reserved(1), 0);
+        assert!(Pallet::<Test>::candidates(DAO_ID).is_empty());
+
+        assert_ok!(Pallet::<Test>::remove_voter(
+            RuntimeOrigin::signed(11),
+            DAO_ID
+        ));
+        assert_eq!(reserved(11), 0);
+        assert_eq!(Pallet::<Test>::voter_count(DAO_ID), 0);
+    });
+}
+
+#[test]
+pub fn phragmen_election_should_work() {
+    new_test_run().execute_with(|| {
+        first_term();
+
+        let members = Pallet::<Test>::members(DAO_ID)
+            .into_iter()
+            .map(|m| m.who)
+            .collect::<Vec<_>>();
+        assert_eq!(members, vec![1, 2]);
+        let runners_up = Pallet::<Test>::runners_up(DAO_ID)
+            .into_iter()
+            .map(|m| m.who)
+            .collect::<Vec<_>>();
+        assert_eq!(runners_up, vec![3]);
+        assert_eq!(collective(), (vec![1, 2], Some(2)));
+        assert!(Pallet::<Test>::candidates(DAO_ID).is_empty());
+        assert_eq!(Pallet::<Test>::election_rounds(DAO_ID), 1);
+        assert_eq!(Pallet::<Test>::next_election(DAO_ID), Some(21));
+
+        // 落选者押金罚没至 DAO 账户
+        assert_eq!(reserved(4), 0);
+        assert_eq!(balance(4), 990);
+        assert_eq!(balance(daoent_dao::Pallet::<Test>::dao_account(DAO_ID)), 10);
+        assert_eq!(reserved(1), 10);
+        assert_eq!(reserved(3), 10);
+
+        assert_noop!(
+            Pallet::<Test>::submit_candidacy(RuntimeOrigin::signed(1), DAO_ID),
+            Error::<Test>::MemberSubmit
+        );
+        assert_noop!(
+            Pallet::<Test>::submit_candidacy(RuntimeOrigin::signed(3), DAO_ID),
+            Error::<Test>::RunnerUpSubmit
+        );
+    });
+}
+
+#[test]
+pub fn runner_up_should_replace_member() {
+    new_test_run().execute_with(|| {
+        first_term();
+
+        assert_ok!(Pallet::<Test>::renounce_candidacy(
+            RuntimeOrigin::signed(1),
+            DAO_ID
+        ));
+        assert_eq!(reserved(1), 0);
+        let members = Pallet::<Test>::members(DAO_ID)
+            .into_iter()
+            .map(|m| m.who)
+            .collect::<Vec<_>>();
+        assert_eq!(members, vec![2, 3]);
+        assert!(Pallet::<Test>::runners_up(DAO_ID).is_empty());
+        assert_eq!(collective().0, vec![2, 3]);
+
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        assert_ok!(Pallet::<Test>::remove_member(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            3,
+            true
+        ));
+        assert_eq!(reserved(3), 0);
+        assert_eq!(balance(3), 990);
+        assert_eq!(collective().0, vec![2]);
+        assert_noop!(
+            Pallet::<Test>::remove_member(RuntimeOrigin::signed(root), DAO_ID, 3, true),
+            Error::<Test>::NotMember
+        );
+
+        // 下一任期，现任自动参选
+        System::set_block_number(21);
+        Pallet::<Test>::on_initialize(21);
+        let members = Pallet::<Test>::members(DAO_ID)
+            .into_iter()
+            .map(|m| m.who)
+            .collect::<Vec<_>>();
+        assert_eq!(members, vec![2]);
+        assert_eq!(Pallet::<Test>::election_rounds(DAO_ID), 2);
+        assert_eq!(reserved(2), 10);
+    });
+}
+
+#[test]
+pub fn elections_over_the_block_limit_should_be_deferred() {
+    new_test_run().execute_with(|| {
+        setup();
+        // MaxElectionsPerBlock is 1, e.g. after the limit was lowered
+        ScheduledElections::<Test>::append(11, 2);
+        ScheduledElections::<Test>::append(11, 3);
+
+        System::set_block_number(11);
+        Pallet::<Test>::on_initialize(11);
+        assert!(ScheduledElections::<Test>::get(11).is_empty());
+        assert_eq!(Pallet::<Test>::next_election(DAO_ID), Some(21));
+        assert_eq!(ScheduledElections::<Test>::get(12), vec![2]);
+        assert_eq!(ScheduledElections::<Test>::get(13), vec![3]);
+        assert_eq!(Pallet::<Test>::next_election(2), Some(12));
+        assert_eq!(Pallet::<Test>::next_election(3), Some(13));
+
+        System::set_block_number(12);
+        Pallet::<Test>::on_initialize(12);
+        assert!(ScheduledElections::<Test>::get(12).is_empty());
+        // DAO 2 has no election parameters, its elections stop
+        assert_eq!(Pallet::<Test>::next_election(2), None);
+    });
+}
@@ -0,0 +1,115 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for daoent_elections.
+pub trait WeightInfo {
+    fn set_election_config() -> Weight;
+    fn submit_candidacy() -> Weight;
+    fn renounce_candidacy() -> Weight;
+    fn vote() -> Weight;
+    fn remove_voter() -> Weight;
+    fn remove_member() -> Weight;
+}
+
+/// Weights for daoent_elections using the Substrate node and recommended hardware.
+pub struct DaosWeight<T>(PhantomData<T>);
+    impl<T: frame_system::Config> WeightInfo for DaosWeight<T> {
+        // Storage: CreateDao Daos (r:1 w:0)
+        // Storage: DaoElections ElectionConfigOf (r:0 w:1)
+        // Storage: DaoElections NextElectionAt (r:1 w:1)
+        // Storage: DaoElections ScheduledElections (r:1 w:1)
+    fn set_election_config() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections ElectionConfigOf (r:1 w:0)
+        // Storage: DaoElections Candidates (r:1 w:1)
+        // Storage: DaoElections Members (r:2 w:0)
+        // Storage: DaoElections RunnersUp (r:2 w:0)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn submit_candidacy() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections Members (r:1 w:1)
+        // Storage: DaoElections RunnersUp (r:1 w:1)
+        // Storage: DaoElections Candidates (r:1 w:1)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn renounce_candidacy() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections ElectionConfigOf (r:1 w:0)
+        // Storage: DaoElections Candidates (r:1 w:0)
+        // Storage: DaoElections Members (r:16 w:0)
+        // Storage: DaoElections RunnersUp (r:16 w:0)
+        // Storage: DaoElections Voting (r:1 w:1)
+        // Storage: DaoElections VoterCount (r:1 w:1)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn vote() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections Voting (r:1 w:1)
+        // Storage: DaoElections VoterCount (r:1 w:1)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn remove_voter() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: CreateDao Daos (r:1 w:0)
+        // Storage: DaoElections Members (r:1 w:1)
+        // Storage: DaoElections RunnersUp (r:1 w:1)
+        // Storage: Tokens Accounts (r:2 w:2)
+    fn remove_member() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+        // Storage: CreateDao Daos (r:1 w:0)
+        // Storage: DaoElections ElectionConfigOf (r:0 w:1)
+        // Storage: DaoElections NextElectionAt (r:1 w:1)
+        // Storage: DaoElections ScheduledElections (r:1 w:1)
+    fn set_election_config() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections ElectionConfigOf (r:1 w:0)
+        // Storage: DaoElections Candidates (r:1 w:1)
+        // Storage: DaoElections Members (r:2 w:0)
+        // Storage: DaoElections RunnersUp (r:2 w:0)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn submit_candidacy() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections Members (r:1 w:1)
+        // Storage: DaoElections RunnersUp (r:1 w:1)
+        // Storage: DaoElections Candidates (r:1 w:1)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn renounce_candidacy() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections ElectionConfigOf (r:1 w:0)
+        // Storage: DaoElections Candidates (r:1 w:0)
+        // Storage: DaoElections Members (r:16 w:0)
+        // Storage: DaoElections RunnersUp (r:16 w:0)
+        // Storage: DaoElections Voting (r:1 w:1)
+        // Storage: DaoElections VoterCount (r:1 w:1)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn vote() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: DaoElections Voting (r:1 w:1)
+        // Storage: DaoElections VoterCount (r:1 w:1)
+        // Storage: Tokens Accounts (r:1 w:1)
+    fn remove_voter() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+        // Storage: CreateDao Daos (r:1 w:0)
+        // Storage: DaoElections Members (r:1 w:1)
+        // Storage: DaoElections RunnersUp (r:1 w:1)
+        // Storage: Tokens Accounts (r:2 w:2)
+    fn remove_member() -> Weight {
+            Weight::from_ref_time(20_0000_0000)
+    }
+}
@@ -0,0 +1,45 @@
+//! Runtime API definition for the gov pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use daoent_gov::{
+    DryRunResult, DryRunTarget, ProposalSummary, ReferendumIndex, ReferendumSummary, VoterSummary,
+};
+use daoent_primitives::types::DaoAssetId;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Governance queries for front-ends.
+    /// 治理查询接口
+    pub trait DaoGovApi<AccountId, Balance, BlockNumber, Hash, Pledge> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+        Pledge: Codec,
+    {
+        /// Open proposals of a DAO with their backing.
+        /// 获取 DAO 的提案及其支持情况
+        fn open_proposals(dao_id: DaoAssetId) -> Vec<ProposalSummary<AccountId, Balance, Hash>>;
+
+        /// Status of a referendum with the current tally, whether it would pass,
+        /// and the blocks remaining until end and enactment.
+        /// 获取全民公投的状态
+        fn referendum_status(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        ) -> Option<ReferendumSummary<Balance, BlockNumber>>;
+
+        /// Active votes and unlockable amounts of an account.
+        /// 获取用户的投票与可解锁数量
+        fn voter_votes(who: AccountId) -> VoterSummary<Pledge, Balance, BlockNumber>;
+
+        /// Dispatch a proposal from the DAO account in a storage transaction that is
+        /// always rolled back, returning the result, weight, events and balance changes.
+        /// 模拟执行提案，不会修改链上状态
+        fn dry_run(dao_id: DaoAssetId, target: DryRunTarget) -> Option<DryRunResult<AccountId, Balance>>;
+    }
+}
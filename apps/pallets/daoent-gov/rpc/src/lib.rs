@@ -0,0 +1,159 @@
+//! RPC interface for the gov pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use daoent_gov::{
+    DryRunResult, DryRunTarget, ProposalSummary, ReferendumIndex, ReferendumSummary, VoterSummary,
+};
+use daoent_primitives::types::DaoAssetId;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use daoent_gov_runtime_api::DaoGovApi as DaoGovRuntimeApi;
+
+#[rpc(client, server)]
+pub trait DaoGovApi<BlockHash, AccountId, Balance, BlockNumber, Hash, Pledge> {
+    /// Open proposals of a DAO with their backing.
+    #[method(name = "daoGov_openProposals")]
+    fn open_proposals(
+        &self,
+        dao_id: DaoAssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalSummary<AccountId, Balance, Hash>>>;
+
+    /// Status of a referendum.
+    #[method(name = "daoGov_referendumStatus")]
+    fn referendum_status(
+        &self,
+        dao_id: DaoAssetId,
+        index: ReferendumIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ReferendumSummary<Balance, BlockNumber>>>;
+
+    /// Active votes and unlockable amounts of an account.
+    #[method(name = "daoGov_voterVotes")]
+    fn voter_votes(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VoterSummary<Pledge, Balance, BlockNumber>>;
+
+    /// Simulate enacting a proposal or referendum, nothing is written to storage.
+    #[method(name = "daoGov_dryRun")]
+    fn dry_run(
+        &self,
+        dao_id: DaoAssetId,
+        target: DryRunTarget,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DryRunResult<AccountId, Balance>>>;
+}
+
+/// Provides RPC methods to query the governance state.
+pub struct DaoGov<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> DaoGov<C, P> {
+    /// Creates a new instance of the DaoGov Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        message,
+        Some(format!("{:?}", e)),
+    ))
+    .into()
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber, Hash, Pledge>
+    DaoGovApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber, Hash, Pledge>
+    for DaoGov<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DaoGovRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash, Pledge>,
+    AccountId: Codec,
+    Balance: Codec,
+    BlockNumber: Codec,
+    Hash: Codec,
+    Pledge: Codec,
+{
+    fn open_proposals(
+        &self,
+        dao_id: DaoAssetId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ProposalSummary<AccountId, Balance, Hash>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.open_proposals(&at, dao_id)
+            .map_err(|e| runtime_error("Unable to query proposals.", e))
+    }
+
+    fn referendum_status(
+        &self,
+        dao_id: DaoAssetId,
+        index: ReferendumIndex,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ReferendumSummary<Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.referendum_status(&at, dao_id, index)
+            .map_err(|e| runtime_error("Unable to query referendum status.", e))
+    }
+
+    fn voter_votes(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<VoterSummary<Pledge, Balance, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.voter_votes(&at, who)
+            .map_err(|e| runtime_error("Unable to query votes.", e))
+    }
+
+    fn dry_run(
+        &self,
+        dao_id: DaoAssetId,
+        target: DryRunTarget,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<DryRunResult<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.dry_run(&at, dao_id, target)
+            .map_err(|e| runtime_error("Unable to dry-run the proposal.", e))
+    }
+}
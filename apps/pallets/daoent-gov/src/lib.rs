@@ -4,29 +4,41 @@
 use codec::{Decode, Encode};
 use frame_support::inherent::Vec;
 use frame_support::{
-    dispatch::{DispatchResult as DResult, UnfilteredDispatchable},
-    RuntimeDebug,
+    dispatch::{DispatchResult as DResult, GetDispatchInfo, UnfilteredDispatchable},
+    storage::{with_transaction, TransactionOutcome},
+    traits::{Get, UnixTime},
+    weights::Weight,
+    BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
-    traits::{BlockNumberProvider, CheckedAdd, CheckedMul, Hash, Saturating},
-    DispatchError,
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{BlockNumberProvider, CheckedAdd, CheckedMul, Hash, SaturatedConversion, Saturating},
+    DispatchError, Perbill, Rounding,
 };
 use sp_std::boxed::Box;
 use sp_std::result;
 use traits::*;
 
-use orml_traits::MultiCurrency;
+use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency};
 
 use daoent_dao::{self};
-use daoent_primitives::types::DaoAssetId;
+use daoent_primitives::{
+    traits::SetCollectiveMembers,
+    types::{DaoAssetId, MemberCount},
+};
 
 use weights::WeightInfo;
 
 pub use pallet::*;
 
 pub type PropIndex = u32;
+/// Milliseconds since the Unix epoch, as reported by the runtime's `Timestamp` pallet.
+pub type Moment = u64;
 pub type ReferendumIndex = u32;
+pub type OptimisticIndex = u32;
 
 #[cfg(test)]
 mod mock;
@@ -36,11 +48,13 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod traits;
 pub mod weights;
 
 /// vote yes or no
 /// 投票
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum MemmberData<ID> {
     /// 全局.
@@ -53,6 +67,7 @@ pub enum MemmberData<ID> {
 
 /// Voting Statistics.
 /// 投票数据统计
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct Tally<Balance> {
     /// The number of yes votes
@@ -65,6 +80,7 @@ pub struct Tally<Balance> {
 
 /// vote yes or no
 /// 投票
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum Opinion {
     /// Agree.
@@ -129,6 +145,439 @@ pub enum ReferendumInfo<BlockNumber, Call, Balance> {
     Finished { approved: bool, end: BlockNumber },
 }
 
+/// Governance parameters of a DAO.
+/// DAO 治理参数
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct GovConfig<BlockNumber, Balance> {
+    /// Maximum number of public proposals at one time.
+    /// 同时存在的最大提案数
+    pub max_public_props: u32,
+    /// How soon can a referendum be called.
+    /// 全民公投的启动间隔
+    pub launch_period: BlockNumber,
+    /// Minimum stake per person when making public proposals.
+    /// 提案的最小押金
+    pub minimum_deposit: Balance,
+    /// How long each proposal can be voted on.
+    /// 投票时长
+    pub voting_period: BlockNumber,
+    /// How long does it take to release the mortgage.
+    /// 押金解锁时长
+    pub reserve_period: BlockNumber,
+    /// How soon after voting closes the proposal can be implemented.
+    /// 投票结束后的执行延迟
+    pub enactment_period: BlockNumber,
+    /// 0: 1 token = 1 vote, 1: 1 account = 1 vote.
+    /// 投票模式
+    pub vote_model: u8,
+    /// Maximum dispatch weight of a proposal.
+    /// 提案执行的最大权重
+    pub max_proposal_weight: Weight,
+    /// Vote weight of a membership NFT pledge when 1 token = 1 vote.
+    /// 抵押会员 NFT 的投票权重
+    pub nft_vote_weight: Balance,
+}
+
+/// A partial update of `GovConfig`, `None` keeps the current value.
+/// 治理参数的部分更新
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct GovConfigPatch<BlockNumber, Balance> {
+    pub max_public_props: Option<u32>,
+    pub launch_period: Option<BlockNumber>,
+    pub minimum_deposit: Option<Balance>,
+    pub voting_period: Option<BlockNumber>,
+    pub reserve_period: Option<BlockNumber>,
+    pub enactment_period: Option<BlockNumber>,
+    pub vote_model: Option<u8>,
+    pub max_proposal_weight: Option<Weight>,
+    pub nft_vote_weight: Option<Balance>,
+}
+
+impl<BlockNumber, Balance> GovConfig<BlockNumber, Balance> {
+    /// Apply a partial update.
+    /// 应用部分更新
+    pub fn patch(&mut self, patch: GovConfigPatch<BlockNumber, Balance>) {
+        if let Some(v) = patch.max_public_props {
+            self.max_public_props = v;
+        }
+        if let Some(v) = patch.launch_period {
+            self.launch_period = v;
+        }
+        if let Some(v) = patch.minimum_deposit {
+            self.minimum_deposit = v;
+        }
+        if let Some(v) = patch.voting_period {
+            self.voting_period = v;
+        }
+        if let Some(v) = patch.reserve_period {
+            self.reserve_period = v;
+        }
+        if let Some(v) = patch.enactment_period {
+            self.enactment_period = v;
+        }
+        if let Some(v) = patch.vote_model {
+            self.vote_model = v;
+        }
+        if let Some(v) = patch.max_proposal_weight {
+            self.max_proposal_weight = v;
+        }
+        if let Some(v) = patch.nft_vote_weight {
+            self.nft_vote_weight = v;
+        }
+    }
+}
+
+/// Per-account limits on proposing in a DAO.
+/// 每个账户的提案限制
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProposerLimits<BlockNumber> {
+    /// Most open proposals an account can propose or second, 0 for no limit.
+    /// 每个账户同时存在的最大提案数
+    pub max_open_proposals: u32,
+    /// Blocks an account waits between two submissions.
+    /// 两次提交之间的冷却期
+    pub cooldown: BlockNumber,
+    /// Deposit discount for each `MemberPoint`.
+    /// 每个贡献点的押金折扣
+    pub discount_per_point: Perbill,
+    /// The largest deposit discount.
+    /// 最大押金折扣
+    pub max_discount: Perbill,
+}
+
+/// Participation rewards paid from the DAO reward pool to voters of finished referendums.
+/// 投票参与奖励规则
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VoteRewardRule<Balance> {
+    /// DAO tokens paid per unit of vote weight.
+    /// 每单位投票权重的奖励
+    pub rate: Perbill,
+    /// The most paid out to all voters of one referendum.
+    /// 每次全民公投的奖励上限
+    pub cap: Balance,
+}
+
+/// Rewards set aside for the voters of a finished referendum.
+/// 为已结束全民公投的投票者预留的奖励
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VoteRewardPot<Balance> {
+    /// Total vote weight counted in the referendum.
+    pub total_weight: Balance,
+    /// Rewards set aside, shared in proportion to vote weight.
+    pub budget: Balance,
+    /// Rewards not claimed yet.
+    pub remaining: Balance,
+}
+
+/// How the member house takes part in a bicameral referendum. In every house a majority
+/// means strictly more votes, so a tie neither approves nor vetoes.
+/// 成员院参与双院制投票的方式，平票既不算通过也不算否决
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BicameralRule {
+    /// Both the token house and a majority of voting members must approve.
+    /// 代币院与成员院都需通过
+    BothHouses,
+    /// The token house decides, a majority of voting members against vetoes.
+    /// 代币院决定，成员院多数反对时否决
+    MemberVeto,
+}
+
+impl BicameralRule {
+    /// Whether the member house lets the proposal pass.
+    pub fn member_house_passes(&self, member_tally: &Tally<MemberCount>) -> bool {
+        match self {
+            BicameralRule::BothHouses => member_tally.yes > member_tally.no,
+            BicameralRule::MemberVeto => member_tally.no <= member_tally.yes,
+        }
+    }
+}
+
+/// Governance periods in milliseconds, for DAOs that measure them in wall-clock time.
+/// 以毫秒计的治理时长
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TimePeriods {
+    /// 全民公投的启动间隔
+    pub launch_period: Moment,
+    /// 投票时长
+    pub voting_period: Moment,
+    /// 押金解锁时长
+    pub reserve_period: Moment,
+    /// 投票结束后的执行延迟
+    pub enactment_period: Moment,
+}
+
+/// End of voting and enactment delay of a time-based referendum.
+/// 按时间计的全民公投结束时间与执行延迟
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumMoments {
+    pub end: Moment,
+    pub delay: Moment,
+}
+
+/// Where a referendum is on its clock, in blocks, or in milliseconds for time-based
+/// referendums.
+/// 全民公投的时钟，单位为区块或毫秒
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct ReferendumClock {
+    pub now: u64,
+    pub end: u64,
+    pub delay: u64,
+    /// Clock units per block, 1 for block-based referendums.
+    pub unit: u64,
+}
+
+impl ReferendumClock {
+    /// Whether voting is still open.
+    pub fn voting(&self) -> bool {
+        self.now < self.end
+    }
+
+    /// When the proposal can be enacted.
+    pub fn enactment(&self) -> u64 {
+        self.end.saturating_add(self.delay)
+    }
+
+    /// A period given in blocks, in clock units.
+    pub fn span(&self, blocks: u64) -> u64 {
+        blocks.saturating_mul(self.unit)
+    }
+}
+
+/// Pass as the guild of `set_emergency_guild` to grant emergency powers to the elected council.
+/// 以选举产生的理事会作为紧急公会
+pub const COUNCIL_GUILD: u64 = u64::MAX;
+
+/// The guild granted emergency powers in a DAO.
+/// 拥有紧急权限的公会
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EmergencyGuild {
+    /// The guild id, guild 0 is the core team.
+    /// 公会 id，0 为核心团队
+    pub guild_id: u64,
+    /// Number of guild members needed to take an emergency action.
+    /// 执行紧急操作需要的公会成员数量
+    pub threshold: MemberCount,
+}
+
+/// A pending fast-track of a referendum.
+/// 等待中的紧急加速
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxApprovals))]
+pub struct FastTrackInfo<AccountId, BlockNumber, MaxApprovals: Get<u32>> {
+    /// The new voting period, counted from the fast-track.
+    /// 新的投票时长
+    pub voting_period: BlockNumber,
+    /// The new enactment delay.
+    /// 新的执行延迟
+    pub enactment_period: BlockNumber,
+    /// Guild members who approved.
+    /// 已同意的公会成员
+    pub approvals: BoundedVec<AccountId, MaxApprovals>,
+}
+
+/// How calls of a `CallId` can take the optimistic track.
+/// 乐观执行规则
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OptimisticRule<Balance, BlockNumber> {
+    /// Who can submit and object.
+    /// 可以提交与反对的成员范围
+    pub member_data: MemmberData<u64>,
+    /// Bond of the submitter and of every challenger, in the DAO token.
+    /// 提交人与反对人的保证金
+    pub bond: Balance,
+    /// How long members can object before the call executes.
+    /// 反对窗口时长
+    pub challenge_period: BlockNumber,
+    /// Weight of objections needed to escalate the call to a referendum.
+    /// 升级为全民公投所需的反对权重
+    pub objection_threshold: Balance,
+}
+
+/// A call on the optimistic track.
+/// 乐观执行中的提案
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OptimisticProposal<AccountId, Call, Balance, BlockNumber> {
+    /// 提交人
+    pub proposer: AccountId,
+    /// 执行内容
+    pub proposal: Call,
+    /// Dispatch weight of the call when it was submitted.
+    /// 提交时记录的执行权重
+    pub weight: Weight,
+    /// 保证金
+    pub bond: Balance,
+    /// When the challenge window closes.
+    /// 反对窗口结束区块
+    pub end: BlockNumber,
+    /// 成员范围
+    pub member_data: MemmberData<u64>,
+    /// Challengers and the objection weight each of them reserved.
+    /// 反对人及其锁定的反对权重
+    pub challengers: Vec<(AccountId, Balance)>,
+    /// 反对权重
+    pub objection_weight: Balance,
+}
+
+/// How votes on referendums of a `CallId` stay hidden until voting ends.
+/// 隐秘投票规则
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CommitRevealRule<BlockNumber> {
+    /// How long voters can reveal after voting ends.
+    /// 投票结束后的揭示时长
+    pub reveal_period: BlockNumber,
+    /// Whether pledges of unrevealed votes go to the DAO account.
+    /// 未揭示投票的抵押是否没收至 DAO 账户
+    pub slash_unrevealed: bool,
+}
+
+/// A hidden vote waiting to be revealed.
+/// 待揭示的投票
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VoteCommitment<Pledge, Hash, Balance, BlockNumber> {
+    /// 抵押
+    pub pledge: Pledge,
+    /// `hash(opinion, salt)`
+    pub commitment: Hash,
+    /// 投票权重
+    pub vote_weight: Balance,
+    /// 解锁区块
+    pub unlock_block: BlockNumber,
+}
+
+/// A public proposal and its backing, returned by the runtime API.
+/// 提案及其支持情况
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProposalSummary<AccountId, Balance, Hash> {
+    /// 提案 id
+    pub index: PropIndex,
+    /// 提案 hash
+    pub proposal_hash: Hash,
+    /// 提案人
+    pub proposer: AccountId,
+    /// Who can vote on the referendum.
+    /// 投票范围
+    pub member_data: MemmberData<u64>,
+    /// Number of accounts backing the proposal, including the proposer.
+    /// 支持者数量
+    pub backers: u32,
+    /// Total deposit backing the proposal.
+    /// 支持的押金总额
+    pub backing: Balance,
+}
+
+/// The state of a referendum, returned by the runtime API.
+/// 全民公投的状态
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ReferendumSummary<Balance, BlockNumber> {
+    /// 进行中
+    Ongoing {
+        /// When voting ends.
+        /// 投票结束区块
+        end: BlockNumber,
+        /// When the proposal can be enacted.
+        /// 可执行区块
+        enactment: BlockNumber,
+        /// 投票统计
+        tally: Tally<Balance>,
+        /// The minimum vote weight needed by the proposal.
+        /// 所需的最小投票权重
+        min_vote_weight: Balance,
+        /// Whether the proposal would pass with the current tally.
+        /// 按当前投票是否会通过
+        would_pass: bool,
+        /// Blocks left until voting ends.
+        /// 距离投票结束的区块数
+        blocks_to_end: BlockNumber,
+        /// Blocks left until the proposal can be enacted.
+        /// 距离可执行的区块数
+        blocks_to_enactment: BlockNumber,
+        /// End and enactment delay in milliseconds, for time-based referendums.
+        /// 按时间计的结束时间与执行延迟
+        moments: Option<ReferendumMoments>,
+    },
+    /// 已结束
+    Finished { approved: bool, end: BlockNumber },
+}
+
+/// A vote of an account, returned by the runtime API.
+/// 用户的投票
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VoteSummary<Pledge, Balance, BlockNumber> {
+    pub dao_id: DaoAssetId,
+    pub referendum_index: ReferendumIndex,
+    pub pledge: Pledge,
+    pub opinion: Opinion,
+    pub vote_weight: Balance,
+    pub unlock_block: BlockNumber,
+    /// Whether the pledge can be unlocked now.
+    /// 现在是否可以解锁
+    pub unlockable: bool,
+}
+
+/// The votes and deposits of an account, returned by the runtime API.
+/// 用户的投票与押金
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VoterSummary<Pledge, Balance, BlockNumber> {
+    /// 投票
+    pub votes: Vec<VoteSummary<Pledge, Balance, BlockNumber>>,
+    /// Total proposal deposits still reserved.
+    /// 仍被锁定的提案押金
+    pub reserved: Balance,
+    /// Proposal deposits that can be unreserved now.
+    /// 现在可以解锁的提案押金
+    pub unreservable: Balance,
+}
+
+/// What to simulate with the dry-run runtime API.
+/// 模拟执行的对象
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DryRunTarget {
+    /// 提案
+    Proposal(PropIndex),
+    /// 全民公投
+    Referendum(ReferendumIndex),
+}
+
+/// The balance of a DAO derived account before and after a dry run.
+/// 模拟执行前后 DAO 派生账户的余额
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BalanceChange<AccountId, Balance> {
+    pub account: AccountId,
+    pub asset_id: DaoAssetId,
+    pub before: Balance,
+    pub after: Balance,
+}
+
+/// What a proposal would do if it was enacted now, returned by the runtime API.
+/// 提案的模拟执行结果
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DryRunResult<AccountId, Balance> {
+    /// 执行结果
+    pub result: DResult,
+    /// Weight used by the call, after refunds.
+    /// 实际消耗的权重
+    pub weight: Weight,
+    /// SCALE encoded runtime events emitted by the call.
+    /// 执行中产生的事件，SCALE 编码
+    pub events: Vec<Vec<u8>>,
+    /// Balances of the DAO derived accounts that changed.
+    /// 余额发生变化的 DAO 派生账户
+    pub balance_changes: Vec<BalanceChange<AccountId, Balance>>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -159,15 +608,88 @@ pub mod pallet {
                 DispatchError,
             >;
 
+        /// Upper bound of `GovConfig::max_public_props`.
+        /// 最大提案数的上限
+        #[pallet::constant]
+        type MaxPublicPropsLimit: Get<u32>;
+
+        /// Lower bound of `GovConfig::voting_period`.
+        /// 投票时长的下限
+        #[pallet::constant]
+        type MinVotingPeriod: Get<Self::BlockNumber>;
+
+        /// Upper bound of every period in `GovConfig`.
+        /// 治理参数中各时长的上限
+        #[pallet::constant]
+        type MaxGovPeriod: Get<Self::BlockNumber>;
+
+        /// Finished referendums older than this are removed from storage.
+        /// 已结束的全民公投保留的时长
+        #[pallet::constant]
+        type ArchiveAge: Get<Self::BlockNumber>;
+
+        /// Maximum number of finished referendums removed in one block.
+        /// 每个区块最多清理的全民公投数量
+        #[pallet::constant]
+        type MaxPrunePerBlock: Get<u32>;
+
+        /// Maximum number of votes removed by one `reap_votes`.
+        /// 每次 `reap_votes` 最多清理的投票数量
+        #[pallet::constant]
+        type MaxReapVotes: Get<u32>;
+
+        /// Deposit in the native token taken for every vote, paid to whoever reaps the vote and
+        /// returned when the voter unlocks it.
+        /// 每次投票收取的原生通证押金，清理者获得该押金，投票者自行解锁时退还
+        #[pallet::constant]
+        type ReapReward: Get<BalanceOf<Self>>;
+
+        /// How long a vetoed proposal can not be proposed again.
+        /// 被否决提案的冷却期
+        #[pallet::constant]
+        type VetoCoolOff: Get<Self::BlockNumber>;
+
+        /// Wall-clock time, used by DAOs with time-based periods.
+        /// 链上时间
+        type UnixTime: UnixTime;
+
+        /// Expected block time, used to check time-based periods against the block bounds
+        /// and to convert block periods for time-based referendums.
+        /// 预期出块时间，单位为毫秒
+        #[pallet::constant]
+        type MillisecsPerBlock: Get<u64>;
+
+        /// Upper bound and default of `GovConfig::max_proposal_weight`.
+        /// 提案执行权重的上限
+        #[pallet::constant]
+        type MaxProposalWeight: Get<Weight>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::prune_finished(now)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::migrate::<T>()
+                .saturating_add(migrations::v2::migrate::<T>())
+                .saturating_add(migrations::v3::migrate::<T>())
+                .saturating_add(migrations::v4::migrate::<T>())
+        }
+    }
+
     /// Number of public proposals so for.
     #[pallet::storage]
     #[pallet::getter(fn public_prop_count)]
@@ -175,65 +697,32 @@ pub mod pallet {
         StorageMap<_, Identity, DaoAssetId, PropIndex, ValueQuery>;
 
     #[pallet::type_value]
-    pub fn MaxPublicPropsOnEmpty() -> PropIndex {
-        100u32
-    }
-
-    /// Maximum number of public proposals at one time.
-    #[pallet::storage]
-    #[pallet::getter(fn max_public_props)]
-    pub type MaxPublicProps<T: Config> =
-        StorageMap<_, Identity, DaoAssetId, u32, ValueQuery, MaxPublicPropsOnEmpty>;
-
-    #[pallet::type_value]
-    pub fn LaunchPeriodOnEmpty<T: Config>() -> T::BlockNumber {
-        T::BlockNumber::from(900u32)
-    }
-
-    /// How soon can a referendum be called.
-    #[pallet::storage]
-    #[pallet::getter(fn launch_period)]
-    pub type LaunchPeriod<T: Config> =
-        StorageMap<_, Identity, DaoAssetId, T::BlockNumber, ValueQuery, LaunchPeriodOnEmpty<T>>;
-
-    /// Minimum stake per person when making public proposals.
-    #[pallet::storage]
-    #[pallet::getter(fn minimum_deposit)]
-    pub type MinimumDeposit<T: Config> =
-        StorageMap<_, Identity, DaoAssetId, BalanceOf<T>, ValueQuery>;
-
-    #[pallet::type_value]
-    pub fn VotingPeriodOnEmpty<T: Config>() -> T::BlockNumber {
-        T::BlockNumber::from(900u32)
-    }
-
-    /// How long each proposal can be voted on.
-    #[pallet::storage]
-    #[pallet::getter(fn voting_period)]
-    pub type VotingPeriod<T: Config> =
-        StorageMap<_, Identity, DaoAssetId, T::BlockNumber, ValueQuery, VotingPeriodOnEmpty<T>>;
-
-    #[pallet::type_value]
-    pub fn ReservePeriodOnEmpty<T: Config>() -> T::BlockNumber {
-        T::BlockNumber::from(900u32)
-    }
-
-    /// How long does it take to release the mortgage.
-    #[pallet::storage]
-    #[pallet::getter(fn reserve_period)]
-    pub type ReservePeriod<T: Config> =
-        StorageMap<_, Identity, DaoAssetId, T::BlockNumber, ValueQuery, ReservePeriodOnEmpty<T>>;
-
-    #[pallet::type_value]
-    pub fn EnactmentPeriodOnEmpty<T: Config>() -> T::BlockNumber {
-        T::BlockNumber::from(900u32)
+    pub fn GovConfigOnEmpty<T: Config>() -> GovConfig<T::BlockNumber, BalanceOf<T>> {
+        GovConfig {
+            max_public_props: 100u32,
+            launch_period: T::BlockNumber::from(900u32),
+            minimum_deposit: BalanceOf::<T>::from(0u32),
+            voting_period: T::BlockNumber::from(900u32),
+            reserve_period: T::BlockNumber::from(900u32),
+            enactment_period: T::BlockNumber::from(900u32),
+            vote_model: 0,
+            max_proposal_weight: T::MaxProposalWeight::get(),
+            nft_vote_weight: BalanceOf::<T>::from(1u32),
+        }
     }
 
-    /// How soon after voting closes the proposal can be implemented.
+    /// Governance parameters of each DAO.
+    /// DAO 治理参数
     #[pallet::storage]
-    #[pallet::getter(fn runment_period)]
-    pub type EnactmentPeriod<T: Config> =
-        StorageMap<_, Identity, DaoAssetId, T::BlockNumber, ValueQuery, EnactmentPeriodOnEmpty<T>>;
+    #[pallet::getter(fn gov_config)]
+    pub type GovConfigOf<T: Config> = StorageMap<
+        _,
+        Identity,
+        DaoAssetId,
+        GovConfig<T::BlockNumber, BalanceOf<T>>,
+        ValueQuery,
+        GovConfigOnEmpty<T>,
+    >;
 
     /// The public proposals. Unsorted. The second item is the proposal's hash.
     #[pallet::storage]
@@ -252,6 +741,39 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Time-based periods of each DAO, DAOs without an entry use the block-based `GovConfig`.
+    /// 使用时间计量治理时长的 DAO
+    #[pallet::storage]
+    #[pallet::getter(fn time_periods)]
+    pub type TimePeriodsOf<T: Config> = StorageMap<_, Identity, DaoAssetId, TimePeriods>;
+
+    /// When a time-based DAO can launch its next referendum.
+    /// 下一次可以开始全民公投的时间
+    #[pallet::storage]
+    #[pallet::getter(fn next_launch_at)]
+    pub type NextLaunchAt<T: Config> = StorageMap<_, Identity, DaoAssetId, Moment, ValueQuery>;
+
+    /// End and enactment delay of time-based referendums.
+    /// 按时间计的全民公投结束时间与执行延迟
+    #[pallet::storage]
+    #[pallet::getter(fn referendum_moments)]
+    pub type ReferendumMomentsOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, ReferendumIndex, ReferendumMoments>;
+
+    /// Dispatch weight of each public proposal, recorded when it is proposed.
+    /// 提案的执行权重
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_weight_of)]
+    pub type ProposalWeightOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, PropIndex, Weight>;
+
+    /// Dispatch weight of the proposal of each ongoing referendum.
+    /// 全民公投提案的执行权重
+    #[pallet::storage]
+    #[pallet::getter(fn referendum_weight_of)]
+    pub type ReferendumWeightOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, ReferendumIndex, Weight>;
+
     /// Those who have locked a deposit.
     ///
     /// TWOX-NOTE: Safe, as increasing integer keys are safe.
@@ -290,12 +812,7 @@ pub mod pallet {
     pub type ReferendumCount<T: Config> =
         StorageMap<_, Identity, DaoAssetId, ReferendumIndex, ValueQuery>;
 
-    /// DAO 投票模式默认 0，1 TOKEN 1 票
-    #[pallet::storage]
-    #[pallet::getter(fn vote_model)]
-    pub type VoteModel<T: Config> = StorageMap<_, Identity, DaoAssetId, u8, ValueQuery>;
-
-    /// Everyone's voting information.
+    /// Everyone's voting information, sorted by DAO and referendum.
     #[pallet::storage]
     #[pallet::getter(fn votes_of)]
     pub type VotesOf<T: Config> = StorageMap<
@@ -319,628 +836,2502 @@ pub mod pallet {
     #[pallet::getter(fn launch_tag)]
     pub type LaunchTag<T: Config> = StorageMap<_, Identity, DaoAssetId, T::BlockNumber, ValueQuery>;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// initiate a proposal.
-        Proposed(DaoAssetId, T::Hash),
-        /// Others support initiating proposals.
-        Recreate(DaoAssetId, BalanceOf<T>),
-        /// Open a referendum.
-        StartTable(DaoAssetId, ReferendumIndex),
-        /// Vote for the referendum.
-        Vote(DaoAssetId, ReferendumIndex, T::Pledge),
-        /// Cancel a vote on a referendum.
-        CancelVote(DaoAssetId, ReferendumIndex),
-        /// Vote and execute the transaction corresponding to the proposa.
-        EnactProposal {
-            dao_id: DaoAssetId,
-            index: ReferendumIndex,
-            result: DResult,
-        },
-        /// Unlock
-        Unlock(T::AccountId, DaoAssetId, T::Pledge),
-        /// Unlock
-        Unreserved(T::AccountId, BalanceOf<T>),
-        /// Set Origin for each Call.
-        SetMinVoteWeight(DaoAssetId, T::CallId, BalanceOf<T>),
-        /// Set the maximum number of proposals at the same time.
-        SetMaxPublicProps {
-            dao_id: DaoAssetId,
-            max: u32,
-        },
-        /// Set the referendum interval.
-        SetLaunchPeriod {
-            dao_id: DaoAssetId,
-            period: T::BlockNumber,
-        },
-        /// Set the minimum amount a proposal needs to stake.
-        SetMinimumDeposit {
-            dao_id: DaoAssetId,
-            min: BalanceOf<T>,
-        },
-        /// Set the voting length of the referendum.
-        SetVotingPeriod {
-            dao_id: DaoAssetId,
-            period: T::BlockNumber,
-        },
-        /// Set the length of time that can be unreserved.
-        SetReservePeriod {
-            dao_id: DaoAssetId,
-            period: T::BlockNumber,
-        },
-        /// Set the time to delay the execution of the proposal.
-        SetEnactmentPeriod {
-            dao_id: DaoAssetId,
-            period: T::BlockNumber,
-        },
-        VoteModelUpdate {
-            dao_id: DaoAssetId,
-            model: u8,
-        },
-    }
-
-    // Errors inform users that something went wrong.
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Integer computation overflow.
-        Overflow,
-        /// Insufficient amount of deposit.
-        DepositTooLow,
-        /// Maximum number of proposals reached.
-        TooManyProposals,
-        /// Proposal does not exist.
-        ProposalMissing,
-        /// There are no proposals in progress.
-        NoneWaiting,
-        /// Referendum does not exist.
-        ReferendumNotExists,
-        /// Referendum ends.
-        ReferendumFinished,
-        /// Referendum voting is underway.
-        VoteNotEnd,
-        /// Delayed execution time.
-        InDelayTime,
-        /// Referendum voting has ended.
-        VoteEnd,
-        /// 宠物投票
-        VoteRedundancy,
-        /// Voting closed but proposal rejected.
-        VoteEndButNotPass,
-        /// It's not time to open a new referendum.
-        NotTableTime,
-        /// Bad origin.
-        VoteWeightTooLow,
-        ///
-        PledgeNotEnough,
-        Gov403,
-    }
-
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// create a proposal
-        /// 创建一个提案
-        #[pallet::call_index(001)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::propose())]
-        pub fn create_propose(
-            origin: OriginFor<T>,
-            dao_id: DaoAssetId,
-            member_data: MemmberData<u64>,
-            proposal: Box<<T as daoent_dao::Config>::RuntimeCall>,
-            #[pallet::compact] value: BalanceOf<T>,
-        ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-            // Self::check_auth_for_proposal(dao_id, who.clone())?;
+    /// Finished referendums waiting to be pruned, in the order they finished.
+    /// 等待清理的已结束全民公投
+    #[pallet::storage]
+    #[pallet::getter(fn finished_queue)]
+    pub type FinishedQueue<T: Config> =
+        StorageMap<_, Identity, u32, (DaoAssetId, ReferendumIndex, T::BlockNumber)>;
 
-            // 确认用户属于可提案的用户范围
-            Self::check_auth_for_vote(dao_id, member_data.clone(), who.clone())?;
+    /// The first and the next free position of `FinishedQueue`.
+    /// `FinishedQueue` 的队首与队尾
+    #[pallet::storage]
+    #[pallet::getter(fn finished_queue_range)]
+    pub type FinishedQueueRange<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
 
-            // 确认当前函数为 sudo/gov 可调用函数
+    /// Optimistic track rules of each call.
+    /// 每个调用的乐观执行规则
+    #[pallet::storage]
+    #[pallet::getter(fn optimistic_rule)]
+    pub type OptimisticRuleOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        T::CallId,
+        OptimisticRule<BalanceOf<T>, T::BlockNumber>,
+    >;
+
+    /// Number of optimistic proposals so far.
+    /// 乐观提案数量
+    #[pallet::storage]
+    #[pallet::getter(fn optimistic_count)]
+    pub type OptimisticCount<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, OptimisticIndex, ValueQuery>;
+
+    /// Calls in their challenge window.
+    /// 处于反对窗口的乐观提案
+    #[pallet::storage]
+    #[pallet::getter(fn optimistic_proposal)]
+    pub type OptimisticProposals<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        OptimisticIndex,
+        OptimisticProposal<
+            T::AccountId,
+            <T as daoent_dao::Config>::RuntimeCall,
+            BalanceOf<T>,
+            T::BlockNumber,
+        >,
+    >;
+
+    /// Referendums escalated from the optimistic track: proposer, bond and challengers with
+    /// their reserved objection weight.
+    /// 由乐观提案升级的全民公投
+    #[pallet::storage]
+    #[pallet::getter(fn escalated_of)]
+    pub type EscalatedOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        ReferendumIndex,
+        (
+            T::AccountId,
+            BalanceOf<T>,
+            Vec<(T::AccountId, BalanceOf<T>)>,
+        ),
+    >;
+
+    /// Commit-reveal rules of each call.
+    /// 每个调用的隐秘投票规则
+    #[pallet::storage]
+    #[pallet::getter(fn commit_reveal_rule)]
+    pub type CommitRevealRuleOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        T::CallId,
+        CommitRevealRule<T::BlockNumber>,
+    >;
+
+    /// The commit-reveal rule a referendum was started with.
+    /// 全民公投使用的隐秘投票规则
+    #[pallet::storage]
+    #[pallet::getter(fn commit_reveal_of)]
+    pub type CommitRevealOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        ReferendumIndex,
+        CommitRevealRule<T::BlockNumber>,
+    >;
+
+    /// Hidden votes of each referendum.
+    /// 全民公投中待揭示的投票
+    #[pallet::storage]
+    #[pallet::getter(fn commitment_of)]
+    pub type Commitments<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (DaoAssetId, ReferendumIndex),
+        Blake2_128Concat,
+        T::AccountId,
+        VoteCommitment<T::Pledge, T::Hash, BalanceOf<T>, T::BlockNumber>,
+    >;
+
+    /// Bicameral rules of each call.
+    /// 每个调用的双院制规则
+    #[pallet::storage]
+    #[pallet::getter(fn bicameral_rule)]
+    pub type BicameralRuleOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, T::CallId, BicameralRule>;
+
+    /// The bicameral rule a referendum was started with.
+    /// 全民公投使用的双院制规则
+    #[pallet::storage]
+    #[pallet::getter(fn bicameral_of)]
+    pub type BicameralOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, ReferendumIndex, BicameralRule>;
+
+    /// Head count tally of the member house of bicameral referendums.
+    /// 成员院按人数的投票统计
+    #[pallet::storage]
+    #[pallet::getter(fn member_tally_of)]
+    pub type MemberTallyOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        ReferendumIndex,
+        Tally<MemberCount>,
+        ValueQuery,
+    >;
+
+    /// Proposer limits of each DAO.
+    /// 提案限制
+    #[pallet::storage]
+    #[pallet::getter(fn proposer_limits)]
+    pub type ProposerLimitsOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, ProposerLimits<T::BlockNumber>>;
+
+    /// Open public proposals each account proposed or seconded.
+    /// 账户提交或附议的未开始提案数
+    #[pallet::storage]
+    #[pallet::getter(fn open_proposals_of)]
+    pub type OpenProposalsOf<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// When each account last proposed or seconded.
+    /// 账户上次提交或附议的区块
+    #[pallet::storage]
+    #[pallet::getter(fn last_proposed_at)]
+    pub type LastProposedAt<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+    /// Participation reward rule of each DAO.
+    /// 投票参与奖励规则
+    #[pallet::storage]
+    #[pallet::getter(fn vote_reward_rule)]
+    pub type VoteRewardRuleOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, VoteRewardRule<BalanceOf<T>>>;
+
+    /// Rewards set aside for each finished referendum.
+    /// 每次全民公投预留的奖励
+    #[pallet::storage]
+    #[pallet::getter(fn vote_reward_pot)]
+    pub type VoteRewardPotOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        ReferendumIndex,
+        VoteRewardPot<BalanceOf<T>>,
+    >;
+
+    /// Rewards set aside in the reward pool and not claimed yet.
+    /// 奖励池中已预留未领取的奖励
+    #[pallet::storage]
+    #[pallet::getter(fn committed_rewards)]
+    pub type CommittedRewards<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, BalanceOf<T>, ValueQuery>;
+
+    /// Votes whose reward was claimed while the vote is still locked.
+    /// 已领取奖励的投票
+    #[pallet::storage]
+    pub type VoteRewardClaimed<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (DaoAssetId, ReferendumIndex),
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+    >;
+
+    /// The guild with emergency powers of each DAO.
+    /// 拥有紧急权限的公会
+    #[pallet::storage]
+    #[pallet::getter(fn emergency_guild)]
+    pub type EmergencyGuildOf<T: Config> = StorageMap<_, Identity, DaoAssetId, EmergencyGuild>;
+
+    /// Pending fast-tracks of referendums.
+    /// 等待中的紧急加速
+    #[pallet::storage]
+    #[pallet::getter(fn fast_track_of)]
+    pub type FastTrackOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        ReferendumIndex,
+        FastTrackInfo<T::AccountId, T::BlockNumber, T::MaxMembers>,
+    >;
+
+    /// Reap deposits of the votes in each referendum.
+    /// 投票的清理押金
+    #[pallet::storage]
+    #[pallet::getter(fn reap_deposit_of)]
+    pub type ReapDepositOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (DaoAssetId, ReferendumIndex),
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+    >;
+
+    /// DAO tokens each NO voter already burned by rage-quitting a referendum.
+    /// 反对者已通过退出销毁的通证
+    #[pallet::storage]
+    #[pallet::getter(fn rage_quit_of)]
+    pub type RageQuitOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (DaoAssetId, ReferendumIndex),
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// DAO tokens each account time-locked for each referendum. One lock covers all of them at
+    /// the largest amount.
+    /// 每次投票锁定的组织通证
+    #[pallet::storage]
+    #[pallet::getter(fn time_locks_of)]
+    pub type TimeLocksOf<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::AccountId, DaoAssetId),
+        Identity,
+        ReferendumIndex,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Guild members who approved vetoing a referendum.
+    /// 同意否决的公会成员
+    #[pallet::storage]
+    #[pallet::getter(fn veto_approvals_of)]
+    pub type VetoApprovalsOf<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        DaoAssetId,
+        Identity,
+        ReferendumIndex,
+        BoundedVec<T::AccountId, T::MaxMembers>,
+        ValueQuery,
+    >;
+
+    /// The council elected in each DAO, sorted.
+    /// 选举产生的理事会
+    #[pallet::storage]
+    #[pallet::getter(fn council_of)]
+    pub type CouncilOf<T: Config> =
+        StorageMap<_, Identity, DaoAssetId, BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
+
+    /// Vetoed proposals, and the block until which they can not be proposed again.
+    /// 被否决的提案及其冷却结束区块
+    #[pallet::storage]
+    #[pallet::getter(fn blacklist)]
+    pub type Blacklist<T: Config> =
+        StorageDoubleMap<_, Identity, DaoAssetId, Identity, T::Hash, T::BlockNumber>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// initiate a proposal.
+        Proposed(DaoAssetId, T::Hash),
+        /// Others support initiating proposals.
+        Recreate(DaoAssetId, BalanceOf<T>),
+        /// Open a referendum.
+        StartTable(DaoAssetId, ReferendumIndex),
+        /// Vote for the referendum.
+        Vote(DaoAssetId, ReferendumIndex, T::Pledge),
+        /// Cancel a vote on a referendum.
+        CancelVote(DaoAssetId, ReferendumIndex),
+        /// Vote and execute the transaction corresponding to the proposa.
+        EnactProposal {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            result: DResult,
+        },
+        /// Unlock
+        Unlock(T::AccountId, DaoAssetId, T::Pledge),
+        /// Unlock
+        Unreserved(T::AccountId, BalanceOf<T>),
+        /// Set Origin for each Call.
+        SetMinVoteWeight(DaoAssetId, T::CallId, BalanceOf<T>),
+        /// A finished referendum was removed from storage.
+        ReferendumArchived {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            approved: bool,
+            end: T::BlockNumber,
+        },
+        /// Stale votes of an account were removed.
+        VotesReaped {
+            who: T::AccountId,
+            reaper: T::AccountId,
+            count: u32,
+        },
+        /// Set the optimistic track rule of a call, `None` disables it.
+        SetOptimisticRule {
+            dao_id: DaoAssetId,
+            call_id: T::CallId,
+            rule: Option<OptimisticRule<BalanceOf<T>, T::BlockNumber>>,
+        },
+        /// A call was submitted to the optimistic track.
+        OptimisticSubmitted {
+            dao_id: DaoAssetId,
+            index: OptimisticIndex,
+            proposer: T::AccountId,
+            end: T::BlockNumber,
+        },
+        /// A member objected to an optimistic call.
+        Objected {
+            dao_id: DaoAssetId,
+            index: OptimisticIndex,
+            who: T::AccountId,
+            weight: BalanceOf<T>,
+        },
+        /// An optimistic call was escalated to a referendum.
+        Escalated {
+            dao_id: DaoAssetId,
+            index: OptimisticIndex,
+            referendum_index: ReferendumIndex,
+        },
+        /// An unchallenged optimistic call was executed.
+        OptimisticExecuted {
+            dao_id: DaoAssetId,
+            index: OptimisticIndex,
+            result: DResult,
+        },
+        /// The bonds of an escalated call were settled, the losing side was slashed.
+        ChallengeSettled {
+            dao_id: DaoAssetId,
+            referendum_index: ReferendumIndex,
+            approved: bool,
+            slashed: BalanceOf<T>,
+        },
+        /// Set the commit-reveal rule of a call, `None` disables it.
+        SetCommitRevealRule {
+            dao_id: DaoAssetId,
+            call_id: T::CallId,
+            rule: Option<CommitRevealRule<T::BlockNumber>>,
+        },
+        /// Set the proposer limits, `None` disables them.
+        SetProposerLimits {
+            dao_id: DaoAssetId,
+            limits: Option<ProposerLimits<T::BlockNumber>>,
+        },
+        /// Set the participation reward rule, `None` disables it.
+        SetVoteRewardRule {
+            dao_id: DaoAssetId,
+            rule: Option<VoteRewardRule<BalanceOf<T>>>,
+        },
+        /// The DAO account moved DAO tokens into the reward pool.
+        RewardPoolFunded {
+            dao_id: DaoAssetId,
+            amount: BalanceOf<T>,
+        },
+        /// Rewards were set aside for the voters of a finished referendum.
+        VoteRewardsReserved {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            budget: BalanceOf<T>,
+        },
+        /// A voter was paid their participation reward.
+        VoteRewardPaid {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A page of vote rewards was claimed, `next` is the referendum the following page
+        /// starts from.
+        VoteRewardsClaimed {
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+            count: u32,
+            total: BalanceOf<T>,
+            next: Option<ReferendumIndex>,
+        },
+        /// Set the bicameral rule of a call, `None` disables it.
+        SetBicameralRule {
+            dao_id: DaoAssetId,
+            call_id: T::CallId,
+            rule: Option<BicameralRule>,
+        },
+        /// Both tallies of a bicameral referendum when its voting was counted.
+        BicameralTallied {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            token_tally: Tally<BalanceOf<T>>,
+            member_tally: Tally<MemberCount>,
+            approved: bool,
+        },
+        /// A hidden vote was committed.
+        VoteCommitted {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+        },
+        /// A hidden vote was revealed and counted.
+        VoteRevealed {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+            opinion: Opinion,
+            vote_weight: BalanceOf<T>,
+        },
+        /// An unrevealed vote was cleared, its pledge slashed or released.
+        UnrevealedCleared {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+            slashed: bool,
+        },
+        /// A NO voter burned DAO tokens and withdrew their share of pool B.
+        RageQuit {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+            burned: BalanceOf<T>,
+            withdrawn: BalanceOf<T>,
+        },
+        /// Set the time-based periods of a DAO, `None` returns it to block-based periods.
+        SetTimePeriods {
+            dao_id: DaoAssetId,
+            periods: Option<TimePeriods>,
+        },
+        /// Set the governance parameters.
+        SetGovConfig {
+            dao_id: DaoAssetId,
+            config: GovConfig<T::BlockNumber, BalanceOf<T>>,
+        },
+        /// Set the guild with emergency powers.
+        SetEmergencyGuild {
+            dao_id: DaoAssetId,
+            guild_id: u64,
+            threshold: MemberCount,
+        },
+        /// A guild member approved fast-tracking a referendum.
+        FastTrackApproved {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+        },
+        /// A pending fast-track was cancelled.
+        FastTrackCancelled {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        },
+        /// A referendum was fast-tracked.
+        FastTracked {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            end: T::BlockNumber,
+            delay: T::BlockNumber,
+        },
+        /// A guild member approved vetoing a referendum.
+        VetoApproved {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: T::AccountId,
+        },
+        /// A passed proposal was vetoed during its enactment delay.
+        Vetoed {
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            proposal_hash: T::Hash,
+            until: T::BlockNumber,
+        },
+        /// A new council was elected, `emergency` tells whether it holds the emergency powers.
+        CouncilUpdated {
+            dao_id: DaoAssetId,
+            members: Vec<T::AccountId>,
+            emergency: bool,
+        },
+    }
+
+    // Errors inform users that something went wrong.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Integer computation overflow.
+        Overflow,
+        /// Insufficient amount of deposit.
+        DepositTooLow,
+        /// Maximum number of proposals reached.
+        TooManyProposals,
+        /// Proposal does not exist.
+        ProposalMissing,
+        /// There are no proposals in progress.
+        NoneWaiting,
+        /// Referendum does not exist.
+        ReferendumNotExists,
+        /// Referendum ends.
+        ReferendumFinished,
+        /// Referendum voting is underway.
+        VoteNotEnd,
+        /// Delayed execution time.
+        InDelayTime,
+        /// Referendum voting has ended.
+        VoteEnd,
+        /// 宠物投票
+        VoteRedundancy,
+        /// Voting closed but proposal rejected.
+        VoteEndButNotPass,
+        /// It's not time to open a new referendum.
+        NotTableTime,
+        /// Bad origin.
+        VoteWeightTooLow,
+        ///
+        PledgeNotEnough,
+        Gov403,
+        /// The DAO has not granted emergency powers to any guild.
+        EmergencyNotEnabled,
+        /// The threshold must be greater than zero.
+        InvalidThreshold,
+        /// Fast-tracking can only shorten the periods.
+        FastTrackNotShorter,
+        /// The periods differ from the pending fast-track.
+        FastTrackMismatch,
+        /// The guild member has already approved.
+        AlreadyApproved,
+        /// More approvals than guild members.
+        TooManyApprovals,
+        /// No pending fast-track.
+        FastTrackNotExists,
+        /// The fast-track can still be completed.
+        FastTrackNotStale,
+        /// The enactment delay has passed.
+        NotInDelayTime,
+        /// The proposal was vetoed and is cooling off.
+        ProposalBlacklisted,
+        /// The maximum number of proposals is out of bounds.
+        InvalidMaxPublicProps,
+        /// The period is shorter than allowed.
+        PeriodTooShort,
+        /// The period is longer than allowed.
+        PeriodTooLong,
+        /// Unknown vote model.
+        InvalidVoteModel,
+        /// No vote can be reaped.
+        NothingToReap,
+        /// No participation reward to claim.
+        NoVoteRewards,
+        /// The account has too many open proposals.
+        TooManyOpenProposals,
+        /// The account proposed too recently.
+        ProposalCooldown,
+        /// The call can not take the optimistic track.
+        NoOptimisticRule,
+        /// Optimistic proposal does not exist.
+        OptimisticNotExists,
+        /// The challenge window has closed.
+        ChallengeWindowClosed,
+        /// The challenge window is still open.
+        ChallengeWindowOpen,
+        /// The member has already objected.
+        AlreadyObjected,
+        /// Votes on this referendum must be committed and revealed.
+        CommitRevealRequired,
+        /// Votes on this referendum are public.
+        NotCommitReveal,
+        /// No hidden vote to reveal or clear.
+        CommitmentNotExists,
+        /// The opinion and salt do not match the commitment.
+        BadReveal,
+        /// It's not time to reveal.
+        NotRevealTime,
+        /// The reveal window is still open.
+        RevealNotEnd,
+        /// Only members who voted NO can rage-quit.
+        NotNoVoter,
+        /// Rage-quit is only possible between the end of voting and enactment.
+        NotRageQuitTime,
+        /// Nothing to burn or nothing to withdraw.
+        NothingToWithdraw,
+        /// Rage-quit can burn no more than the DAO tokens pledged for the NO vote.
+        RageQuitExceedsVote,
+        /// The proposal's dispatch weight is above the DAO's maximum.
+        ProposalTooHeavy,
+        /// The weight bound is lower than the proposal's dispatch weight.
+        WrongProposalWeight,
+        /// The maximum proposal weight is above the runtime limit.
+        InvalidMaxProposalWeight,
+        /// Only NO votes pledging DAO tokens can rage-quit.
+        NotDaoTokenPledge,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// create a proposal
+        /// 创建一个提案
+        #[pallet::call_index(001)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::propose())]
+        pub fn create_propose(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            member_data: MemmberData<u64>,
+            proposal: Box<<T as daoent_dao::Config>::RuntimeCall>,
+            #[pallet::compact] value: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            // Self::check_auth_for_proposal(dao_id, who.clone())?;
+
+            // 确认用户属于可提案的用户范围
+            Self::check_auth_for_vote(dao_id, member_data.clone(), who.clone())?;
+
+            // 确认当前函数为 sudo/gov 可调用函数
             let call_id: T::CallId =
                 TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(*proposal.clone())
                     .unwrap_or_default();
 
             // 确认提案为当前资产支持的 调用
             ensure!(
-                call_id != T::CallId::default(),
-                daoent_dao::Error::<T>::InVailCall
+                call_id != T::CallId::default(),
+                daoent_dao::Error::<T>::InVailCall
+            );
+
+            // 判断最小押金
+            let config = GovConfigOf::<T>::get(dao_id);
+            ensure!(
+                value >= Self::minimum_deposit_of(dao_id, &who),
+                Error::<T>::DepositTooLow
+            );
+            Self::check_proposer(dao_id, &who)?;
+
+            // 记录提案的执行权重
+            let proposal_weight = proposal.get_dispatch_info().weight;
+            ensure!(
+                proposal_weight.all_lte(config.max_proposal_weight),
+                Error::<T>::ProposalTooHeavy
+            );
+
+            let proposal_hash = T::Hashing::hash_of(&proposal);
+
+            // 被否决的提案在冷却期内不能再次提交
+            if let Some(until) = Blacklist::<T>::get(dao_id, proposal_hash) {
+                ensure!(until <= Self::now(), Error::<T>::ProposalBlacklisted);
+                Blacklist::<T>::remove(dao_id, proposal_hash);
+            }
+
+            let proposal_index = Self::public_prop_count(dao_id);
+            let real_prop_count = PublicProps::<T>::decode_len(dao_id).unwrap_or(0) as u32;
+            let max_proposals = config.max_public_props;
+
+            // 确定提案数是否超过了最大提案
+            ensure!(
+                real_prop_count < max_proposals,
+                Error::<T>::TooManyProposals
+            );
+
+            daoent_assets::Pallet::<T>::reserve(dao_id, who.clone(), value)?;
+            Self::note_proposer(dao_id, &who);
+
+            // 更新提案数量
+            PublicPropCount::<T>::insert(dao_id, proposal_index + 1);
+
+            // 添加提案抵押
+            <DepositOf<T>>::insert(dao_id, proposal_index, (&[&who][..], value));
+            ProposalWeightOf::<T>::insert(dao_id, proposal_index, proposal_weight);
+
+            // 添加提案
+            <PublicProps<T>>::append(
+                dao_id,
+                (proposal_index, proposal_hash, *proposal, member_data, who),
+            );
+
+            Self::deposit_event(Event::<T>::Proposed(dao_id, proposal_hash));
+            Ok(().into())
+        }
+
+        /// 重新提交提案
+        #[pallet::call_index(002)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::second())]
+        pub fn recreate(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            #[pallet::compact] proposal_index: PropIndex,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let mut deposit =
+                Self::deposit_of(dao_id, proposal_index).ok_or(Error::<T>::ProposalMissing)?;
+
+            let deposit_amount = deposit.1;
+            Self::check_proposer(dao_id, &who)?;
+            daoent_assets::Pallet::<T>::reserve(dao_id, who.clone(), deposit_amount)?;
+            Self::note_proposer(dao_id, &who);
+
+            deposit.0.push(who.clone());
+            <DepositOf<T>>::insert(dao_id, proposal_index, deposit);
+
+            let reserve_period = match TimePeriodsOf::<T>::get(dao_id) {
+                Some(periods) => Self::ms_to_blocks(periods.reserve_period),
+                None => GovConfigOf::<T>::get(dao_id).reserve_period,
+            };
+            let unreserved_block = Self::now()
+                .checked_add(&reserve_period)
+                .ok_or(Error::<T>::Overflow)?;
+            ReserveOf::<T>::append(who, (deposit_amount, unreserved_block));
+
+            Self::deposit_event(Event::<T>::Recreate(dao_id, deposit_amount));
+            Ok(().into())
+        }
+
+        /// Open a referendum.
+        /// 开始全民公投
+        #[pallet::call_index(003)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::open_referendum())]
+        pub fn start_referendum(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            propose_index: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let tag = LaunchTag::<T>::get(dao_id);
+            let config = GovConfigOf::<T>::get(dao_id);
+            let now = Self::now();
+            let dao_start_time = daoent_dao::Pallet::<T>::try_get_dao(dao_id)?.start_block;
+            if let Some(periods) = TimePeriodsOf::<T>::get(dao_id) {
+                let moment = Self::moment();
+                ensure!(
+                    NextLaunchAt::<T>::get(dao_id) <= moment,
+                    Error::<T>::NotTableTime
+                );
+                NextLaunchAt::<T>::insert(dao_id, moment.saturating_add(periods.launch_period));
+            } else {
+                // (now - dao_start_time) / LaunchPeriod > tag
+                ensure!(
+                    tag.checked_mul(&config.launch_period)
+                        .ok_or(Error::<T>::Overflow)?
+                        < (now - dao_start_time),
+                    Error::<T>::NotTableTime
+                );
+            }
+
+            // 获取提案
+            let mut public_props = Self::public_props(dao_id);
+            ensure!(
+                public_props.len() > propose_index.try_into().unwrap(),
+                Error::<T>::NotTableTime
+            );
+            let (prop_index, _, proposal, member_data, _) =
+                public_props.swap_remove(propose_index.try_into().unwrap());
+            <PublicProps<T>>::insert(dao_id, public_props);
+
+            // 确认用户属于可提案的用户范围
+            Self::check_auth_for_vote(dao_id, member_data.clone(), who)?;
+
+            // 获取抵押
+            let mut referendum_index: Option<ReferendumIndex> = None;
+            if let Some((depositors, _)) = <DepositOf<T>>::take(dao_id, prop_index) {
+                for depositor in depositors.iter() {
+                    OpenProposalsOf::<T>::mutate(dao_id, depositor, |n| *n = n.saturating_sub(1));
+                }
+                let proposal_weight = ProposalWeightOf::<T>::take(dao_id, prop_index)
+                    .unwrap_or_else(|| proposal.get_dispatch_info().weight);
+                referendum_index = Some(Self::inject_referendum(
+                    dao_id,
+                    proposal,
+                    proposal_weight,
+                    member_data,
+                ));
+            }
+
+            if referendum_index.is_none() {
+                Err(Error::<T>::NoneWaiting)?
+            }
+
+            Self::deposit_event(Event::<T>::StartTable(dao_id, referendum_index.unwrap()));
+
+            Ok(().into())
+        }
+
+        /// Vote for the referendum
+        /// 为全民公投投票
+        #[pallet::call_index(004)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_for_referendum())]
+        pub fn vote_for_referendum(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            referendum_index: ReferendumIndex,
+            pledge: T::Pledge,
+            opinion: Opinion,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let now = Self::now();
+            let mut vote_weight = BalanceOf::<T>::from(0u32);
+            ensure!(
+                !CommitRevealOf::<T>::contains_key(dao_id, referendum_index),
+                Error::<T>::CommitRevealRequired
+            );
+
+            // 检查用户是否已经参与了投票，只能投票一次
+            let slot = Self::vote_slot(&who, dao_id, referendum_index)?;
+
+            ReferendumInfoOf::<T>::try_mutate_exists(
+                dao_id,
+                referendum_index,
+                |h| -> result::Result<(), DispatchError> {
+                    let mut info = h.take().ok_or(Error::<T>::ReferendumNotExists)?;
+                    if let ReferendumInfo::Ongoing(ref mut x) = info {
+                        // 确认用户属于可投票的用户范围
+                        Self::check_auth_for_vote(dao_id, x.member_data.clone(), who.clone())?;
+
+                        if Self::clock(dao_id, referendum_index, x).voting() {
+                            let vote_model = GovConfigOf::<T>::get(dao_id).vote_model;
+                            let vote_result =
+                                pledge.try_vote(&who, &dao_id, referendum_index, vote_model)?;
+                            vote_weight = vote_result.0;
+                            Self::take_reap_deposit(dao_id, referendum_index, &who)?;
+
+                            let duration = vote_result.1;
+                            match opinion {
+                                Opinion::NO => {
+                                    x.tally.no += vote_weight;
+                                }
+                                Opinion::YES => {
+                                    x.tally.yes += vote_weight;
+                                }
+                            };
+                            Self::count_member(dao_id, referendum_index, &opinion, true);
+
+                            VotesOf::<T>::mutate(&who, |votes| {
+                                votes.insert(
+                                    slot,
+                                    VoteInfo {
+                                        dao_id,
+                                        pledge,
+                                        opinion,
+                                        vote_weight,
+                                        unlock_block: now + duration,
+                                        referendum_index,
+                                    },
+                                )
+                            });
+                        } else {
+                            return Err(Error::<T>::VoteEnd)?;
+                        }
+                    } else {
+                        return Err(Error::<T>::ReferendumFinished)?;
+                    }
+                    *h = Some(info);
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::<T>::Vote(dao_id, referendum_index, pledge));
+            Ok(().into())
+        }
+
+        /// Cancel a vote on a referendum
+        /// 取消一个投票
+        #[pallet::call_index(005)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_vote())]
+        pub fn cancel_vote(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ReferendumInfoOf::<T>::try_mutate_exists(
+                dao_id,
+                index,
+                |h| -> result::Result<(), DispatchError> {
+                    let mut info = h.take().ok_or(Error::<T>::ReferendumNotExists)?;
+                    if let ReferendumInfo::Ongoing(ref mut x) = info {
+                        if Self::clock(dao_id, index, x).voting() {
+                            let mut votes = VotesOf::<T>::get(&who);
+                            votes.retain(|h| {
+                                if h.dao_id == dao_id
+                                    && h.referendum_index == index
+                                    && h.pledge.vote_end_do(&who, &dao_id, index).is_ok()
+                                {
+                                    match h.opinion {
+                                        Opinion::NO => {
+                                            x.tally.no = x.tally.no.saturating_sub(h.vote_weight);
+                                        }
+                                        _ => {
+                                            x.tally.yes = x.tally.yes.saturating_sub(h.vote_weight);
+                                        }
+                                    };
+                                    Self::count_member(dao_id, index, &h.opinion, false);
+                                    Self::release_reap_deposit(dao_id, index, &who, &who);
+                                    false
+                                } else {
+                                    true
+                                }
+                            });
+                            VotesOf::<T>::insert(&who, votes);
+                        } else {
+                            return Err(Error::<T>::VoteEnd)?;
+                        }
+                    } else {
+                        return Err(Error::<T>::ReferendumFinished)?;
+                    }
+                    *h = Some(info);
+                    Ok(())
+                },
+            )?;
+            Self::deposit_event(Event::<T>::CancelVote(dao_id, index));
+
+            Ok(().into())
+        }
+
+        /// Vote and execute the transaction corresponding to the proposa
+        /// 执行一个投票通过提案
+        /// `weight_bound` must cover the dispatch weight recorded for the proposal, the caller
+        /// is refunded what the proposal does not use.
+        #[pallet::call_index(006)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::run_proposal().saturating_add(*weight_bound))]
+        pub fn run_proposal(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            weight_bound: Weight,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let now = Self::now();
+            let mut approved = false;
+            let mut used_weight = Weight::zero();
+            let info =
+                ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
+            let proposal_weight = ReferendumWeightOf::<T>::get(dao_id, index).unwrap_or_default();
+            ensure!(
+                proposal_weight.all_lte(weight_bound),
+                Error::<T>::WrongProposalWeight
             );
+            match info {
+                ReferendumInfo::Ongoing(state) => {
+                    let clock = Self::clock(dao_id, index, &state);
+                    if clock.voting() {
+                        return Err(Error::<T>::VoteNotEnd)?;
+                    } else if Self::reveal_end(dao_id, index, &clock) > clock.now {
+                        return Err(Error::<T>::RevealNotEnd)?;
+                    } else if clock.enactment() > clock.now {
+                        return Err(Error::<T>::InDelayTime)?;
+                    } else {
+                        {
+                            let call_id: T::CallId =
+                                TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(
+                                    state.proposal.clone(),
+                                )
+                                .unwrap_or_default();
 
-            // 判断最小押金
+                            if state.tally.yes.saturating_add(state.tally.no)
+                                >= MinVoteWeightOf::<T>::get(dao_id, call_id)
+                            {
+                                if Self::houses_pass(dao_id, index, &state.tally, true) {
+                                    approved = true;
+                                    let res = state.proposal.dispatch_bypass_filter(
+                                        frame_system::RawOrigin::Signed(
+                                            daoent_dao::Pallet::<T>::try_get_dao_account_id(
+                                                dao_id,
+                                            )?,
+                                        )
+                                        .into(),
+                                    );
+                                    used_weight = match res {
+                                        Ok(post) => post.actual_weight,
+                                        Err(e) => e.post_info.actual_weight,
+                                    }
+                                    .unwrap_or(proposal_weight);
+                                    Self::deposit_event(Event::EnactProposal {
+                                        dao_id,
+                                        index,
+                                        result: res.map(|_| ()).map_err(|e| e.error),
+                                    });
+                                } else {
+                                    Self::deposit_event(Event::EnactProposal {
+                                        dao_id,
+                                        index,
+                                        result: Err(Error::<T>::VoteEndButNotPass.into()),
+                                    });
+                                }
+                            } else {
+                                // 投票权重不足，按未通过结束并结算保证金
+                                Self::deposit_event(Event::EnactProposal {
+                                    dao_id,
+                                    index,
+                                    result: Err(Error::<T>::VoteWeightTooLow.into()),
+                                });
+                            }
+                        }
+                    }
+                }
+                _ => return Err(Error::<T>::ReferendumFinished)?,
+            }
+            Self::finish_referendum(dao_id, index, approved, now);
+
+            Ok(
+                Some(<T as pallet::Config>::WeightInfo::run_proposal().saturating_add(used_weight))
+                    .into(),
+            )
+        }
+
+        /// Unlock
+        #[pallet::call_index(007)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unlock())]
+        pub fn unlock(origin: OriginFor<T>, dao_id: DaoAssetId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let now = Self::now();
+            //
+            {
+                let mut total = BalanceOf::<T>::from(0u32);
+                let mut reserve_info = ReserveOf::<T>::get(&who);
+                reserve_info.retain(|h| {
+                    if h.1 > now {
+                        true
+                    } else {
+                        daoent_assets::Pallet::<T>::unreserve(dao_id, who.clone(), h.0).unwrap();
+                        total += h.0;
+                        false
+                    }
+                });
+                ReserveOf::<T>::insert(&who, reserve_info);
+                Self::deposit_event(Event::<T>::Unreserved(who.clone(), total));
+            }
+
+            //
+            {
+                let mut votes = VotesOf::<T>::get(&who);
+                votes.retain(|h| {
+                    if h.unlock_block > now
+                        || h.pledge
+                            .vote_end_do(&who, &h.dao_id, h.referendum_index)
+                            .is_err()
+                    {
+                        true
+                    } else {
+                        Self::pay_vote_reward(&who, h, true);
+                        Self::release_reap_deposit(h.dao_id, h.referendum_index, &who, &who);
+                        RageQuitOf::<T>::remove((h.dao_id, h.referendum_index), &who);
+                        Self::deposit_event(Event::<T>::Unlock(who.clone(), h.dao_id, h.pledge));
+                        false
+                    }
+                });
+                VotesOf::<T>::insert(&who, votes);
+            }
+
+            Ok(().into())
+        }
+
+        /// Set Origin for each Call.
+        #[pallet::call_index(008)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_min_vote_weight_for_every_call())]
+        pub fn set_min_vote_weight_for_every_call(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            call_id: T::CallId,
+            min_vote_weight: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+            MinVoteWeightOf::<T>::insert(dao_id, call_id, min_vote_weight);
+            Self::deposit_event(Event::<T>::SetMinVoteWeight(
+                dao_id,
+                call_id,
+                min_vote_weight,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Grant emergency powers to a guild, or to the elected council with `COUNCIL_GUILD`
+        /// 设置拥有紧急权限的公会
+        #[pallet::call_index(016)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_emergency_guild())]
+        pub fn set_emergency_guild(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            guild_id: u64,
+            threshold: MemberCount,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+
+            ensure!(threshold > 0, Error::<T>::InvalidThreshold);
             ensure!(
-                value >= MinimumDeposit::<T>::get(dao_id),
-                Error::<T>::DepositTooLow
+                guild_id == COUNCIL_GUILD
+                    || (guild_id as usize) < daoent_dao::Guilds::<T>::get(dao_id).len(),
+                daoent_dao::Error::<T>::GuildNotExists
             );
 
-            let proposal_hash = T::Hashing::hash_of(&proposal);
-            let proposal_index = Self::public_prop_count(dao_id);
-            let real_prop_count = PublicProps::<T>::decode_len(dao_id).unwrap_or(0) as u32;
-            let max_proposals = MaxPublicProps::<T>::get(dao_id);
+            EmergencyGuildOf::<T>::insert(
+                dao_id,
+                EmergencyGuild {
+                    guild_id,
+                    threshold,
+                },
+            );
+            Self::deposit_event(Event::<T>::SetEmergencyGuild {
+                dao_id,
+                guild_id,
+                threshold,
+            });
 
-            // 确定提案数是否超过了最大提案
+            Ok(().into())
+        }
+
+        /// Approve shortening the voting and enactment periods of a referendum
+        /// 紧急加速全民公投
+        #[pallet::call_index(017)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::fast_track())]
+        pub fn fast_track(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            voting_period: T::BlockNumber,
+            enactment_period: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let threshold = Self::check_auth_for_emergency(dao_id, who.clone())?;
+
+            let config = GovConfigOf::<T>::get(dao_id);
+            let shorter = match TimePeriodsOf::<T>::get(dao_id) {
+                Some(periods) => {
+                    Self::blocks_to_ms(voting_period) <= periods.voting_period
+                        && Self::blocks_to_ms(enactment_period) <= periods.enactment_period
+                }
+                None => {
+                    voting_period <= config.voting_period
+                        && enactment_period <= config.enactment_period
+                }
+            };
+            ensure!(shorter, Error::<T>::FastTrackNotShorter);
+
+            let now = Self::now();
+            let mut info =
+                ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
+            let state = match info {
+                ReferendumInfo::Ongoing(ref mut x) => x,
+                _ => return Err(Error::<T>::ReferendumFinished)?,
+            };
+            let clock = Self::clock(dao_id, index, state);
+            ensure!(clock.voting(), Error::<T>::VoteEnd);
+
+            // 只统计仍在公会中的同意者，没有同意者时可以使用新的时长
+            let mut track = FastTrackOf::<T>::get(dao_id, index)
+                .map(|mut t| {
+                    Self::retain_guild_approvals(dao_id, &mut t.approvals);
+                    t
+                })
+                .filter(|t| !t.approvals.is_empty())
+                .unwrap_or(FastTrackInfo {
+                    voting_period,
+                    enactment_period,
+                    approvals: Default::default(),
+                });
             ensure!(
-                real_prop_count < max_proposals,
-                Error::<T>::TooManyProposals
+                track.voting_period == voting_period && track.enactment_period == enactment_period,
+                Error::<T>::FastTrackMismatch
             );
+            ensure!(!track.approvals.contains(&who), Error::<T>::AlreadyApproved);
+            track
+                .approvals
+                .try_push(who.clone())
+                .map_err(|_| Error::<T>::TooManyApprovals)?;
+            Self::deposit_event(Event::<T>::FastTrackApproved { dao_id, index, who });
+
+            if (track.approvals.len() as MemberCount) < threshold {
+                FastTrackOf::<T>::insert(dao_id, index, track);
+                return Ok(().into());
+            }
 
-            daoent_assets::Pallet::<T>::reserve(dao_id, who.clone(), value)?;
+            // 达到门槛，缩短投票与执行延迟
+            state.end = state.end.min(now.saturating_add(voting_period));
+            state.delay = state.delay.min(enactment_period);
+            ReferendumMomentsOf::<T>::mutate(dao_id, index, |m| {
+                if let Some(m) = m {
+                    m.end = m.end.min(
+                        clock
+                            .now
+                            .saturating_add(clock.span(voting_period.saturated_into())),
+                    );
+                    m.delay = m.delay.min(clock.span(enactment_period.saturated_into()));
+                }
+            });
+            let (end, delay) = (state.end, state.delay);
+            ReferendumInfoOf::<T>::insert(dao_id, index, info);
+            FastTrackOf::<T>::remove(dao_id, index);
+            Self::deposit_event(Event::<T>::FastTracked {
+                dao_id,
+                index,
+                end,
+                delay,
+            });
 
-            // 更新提案数量
-            PublicPropCount::<T>::insert(dao_id, proposal_index + 1);
+            Ok(().into())
+        }
 
-            // 添加提案抵押
-            <DepositOf<T>>::insert(dao_id, proposal_index, (&[&who][..], value));
+        /// Approve vetoing a passed proposal during its enactment delay
+        /// 在执行延迟期间否决已通过的提案
+        #[pallet::call_index(018)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::veto())]
+        pub fn veto(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let threshold = Self::check_auth_for_emergency(dao_id, who.clone())?;
 
-            // 添加提案
-            <PublicProps<T>>::append(
+            let now = Self::now();
+            let info =
+                ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
+            let state = match info {
+                ReferendumInfo::Ongoing(x) => x,
+                _ => return Err(Error::<T>::ReferendumFinished)?,
+            };
+            let clock = Self::clock(dao_id, index, &state);
+            ensure!(!clock.voting(), Error::<T>::VoteNotEnd);
+            // 隐秘投票揭示结束前计票尚未完成
+            ensure!(
+                Self::reveal_end(dao_id, index, &clock) <= clock.now,
+                Error::<T>::RevealNotEnd
+            );
+            ensure!(clock.enactment() > clock.now, Error::<T>::NotInDelayTime);
+            ensure!(
+                Self::houses_pass(dao_id, index, &state.tally, false),
+                Error::<T>::VoteEndButNotPass
+            );
+
+            let mut approvals = VetoApprovalsOf::<T>::get(dao_id, index);
+            Self::retain_guild_approvals(dao_id, &mut approvals);
+            ensure!(!approvals.contains(&who), Error::<T>::AlreadyApproved);
+            approvals
+                .try_push(who.clone())
+                .map_err(|_| Error::<T>::TooManyApprovals)?;
+            Self::deposit_event(Event::<T>::VetoApproved { dao_id, index, who });
+
+            if (approvals.len() as MemberCount) < threshold {
+                VetoApprovalsOf::<T>::insert(dao_id, index, approvals);
+                return Ok(().into());
+            }
+
+            // 达到门槛，否决提案并加入冷却黑名单
+            let proposal_hash = T::Hashing::hash_of(&state.proposal);
+            let until = now.saturating_add(T::VetoCoolOff::get());
+            Blacklist::<T>::insert(dao_id, proposal_hash, until);
+            VetoApprovalsOf::<T>::remove(dao_id, index);
+            Self::finish_referendum(dao_id, index, false, now);
+            Self::deposit_event(Event::<T>::Vetoed {
                 dao_id,
-                (proposal_index, proposal_hash, *proposal, member_data, who),
+                index,
+                proposal_hash,
+                until,
+            });
+
+            Ok(().into())
+        }
+
+        /// Cancel a fast-track that can no longer be completed: voting has ended, or none of its
+        /// approvers is still in the emergency guild
+        /// 取消失效的紧急加速
+        #[pallet::call_index(036)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_fast_track())]
+        pub fn cancel_fast_track(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let mut track =
+                FastTrackOf::<T>::get(dao_id, index).ok_or(Error::<T>::FastTrackNotExists)?;
+            let voting = match ReferendumInfoOf::<T>::get(dao_id, index) {
+                Some(ReferendumInfo::Ongoing(state)) => Self::clock(dao_id, index, &state).voting(),
+                _ => false,
+            };
+            Self::retain_guild_approvals(dao_id, &mut track.approvals);
+            ensure!(
+                !voting || track.approvals.is_empty(),
+                Error::<T>::FastTrackNotStale
             );
 
-            Self::deposit_event(Event::<T>::Proposed(dao_id, proposal_hash));
+            FastTrackOf::<T>::remove(dao_id, index);
+            Self::deposit_event(Event::<T>::FastTrackCancelled { dao_id, index });
+
             Ok(().into())
         }
 
-        /// 重新提交提案
-        #[pallet::call_index(002)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::second())]
-        pub fn recreate(
+        /// Remove the votes of an account whose lock has expired and whose referendum has been
+        /// archived, `ArchiveAge` after it finished, so voters have that long to unlock first
+        /// 清理用户已归档公投的过期投票，调用者获得奖励
+        #[pallet::call_index(020)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reap_votes())]
+        pub fn reap_votes(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+            let reaper = ensure_signed(origin)?;
+            let now = Self::now();
+            let max = T::MaxReapVotes::get();
+            let mut count = 0u32;
+
+            let mut votes = VotesOf::<T>::get(&who);
+            votes.retain(|h| {
+                if count >= max
+                    || h.unlock_block > now
+                    || ReferendumInfoOf::<T>::contains_key(h.dao_id, h.referendum_index)
+                    || h.pledge
+                        .vote_end_do(&who, &h.dao_id, h.referendum_index)
+                        .is_err()
+                {
+                    return true;
+                }
+
+                count += 1;
+                Self::pay_vote_reward(&who, h, true);
+                // 奖励来自投票者的清理押金，而不是 DAO 账户
+                Self::release_reap_deposit(h.dao_id, h.referendum_index, &who, &reaper);
+                RageQuitOf::<T>::remove((h.dao_id, h.referendum_index), &who);
+                Self::deposit_event(Event::<T>::Unlock(who.clone(), h.dao_id, h.pledge));
+                false
+            });
+            ensure!(count > 0, Error::<T>::NothingToReap);
+            VotesOf::<T>::insert(&who, votes);
+
+            Self::deposit_event(Event::<T>::VotesReaped { who, reaper, count });
+            Ok(().into())
+        }
+
+        /// Set or remove the optimistic track rule of a call
+        /// 设置调用的乐观执行规则
+        #[pallet::call_index(021)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_optimistic_rule())]
+        pub fn set_optimistic_rule(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            #[pallet::compact] proposal_index: PropIndex,
+            call_id: T::CallId,
+            rule: Option<OptimisticRule<BalanceOf<T>, T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+
+            match rule {
+                Some(ref r) => {
+                    ensure!(
+                        r.challenge_period > T::BlockNumber::from(0u32),
+                        Error::<T>::PeriodTooShort
+                    );
+                    ensure!(
+                        r.challenge_period <= T::MaxGovPeriod::get(),
+                        Error::<T>::PeriodTooLong
+                    );
+                    OptimisticRuleOf::<T>::insert(dao_id, call_id, r);
+                }
+                None => OptimisticRuleOf::<T>::remove(dao_id, call_id),
+            }
+            Self::deposit_event(Event::<T>::SetOptimisticRule {
+                dao_id,
+                call_id,
+                rule,
+            });
+
+            Ok(().into())
+        }
+
+        /// Submit a call that executes after the challenge window unless members object
+        /// 提交乐观执行的提案
+        #[pallet::call_index(022)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_optimistic())]
+        pub fn submit_optimistic(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            proposal: Box<<T as daoent_dao::Config>::RuntimeCall>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            let mut deposit =
-                Self::deposit_of(dao_id, proposal_index).ok_or(Error::<T>::ProposalMissing)?;
+            let call_id: T::CallId =
+                TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(*proposal.clone())
+                    .unwrap_or_default();
+            ensure!(
+                call_id != T::CallId::default(),
+                daoent_dao::Error::<T>::InVailCall
+            );
+            let rule =
+                OptimisticRuleOf::<T>::get(dao_id, call_id).ok_or(Error::<T>::NoOptimisticRule)?;
+            Self::check_auth_for_vote(dao_id, rule.member_data.clone(), who.clone())?;
+            let weight = proposal.get_dispatch_info().weight;
+            ensure!(
+                weight.all_lte(GovConfigOf::<T>::get(dao_id).max_proposal_weight),
+                Error::<T>::ProposalTooHeavy
+            );
 
-            let deposit_amount = deposit.1;
-            daoent_assets::Pallet::<T>::reserve(dao_id, who.clone(), deposit_amount)?;
+            let proposal_hash = T::Hashing::hash_of(&proposal);
+            if let Some(until) = Blacklist::<T>::get(dao_id, proposal_hash) {
+                ensure!(until <= Self::now(), Error::<T>::ProposalBlacklisted);
+                Blacklist::<T>::remove(dao_id, proposal_hash);
+            }
 
-            deposit.0.push(who.clone());
-            <DepositOf<T>>::insert(dao_id, proposal_index, deposit);
+            daoent_assets::Pallet::<T>::reserve(dao_id, who.clone(), rule.bond)?;
 
-            let unreserved_block = Self::now()
-                .checked_add(&ReservePeriod::<T>::get(dao_id))
-                .ok_or(Error::<T>::Overflow)?;
-            ReserveOf::<T>::append(who, (deposit_amount, unreserved_block));
+            let index = OptimisticCount::<T>::get(dao_id);
+            OptimisticCount::<T>::insert(dao_id, index.checked_add(1).ok_or(Error::<T>::Overflow)?);
+            let end = Self::now().saturating_add(rule.challenge_period);
+            OptimisticProposals::<T>::insert(
+                dao_id,
+                index,
+                OptimisticProposal {
+                    proposer: who.clone(),
+                    proposal: *proposal,
+                    weight,
+                    bond: rule.bond,
+                    end,
+                    member_data: rule.member_data,
+                    challengers: Vec::new(),
+                    objection_weight: BalanceOf::<T>::from(0u32),
+                },
+            );
 
-            Self::deposit_event(Event::<T>::Recreate(dao_id, deposit_amount));
+            Self::deposit_event(Event::<T>::OptimisticSubmitted {
+                dao_id,
+                index,
+                proposer: who,
+                end,
+            });
             Ok(().into())
         }
 
-        /// Open a referendum.
-        /// 开始全民公投
-        #[pallet::call_index(003)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::open_referendum())]
-        pub fn start_referendum(
+        /// Object to an optimistic call with a bond and `weight` DAO tokens, both stay reserved
+        /// until the call is executed or its referendum ends
+        /// 反对乐观提案，达到门槛后升级为全民公投
+        #[pallet::call_index(023)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::object_optimistic())]
+        pub fn object_optimistic(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            propose_index: u32,
+            index: OptimisticIndex,
+            weight: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            let tag = LaunchTag::<T>::get(dao_id);
-            let now = Self::now();
-            let dao_start_time = daoent_dao::Pallet::<T>::try_get_dao(dao_id)?.start_block;
-            // (now - dao_start_time) / LaunchPeriod > tag
-            ensure!(
-                tag.checked_mul(&LaunchPeriod::<T>::get(dao_id))
-                    .ok_or(Error::<T>::Overflow)?
-                    < (now - dao_start_time),
-                Error::<T>::NotTableTime
-            );
-
-            // 获取提案
-            let mut public_props = Self::public_props(dao_id);
+            let mut info = OptimisticProposals::<T>::get(dao_id, index)
+                .ok_or(Error::<T>::OptimisticNotExists)?;
+            ensure!(info.end > Self::now(), Error::<T>::ChallengeWindowClosed);
             ensure!(
-                public_props.len() > propose_index.try_into().unwrap(),
-                Error::<T>::NotTableTime
+                !info.challengers.iter().any(|(c, _)| c == &who),
+                Error::<T>::AlreadyObjected
             );
-            let (prop_index, _, proposal, member_data, _) =
-                public_props.swap_remove(propose_index.try_into().unwrap());
-            <PublicProps<T>>::insert(dao_id, public_props);
+            Self::check_auth_for_vote(dao_id, info.member_data.clone(), who.clone())?;
 
-            // 确认用户属于可提案的用户范围
-            Self::check_auth_for_vote(dao_id, member_data.clone(), who)?;
-
-            // 获取抵押
-            let mut referendum_index: Option<ReferendumIndex> = None;
-            let now = Self::now();
-            if <DepositOf<T>>::take(dao_id, prop_index).is_some() {
-                referendum_index = Some(Self::inject_referendum(
-                    dao_id,
-                    proposal,
-                    now.saturating_add(VotingPeriod::<T>::get(dao_id)),
-                    EnactmentPeriod::<T>::get(dao_id),
-                    member_data,
-                ));
-            }
+            daoent_assets::Pallet::<T>::reserve(
+                dao_id,
+                who.clone(),
+                info.bond.saturating_add(weight),
+            )?;
+            info.challengers.push((who.clone(), weight));
+            info.objection_weight = info.objection_weight.saturating_add(weight);
+            Self::deposit_event(Event::<T>::Objected {
+                dao_id,
+                index,
+                who,
+                weight,
+            });
 
-            if referendum_index.is_none() {
-                Err(Error::<T>::NoneWaiting)?
+            let call_id: T::CallId =
+                TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(info.proposal.clone())
+                    .unwrap_or_default();
+            let threshold = OptimisticRuleOf::<T>::get(dao_id, call_id)
+                .map(|r| r.objection_threshold)
+                .unwrap_or_default();
+            if info.objection_weight < threshold {
+                OptimisticProposals::<T>::insert(dao_id, index, info);
+                return Ok(().into());
             }
 
-            Self::deposit_event(Event::<T>::StartTable(dao_id, referendum_index.unwrap()));
+            // 达到门槛，升级为全民公投
+            OptimisticProposals::<T>::remove(dao_id, index);
+            let referendum_index =
+                Self::inject_referendum(dao_id, info.proposal, info.weight, info.member_data);
+            EscalatedOf::<T>::insert(
+                dao_id,
+                referendum_index,
+                (info.proposer, info.bond, info.challengers),
+            );
+            Self::deposit_event(Event::<T>::Escalated {
+                dao_id,
+                index,
+                referendum_index,
+            });
 
             Ok(().into())
         }
 
-        /// Vote for the referendum
-        /// 为全民公投投票
-        #[pallet::call_index(004)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_for_referendum())]
-        pub fn vote_for_referendum(
+        /// Execute an optimistic call whose challenge window closed without escalation
+        /// 执行未被反对的乐观提案
+        /// `weight_bound` must cover the dispatch weight recorded for the call, the caller is
+        /// refunded what the call does not use.
+        #[pallet::call_index(024)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::execute_optimistic().saturating_add(*weight_bound))]
+        pub fn execute_optimistic(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            referendum_index: ReferendumIndex,
-            pledge: T::Pledge,
-            opinion: Opinion,
+            index: OptimisticIndex,
+            weight_bound: Weight,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-            let now = Self::now();
-            let mut vote_weight = BalanceOf::<T>::from(0u32);
-
-            // 检查用户是否已经参与了投票，只能投票一次
-            let votes = VotesOf::<T>::get(&who);
-            votes
-                .binary_search_by(|v| v.referendum_index.cmp(&referendum_index))
-                .err()
-                .ok_or(Error::<T>::VoteRedundancy)?;
-
-            ReferendumInfoOf::<T>::try_mutate_exists(
-                dao_id,
-                referendum_index,
-                |h| -> result::Result<(), DispatchError> {
-                    let mut info = h.take().ok_or(Error::<T>::ReferendumNotExists)?;
-                    if let ReferendumInfo::Ongoing(ref mut x) = info {
-                        // 确认用户属于可投票的用户范围
-                        Self::check_auth_for_vote(dao_id, x.member_data.clone(), who.clone())?;
+            ensure_signed(origin)?;
 
-                        if x.end > now {
-                            let vote_model = <VoteModel<T>>::try_get(dao_id).unwrap_or_default();
-                            let vote_result = pledge.try_vote(&who, &dao_id, vote_model)?;
-                            vote_weight = vote_result.0;
+            let info = OptimisticProposals::<T>::get(dao_id, index)
+                .ok_or(Error::<T>::OptimisticNotExists)?;
+            ensure!(info.end <= Self::now(), Error::<T>::ChallengeWindowOpen);
+            ensure!(
+                info.weight.all_lte(weight_bound),
+                Error::<T>::WrongProposalWeight
+            );
+            OptimisticProposals::<T>::remove(dao_id, index);
 
-                            let duration = vote_result.1;
-                            match opinion {
-                                Opinion::NO => {
-                                    x.tally.no += vote_weight;
-                                }
-                                Opinion::YES => {
-                                    x.tally.yes += vote_weight;
-                                }
-                            };
+            // 退还提交人与反对人的保证金及反对权重
+            daoent_assets::Pallet::<T>::unreserve(dao_id, info.proposer, info.bond)?;
+            for (challenger, weight) in info.challengers {
+                daoent_assets::Pallet::<T>::unreserve(
+                    dao_id,
+                    challenger,
+                    info.bond.saturating_add(weight),
+                )?;
+            }
 
-                            VotesOf::<T>::append(
-                                &who,
-                                VoteInfo {
-                                    dao_id,
-                                    pledge,
-                                    opinion,
-                                    vote_weight,
-                                    unlock_block: now + duration,
-                                    referendum_index,
-                                },
-                            );
-                        } else {
-                            return Err(Error::<T>::VoteEnd)?;
-                        }
-                    } else {
-                        return Err(Error::<T>::ReferendumFinished)?;
-                    }
-                    *h = Some(info);
-                    Ok(())
-                },
-            )?;
+            let res = info.proposal.dispatch_bypass_filter(
+                frame_system::RawOrigin::Signed(daoent_dao::Pallet::<T>::try_get_dao_account_id(
+                    dao_id,
+                )?)
+                .into(),
+            );
+            let used_weight = match res {
+                Ok(post) => post.actual_weight,
+                Err(e) => e.post_info.actual_weight,
+            }
+            .unwrap_or(info.weight);
+            Self::deposit_event(Event::<T>::OptimisticExecuted {
+                dao_id,
+                index,
+                result: res.map(|_| ()).map_err(|e| e.error),
+            });
 
-            Self::deposit_event(Event::<T>::Vote(dao_id, referendum_index, pledge));
-            Ok(().into())
+            Ok(Some(
+                <T as pallet::Config>::WeightInfo::execute_optimistic().saturating_add(used_weight),
+            )
+            .into())
         }
 
-        /// Cancel a vote on a referendum
-        /// 取消一个投票
-        #[pallet::call_index(005)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_vote())]
-        pub fn cancel_vote(
+        /// Set or remove the commit-reveal rule of a call
+        /// 设置调用的隐秘投票规则
+        #[pallet::call_index(025)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_commit_reveal_rule())]
+        pub fn set_commit_reveal_rule(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            index: ReferendumIndex,
+            call_id: T::CallId,
+            rule: Option<CommitRevealRule<T::BlockNumber>>,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-            ReferendumInfoOf::<T>::try_mutate_exists(
+            let me = ensure_signed(origin)?;
+            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+
+            match rule {
+                Some(ref r) => {
+                    ensure!(
+                        r.reveal_period > T::BlockNumber::from(0u32),
+                        Error::<T>::PeriodTooShort
+                    );
+                    ensure!(
+                        r.reveal_period <= T::MaxGovPeriod::get(),
+                        Error::<T>::PeriodTooLong
+                    );
+                    CommitRevealRuleOf::<T>::insert(dao_id, call_id, r);
+                }
+                None => CommitRevealRuleOf::<T>::remove(dao_id, call_id),
+            }
+            Self::deposit_event(Event::<T>::SetCommitRevealRule {
                 dao_id,
-                index,
-                |h| -> result::Result<(), DispatchError> {
-                    let mut info = h.take().ok_or(Error::<T>::ReferendumNotExists)?;
-                    let now = Self::now();
-                    if let ReferendumInfo::Ongoing(ref mut x) = info {
-                        if x.end > now {
-                            let mut votes = VotesOf::<T>::get(&who);
-                            votes.retain(|h| {
-                                if h.referendum_index == index
-                                    && h.pledge.vote_end_do(&who, &dao_id).is_ok()
-                                {
-                                    match h.opinion {
-                                        Opinion::NO => {
-                                            x.tally.no = x.tally.no.saturating_sub(h.vote_weight);
-                                        }
-                                        _ => {
-                                            x.tally.yes = x.tally.yes.saturating_sub(h.vote_weight);
-                                        }
-                                    };
-                                    false
-                                } else {
-                                    true
-                                }
-                            });
-                            VotesOf::<T>::insert(&who, votes);
-                        } else {
-                            return Err(Error::<T>::VoteEnd)?;
-                        }
-                    } else {
-                        return Err(Error::<T>::ReferendumFinished)?;
-                    }
-                    *h = Some(info);
-                    Ok(())
-                },
-            )?;
-            Self::deposit_event(Event::<T>::CancelVote(dao_id, index));
+                call_id,
+                rule,
+            });
 
             Ok(().into())
         }
 
-        /// Vote and execute the transaction corresponding to the proposa
-        /// 执行一个投票通过提案
-        #[pallet::call_index(006)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::run_proposal())]
-        pub fn run_proposal(
+        /// Commit a hidden vote, `commitment` being `hash(who, referendum_index, opinion, salt)`
+        /// 提交隐秘投票
+        #[pallet::call_index(026)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::commit_vote())]
+        pub fn commit_vote(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            index: ReferendumIndex,
+            referendum_index: ReferendumIndex,
+            pledge: T::Pledge,
+            commitment: T::Hash,
         ) -> DispatchResultWithPostInfo {
-            ensure_signed(origin)?;
+            let who = ensure_signed(origin)?;
             let now = Self::now();
-            let mut approved = false;
-            let info =
-                ReferendumInfoOf::<T>::get(dao_id, index).ok_or(Error::<T>::ReferendumNotExists)?;
-            match info {
-                ReferendumInfo::Ongoing(state) => {
-                    if state.end > now {
-                        return Err(Error::<T>::VoteNotEnd)?;
-                    } else if state.end.saturating_add(state.delay) > now {
-                        return Err(Error::<T>::InDelayTime)?;
-                    } else {
-                        {
-                            let call_id: T::CallId =
-                                TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(
-                                    state.proposal.clone(),
-                                )
-                                .unwrap_or_default();
+            ensure!(
+                CommitRevealOf::<T>::contains_key(dao_id, referendum_index),
+                Error::<T>::NotCommitReveal
+            );
+            ensure!(
+                !Commitments::<T>::contains_key((dao_id, referendum_index), &who),
+                Error::<T>::VoteRedundancy
+            );
 
-                            if state.tally.yes.saturating_add(state.tally.no)
-                                >= MinVoteWeightOf::<T>::get(dao_id, call_id)
-                            {
-                                if state.tally.yes >= state.tally.no {
-                                    approved = true;
-                                    let res = state.proposal.dispatch_bypass_filter(
-                                        frame_system::RawOrigin::Signed(
-                                            daoent_dao::Pallet::<T>::try_get_dao_account_id(
-                                                dao_id,
-                                            )?,
-                                        )
-                                        .into(),
-                                    );
-                                    Self::deposit_event(Event::EnactProposal {
-                                        dao_id,
-                                        index,
-                                        result: res.map(|_| ()).map_err(|e| e.error),
-                                    });
-                                } else {
-                                    Self::deposit_event(Event::EnactProposal {
-                                        dao_id,
-                                        index,
-                                        result: Err(Error::<T>::VoteEndButNotPass)?,
-                                    });
-                                }
-                            } else {
-                                return Err(Error::<T>::VoteWeightTooLow)?;
-                            }
-                        }
-                    }
-                }
+            let info = ReferendumInfoOf::<T>::get(dao_id, referendum_index)
+                .ok_or(Error::<T>::ReferendumNotExists)?;
+            let state = match info {
+                ReferendumInfo::Ongoing(x) => x,
                 _ => return Err(Error::<T>::ReferendumFinished)?,
-            }
-            ReferendumInfoOf::<T>::insert(
-                dao_id,
-                index,
-                ReferendumInfo::Finished { approved, end: now },
+            };
+            Self::check_auth_for_vote(dao_id, state.member_data.clone(), who.clone())?;
+            ensure!(
+                Self::clock(dao_id, referendum_index, &state).voting(),
+                Error::<T>::VoteEnd
             );
 
+            let vote_model = GovConfigOf::<T>::get(dao_id).vote_model;
+            let (vote_weight, duration) =
+                pledge.try_vote(&who, &dao_id, referendum_index, vote_model)?;
+            Self::take_reap_deposit(dao_id, referendum_index, &who)?;
+            Commitments::<T>::insert(
+                (dao_id, referendum_index),
+                &who,
+                VoteCommitment {
+                    pledge,
+                    commitment,
+                    vote_weight,
+                    unlock_block: now + duration,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::VoteCommitted {
+                dao_id,
+                index: referendum_index,
+                who,
+            });
             Ok(().into())
         }
 
-        /// Unlock
-        #[pallet::call_index(007)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::unlock())]
-        pub fn unlock(origin: OriginFor<T>, dao_id: DaoAssetId) -> DispatchResultWithPostInfo {
+        /// Reveal a hidden vote after voting ends, only revealed votes are counted
+        /// 揭示隐秘投票并计票
+        #[pallet::call_index(027)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_vote())]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            referendum_index: ReferendumIndex,
+            opinion: Opinion,
+            salt: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            let now = Self::now();
-            //
-            {
-                let mut total = BalanceOf::<T>::from(0u32);
-                let mut reserve_info = ReserveOf::<T>::get(&who);
-                reserve_info.retain(|h| {
-                    if h.1 > now {
-                        true
-                    } else {
-                        daoent_assets::Pallet::<T>::unreserve(dao_id, who.clone(), h.0).unwrap();
-                        total += h.0;
-                        false
-                    }
-                });
-                ReserveOf::<T>::insert(&who, reserve_info);
-                Self::deposit_event(Event::<T>::Unreserved(who.clone(), total));
-            }
+            let rule = CommitRevealOf::<T>::get(dao_id, referendum_index)
+                .ok_or(Error::<T>::NotCommitReveal)?;
+            let commitment = Commitments::<T>::get((dao_id, referendum_index), &who)
+                .ok_or(Error::<T>::CommitmentNotExists)?;
+            ensure!(
+                T::Hashing::hash_of(&(who.clone(), referendum_index, opinion.clone(), salt))
+                    == commitment.commitment,
+                Error::<T>::BadReveal
+            );
+            let slot = Self::vote_slot(&who, dao_id, referendum_index)?;
 
-            //
-            {
-                let mut votes = VotesOf::<T>::get(&who);
-                votes.retain(|h| {
-                    if h.unlock_block > now || h.pledge.vote_end_do(&who, &h.dao_id).is_err() {
-                        true
+            ReferendumInfoOf::<T>::try_mutate_exists(
+                dao_id,
+                referendum_index,
+                |h| -> result::Result<(), DispatchError> {
+                    let mut info = h.take().ok_or(Error::<T>::ReferendumNotExists)?;
+                    if let ReferendumInfo::Ongoing(ref mut x) = info {
+                        let clock = Self::clock(dao_id, referendum_index, x);
+                        ensure!(
+                            !clock.voting()
+                                && clock.now
+                                    < clock.end.saturating_add(
+                                        clock.span(rule.reveal_period.saturated_into())
+                                    ),
+                            Error::<T>::NotRevealTime
+                        );
+                        match opinion {
+                            Opinion::NO => {
+                                x.tally.no += commitment.vote_weight;
+                            }
+                            Opinion::YES => {
+                                x.tally.yes += commitment.vote_weight;
+                            }
+                        };
+                        Self::count_member(dao_id, referendum_index, &opinion, true);
                     } else {
-                        Self::deposit_event(Event::<T>::Unlock(who.clone(), h.dao_id, h.pledge));
-                        false
+                        return Err(Error::<T>::ReferendumFinished)?;
                     }
-                });
-                VotesOf::<T>::insert(&who, votes);
-            }
+                    *h = Some(info);
+                    Ok(())
+                },
+            )?;
 
+            Commitments::<T>::remove((dao_id, referendum_index), &who);
+            VotesOf::<T>::mutate(&who, |votes| {
+                votes.insert(
+                    slot,
+                    VoteInfo {
+                        dao_id,
+                        pledge: commitment.pledge,
+                        opinion: opinion.clone(),
+                        vote_weight: commitment.vote_weight,
+                        unlock_block: commitment.unlock_block,
+                        referendum_index,
+                    },
+                )
+            });
+
+            Self::deposit_event(Event::<T>::VoteRevealed {
+                dao_id,
+                index: referendum_index,
+                who,
+                opinion,
+                vote_weight: commitment.vote_weight,
+            });
             Ok(().into())
         }
 
-        /// Set Origin for each Call.
-        #[pallet::call_index(008)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_min_vote_weight_for_every_call())]
-        pub fn set_min_vote_weight_for_every_call(
+        /// Clear a vote that was not revealed in time, slashing or releasing its pledge
+        /// 清理未揭示的投票
+        #[pallet::call_index(028)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::clear_unrevealed())]
+        pub fn clear_unrevealed(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            call_id: T::CallId,
-            min_vote_weight: BalanceOf<T>,
+            referendum_index: ReferendumIndex,
+            who: T::AccountId,
         ) -> DispatchResultWithPostInfo {
-            let me = ensure_signed(origin)?;
-            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
-            MinVoteWeightOf::<T>::insert(dao_id, call_id, min_vote_weight);
-            Self::deposit_event(Event::<T>::SetMinVoteWeight(
+            let reaper = ensure_signed(origin)?;
+            let rule = CommitRevealOf::<T>::get(dao_id, referendum_index)
+                .ok_or(Error::<T>::NotCommitReveal)?;
+            let commitment = Commitments::<T>::get((dao_id, referendum_index), &who)
+                .ok_or(Error::<T>::CommitmentNotExists)?;
+            if let Some(ReferendumInfo::Ongoing(x)) =
+                ReferendumInfoOf::<T>::get(dao_id, referendum_index)
+            {
+                let clock = Self::clock(dao_id, referendum_index, &x);
+                ensure!(
+                    clock
+                        .end
+                        .saturating_add(clock.span(rule.reveal_period.saturated_into()))
+                        <= clock.now,
+                    Error::<T>::RevealNotEnd
+                );
+            }
+
+            if rule.slash_unrevealed {
+                commitment.pledge.slash_to(
+                    &who,
+                    &dao_id,
+                    referendum_index,
+                    &daoent_dao::Pallet::<T>::dao_account(dao_id),
+                )?;
+            } else {
+                commitment
+                    .pledge
+                    .vote_end_do(&who, &dao_id, referendum_index)?;
+            }
+            Commitments::<T>::remove((dao_id, referendum_index), &who);
+            Self::release_reap_deposit(dao_id, referendum_index, &who, &reaper);
+            Self::remove_commit_reveal_if_done(dao_id, referendum_index);
+
+            Self::deposit_event(Event::<T>::UnrevealedCleared {
                 dao_id,
-                call_id,
-                min_vote_weight,
-            ));
+                index: referendum_index,
+                who,
+                slashed: rule.slash_unrevealed,
+            });
+            Ok(().into())
+        }
+
+        /// Burn DAO tokens and withdraw their pro-rata share of the native tokens in pool B,
+        /// open to NO voters of a passed referendum until it is enacted, up to the DAO tokens
+        /// they pledged for the vote
+        /// 反对者在已通过提案执行前销毁通证并按发行量比例取回 B 池中的原生通证
+        #[pallet::call_index(029)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::rage_quit())]
+        pub fn rage_quit(
+            origin: OriginFor<T>,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let state = match ReferendumInfoOf::<T>::get(dao_id, index) {
+                Some(ReferendumInfo::Ongoing(x)) => x,
+                Some(_) => return Err(Error::<T>::ReferendumFinished)?,
+                None => return Err(Error::<T>::ReferendumNotExists)?,
+            };
+            let clock = Self::clock(dao_id, index, &state);
+            ensure!(
+                !clock.voting()
+                    && Self::reveal_end(dao_id, index, &clock) <= clock.now
+                    && clock.now < clock.enactment(),
+                Error::<T>::NotRageQuitTime
+            );
+            ensure!(
+                Self::houses_pass(dao_id, index, &state.tally, false),
+                Error::<T>::VoteEndButNotPass
+            );
+            let pledged = VotesOf::<T>::get(&who)
+                .iter()
+                .find(|v| {
+                    v.dao_id == dao_id && v.referendum_index == index && v.opinion == Opinion::NO
+                })
+                .ok_or(Error::<T>::NotNoVoter)?
+                .pledge
+                .dao_tokens()
+                .ok_or(Error::<T>::NotDaoTokenPledge)?;
+            let burned = RageQuitOf::<T>::get((dao_id, index), &who).saturating_add(amount);
+            ensure!(burned <= pledged, Error::<T>::RageQuitExceedsVote);
+
+            let pool_b = daoent_dao::Pallet::<T>::dao_asset(dao_id);
+            let pool_b_total =
+                <daoent_assets::Pallet<T> as MultiCurrency<T::AccountId>>::free_balance(
+                    daoent_assets::NATIVE_ASSET_ID,
+                    &pool_b,
+                );
+            // 与赎回相同，按总发行量计价
+            let supply =
+                <daoent_assets::Pallet<T> as MultiCurrency<T::AccountId>>::total_issuance(dao_id);
+            let withdrawn: BalanceOf<T> = multiply_by_rational_with_rounding(
+                pool_b_total.saturated_into(),
+                amount.saturated_into(),
+                supply.saturated_into(),
+                Rounding::Down,
+            )
+            .ok_or(Error::<T>::Overflow)?
+            .saturated_into();
+            ensure!(
+                withdrawn > BalanceOf::<T>::from(0u32),
+                Error::<T>::NothingToWithdraw
+            );
 
+            <daoent_assets::Pallet<T> as MultiCurrency<T::AccountId>>::withdraw(
+                dao_id, &who, amount,
+            )?;
+            RageQuitOf::<T>::insert((dao_id, index), &who, burned);
+            <daoent_assets::Pallet<T> as MultiCurrency<T::AccountId>>::transfer(
+                daoent_assets::NATIVE_ASSET_ID,
+                &pool_b,
+                &who,
+                withdrawn,
+            )?;
+
+            Self::deposit_event(Event::<T>::RageQuit {
+                dao_id,
+                index,
+                who,
+                burned: amount,
+                withdrawn,
+            });
             Ok(().into())
         }
 
-        /// Set the maximum number of proposals at the same time
-        #[pallet::call_index(009)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_max_public_props())]
-        pub fn set_max_public_props(
+        /// Measure the periods of a DAO in wall-clock time, `None` returns it to the block-based
+        /// periods of `GovConfig`. Running referendums keep their clock.
+        /// 设置以时间计量的治理时长
+        #[pallet::call_index(030)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_time_periods())]
+        pub fn set_time_periods(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            max: u32,
+            periods: Option<TimePeriods>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
             daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
 
-            MaxPublicProps::<T>::insert(dao_id, max);
-            Self::deposit_event(Event::<T>::SetMaxPublicProps { dao_id, max });
+            match periods {
+                Some(ref p) => {
+                    ensure!(
+                        p.voting_period >= Self::blocks_to_ms(T::MinVotingPeriod::get()),
+                        Error::<T>::PeriodTooShort
+                    );
+                    let max_period = Self::blocks_to_ms(T::MaxGovPeriod::get());
+                    ensure!(
+                        p.launch_period <= max_period
+                            && p.voting_period <= max_period
+                            && p.reserve_period <= max_period
+                            && p.enactment_period <= max_period,
+                        Error::<T>::PeriodTooLong
+                    );
+                    TimePeriodsOf::<T>::insert(dao_id, p);
+                }
+                None => TimePeriodsOf::<T>::remove(dao_id),
+            }
+            Self::deposit_event(Event::<T>::SetTimePeriods { dao_id, periods });
 
             Ok(().into())
         }
 
-        /// Set the referendum interval
-        #[pallet::call_index(010)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_launch_period())]
-        pub fn set_launch_period(
+        /// Set or remove the bicameral rule of a call, referendums on it then also keep a head
+        /// count tally of the member house.
+        /// 设置调用的双院制规则
+        #[pallet::call_index(031)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_bicameral_rule())]
+        pub fn set_bicameral_rule(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            period: T::BlockNumber,
+            call_id: T::CallId,
+            rule: Option<BicameralRule>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
             daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
 
-            LaunchPeriod::<T>::insert(dao_id, period);
-            Self::deposit_event(Event::<T>::SetLaunchPeriod { dao_id, period });
+            match rule {
+                Some(r) => BicameralRuleOf::<T>::insert(dao_id, call_id, r),
+                None => BicameralRuleOf::<T>::remove(dao_id, call_id),
+            }
+            Self::deposit_event(Event::<T>::SetBicameralRule {
+                dao_id,
+                call_id,
+                rule,
+            });
 
             Ok(().into())
         }
 
-        /// Set the minimum amount a proposal needs to stake
-        #[pallet::call_index(011)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_minimum_deposit())]
-        pub fn set_minimum_deposit(
+        /// Set or remove the per-account proposer limits of a DAO, enforced in `create_propose`
+        /// and `recreate`.
+        /// 设置账户提案限制
+        #[pallet::call_index(035)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_proposer_limits())]
+        pub fn set_proposer_limits(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            min: BalanceOf<T>,
+            limits: Option<ProposerLimits<T::BlockNumber>>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
             daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
 
-            MinimumDeposit::<T>::insert(dao_id, min);
-            Self::deposit_event(Event::<T>::SetMinimumDeposit { dao_id, min });
+            match limits {
+                Some(ref l) => {
+                    ensure!(
+                        l.cooldown <= T::MaxGovPeriod::get(),
+                        Error::<T>::PeriodTooLong
+                    );
+                    ProposerLimitsOf::<T>::insert(dao_id, l);
+                }
+                None => ProposerLimitsOf::<T>::remove(dao_id),
+            }
+            Self::deposit_event(Event::<T>::SetProposerLimits { dao_id, limits });
 
             Ok(().into())
         }
 
-        /// Set the voting length of the referendum
-        #[pallet::call_index(012)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_voting_period())]
-        pub fn set_voting_period(
+        /// Set or remove the participation reward rule of a DAO.
+        /// 设置投票参与奖励规则
+        #[pallet::call_index(032)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_vote_reward_rule())]
+        pub fn set_vote_reward_rule(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            period: T::BlockNumber,
+            rule: Option<VoteRewardRule<BalanceOf<T>>>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
             daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
 
-            VotingPeriod::<T>::insert(dao_id, period);
-            Self::deposit_event(Event::<T>::SetVotingPeriod { dao_id, period });
+            match rule {
+                Some(ref r) => VoteRewardRuleOf::<T>::insert(dao_id, r),
+                None => VoteRewardRuleOf::<T>::remove(dao_id),
+            }
+            Self::deposit_event(Event::<T>::SetVoteRewardRule { dao_id, rule });
 
             Ok(().into())
         }
 
-        /// Set the length of time that can be unreserved
-        #[pallet::call_index(013)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_rerserve_period())]
-        pub fn set_rerserve_period(
+        /// Move DAO tokens from the DAO account into the reward pool.
+        /// 从 DAO 账户向奖励池注入 DAO 代币
+        #[pallet::call_index(033)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::fund_reward_pool())]
+        pub fn fund_reward_pool(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            period: T::BlockNumber,
+            amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
-            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+            let dao_account = daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
 
-            ReservePeriod::<T>::insert(dao_id, period);
-            Self::deposit_event(Event::<T>::SetReservePeriod { dao_id, period });
+            daoent_assets::Pallet::<T>::try_transfer(
+                dao_id,
+                dao_account,
+                daoent_dao::Pallet::<T>::dao_reward_pool(dao_id),
+                amount,
+            )?;
+            Self::deposit_event(Event::<T>::RewardPoolFunded { dao_id, amount });
 
             Ok(().into())
         }
 
-        /// Set the time to delay the execution of the proposal
-        #[pallet::call_index(014)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_runment_period())]
-        pub fn set_runment_period(
+        /// Claim the participation rewards of votes in finished referendums of a DAO without
+        /// unlocking them. Votes are read from referendum `from` on, at most `MaxReapVotes` per
+        /// call, the summary event tells where the next page starts.
+        /// 分页领取投票参与奖励
+        #[pallet::call_index(034)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_vote_rewards())]
+        pub fn claim_vote_rewards(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            period: T::BlockNumber,
+            from: ReferendumIndex,
         ) -> DispatchResultWithPostInfo {
-            let me = ensure_signed(origin)?;
-            daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
+            let who = ensure_signed(origin)?;
+            let zero = BalanceOf::<T>::from(0u32);
 
-            EnactmentPeriod::<T>::insert(dao_id, period);
-            Self::deposit_event(Event::<T>::SetEnactmentPeriod { dao_id, period });
+            let votes = VotesOf::<T>::get(&who);
+            let first = votes.partition_point(|v| (v.dao_id, v.referendum_index) < (dao_id, from));
+            let mut total = zero;
+            let mut count = 0u32;
+            let mut read = 0u32;
+            let mut next = None;
+            for vote in votes[first..].iter().take_while(|v| v.dao_id == dao_id) {
+                if read >= T::MaxReapVotes::get() {
+                    next = Some(vote.referendum_index);
+                    break;
+                }
+                read += 1;
+                if !VoteRewardPotOf::<T>::contains_key(dao_id, vote.referendum_index) {
+                    continue;
+                }
+                let amount = Self::pay_vote_reward(&who, vote, false);
+                if amount > zero {
+                    total = total.saturating_add(amount);
+                    count += 1;
+                }
+            }
+            ensure!(total > zero || next.is_some(), Error::<T>::NoVoteRewards);
 
+            Self::deposit_event(Event::<T>::VoteRewardsClaimed {
+                dao_id,
+                who,
+                count,
+                total,
+                next,
+            });
             Ok(().into())
         }
 
-        #[pallet::call_index(015)]
-        #[pallet::weight(1_500_000_000)]
-        pub fn update_vote_model(
+        /// Update the governance parameters, fields left as `None` are unchanged
+        /// 更新治理参数，未设置的字段保持不变
+        #[pallet::call_index(019)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_gov_config())]
+        pub fn set_gov_config(
             origin: OriginFor<T>,
             dao_id: DaoAssetId,
-            model: u8,
+            patch: GovConfigPatch<T::BlockNumber, BalanceOf<T>>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
             daoent_dao::Pallet::<T>::ensrue_dao_root(me, dao_id)?;
 
-            <VoteModel<T>>::insert(dao_id, model);
-            Self::deposit_event(Event::<T>::VoteModelUpdate { dao_id, model });
+            let mut config = GovConfigOf::<T>::get(dao_id);
+            config.patch(patch);
+            Self::check_gov_config(&config)?;
+
+            GovConfigOf::<T>::insert(dao_id, config.clone());
+            Self::deposit_event(Event::<T>::SetGovConfig { dao_id, config });
 
             Ok(().into())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// 投票记录按 DAO 与全民公投排序，返回新投票的插入位置，已投票时返回错误
+        fn vote_slot(
+            who: &T::AccountId,
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+        ) -> result::Result<usize, DispatchError> {
+            VotesOf::<T>::get(who)
+                .binary_search_by(|v| (v.dao_id, v.referendum_index).cmp(&(dao_id, index)))
+                .err()
+                .ok_or_else(|| Error::<T>::VoteRedundancy.into())
+        }
+
+        /// 结束全民公投并加入清理队列
+        pub(crate) fn finish_referendum(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            approved: bool,
+            end: T::BlockNumber,
+        ) {
+            if let Some(ReferendumInfo::Ongoing(state)) = ReferendumInfoOf::<T>::get(dao_id, index)
+            {
+                Self::reserve_vote_rewards(
+                    dao_id,
+                    index,
+                    state.tally.yes.saturating_add(state.tally.no),
+                );
+            }
+            ReferendumInfoOf::<T>::insert(
+                dao_id,
+                index,
+                ReferendumInfo::Finished { approved, end },
+            );
+            ReferendumWeightOf::<T>::remove(dao_id, index);
+            ReferendumMomentsOf::<T>::remove(dao_id, index);
+            BicameralOf::<T>::remove(dao_id, index);
+            MemberTallyOf::<T>::remove(dao_id, index);
+            FastTrackOf::<T>::remove(dao_id, index);
+            VetoApprovalsOf::<T>::remove(dao_id, index);
+            FinishedQueueRange::<T>::mutate(|(_, tail)| {
+                FinishedQueue::<T>::insert(*tail, (dao_id, index, end));
+                *tail = tail.wrapping_add(1);
+            });
+            Self::settle_challenge(dao_id, index, approved);
+        }
+
+        /// 结算由乐观提案升级的全民公投，失败一方的保证金转入 DAO 账户，反对权重全部解锁
+        fn settle_challenge(dao_id: DaoAssetId, index: ReferendumIndex, approved: bool) {
+            let (proposer, bond, challengers) = match EscalatedOf::<T>::take(dao_id, index) {
+                Some(x) => x,
+                None => return,
+            };
+            let dao_account = daoent_dao::Pallet::<T>::dao_account(dao_id);
+            let proposer = sp_std::vec![(proposer, BalanceOf::<T>::from(0u32))];
+            let (losers, winners) = if approved {
+                (challengers, proposer)
+            } else {
+                (proposer, challengers)
+            };
+
+            let mut slashed = BalanceOf::<T>::from(0u32);
+            for (who, weight) in losers {
+                let missing = <daoent_assets::Pallet<T> as MultiReservableCurrency<
+                    T::AccountId,
+                >>::repatriate_reserved(
+                    dao_id, &who, &dao_account, bond, BalanceStatus::Free
+                )
+                .unwrap_or(bond);
+                slashed = slashed.saturating_add(bond.saturating_sub(missing));
+                let _ = daoent_assets::Pallet::<T>::unreserve(dao_id, who, weight);
+            }
+            for (who, weight) in winners {
+                let _ =
+                    daoent_assets::Pallet::<T>::unreserve(dao_id, who, bond.saturating_add(weight));
+            }
+
+            Self::deposit_event(Event::<T>::ChallengeSettled {
+                dao_id,
+                referendum_index: index,
+                approved,
+                slashed,
+            });
+        }
+
+        /// 清理超过保留时长的已结束全民公投
+        pub(crate) fn prune_finished(now: T::BlockNumber) -> Weight {
+            let (mut head, tail) = FinishedQueueRange::<T>::get();
+            let age = T::ArchiveAge::get();
+            let mut pruned: u64 = 0;
+
+            while head != tail && pruned < T::MaxPrunePerBlock::get() as u64 {
+                if let Some((dao_id, index, end)) = FinishedQueue::<T>::get(head) {
+                    if end.saturating_add(age) > now {
+                        break;
+                    }
+                    FinishedQueue::<T>::remove(head);
+                    if let Some(ReferendumInfo::Finished { approved, end }) =
+                        ReferendumInfoOf::<T>::get(dao_id, index)
+                    {
+                        ReferendumInfoOf::<T>::remove(dao_id, index);
+                        Self::remove_commit_reveal_if_done(dao_id, index);
+                        // 未领取的奖励退回奖励池
+                        if let Some(pot) = VoteRewardPotOf::<T>::take(dao_id, index) {
+                            CommittedRewards::<T>::mutate(dao_id, |c| {
+                                *c = c.saturating_sub(pot.remaining)
+                            });
+                        }
+                        Self::deposit_event(Event::<T>::ReferendumArchived {
+                            dao_id,
+                            index,
+                            approved,
+                            end,
+                        });
+                    }
+                }
+                head = head.wrapping_add(1);
+                pruned += 1;
+            }
+
+            if pruned > 0 {
+                FinishedQueueRange::<T>::put((head, tail));
+            }
+            T::DbWeight::get().reads_writes(1 + pruned * 3, pruned * 4 + 1)
+        }
+
+        /// 已归档且没有待清理的隐秘投票时，移除全民公投的隐秘投票规则
+        fn remove_commit_reveal_if_done(dao_id: DaoAssetId, index: ReferendumIndex) {
+            if !ReferendumInfoOf::<T>::contains_key(dao_id, index)
+                && Commitments::<T>::iter_key_prefix((dao_id, index))
+                    .next()
+                    .is_none()
+            {
+                CommitRevealOf::<T>::remove(dao_id, index);
+            }
+        }
+
+        /// The minimum deposit of an account, lowered by its `MemberPoint` when the DAO
+        /// grants a discount.
+        /// 账户提案的最小押金
+        pub fn minimum_deposit_of(dao_id: DaoAssetId, who: &T::AccountId) -> BalanceOf<T> {
+            let minimum = GovConfigOf::<T>::get(dao_id).minimum_deposit;
+            let limits = match ProposerLimitsOf::<T>::get(dao_id) {
+                Some(limits) => limits,
+                None => return minimum,
+            };
+            let points = daoent_dao::Pallet::<T>::member_point(dao_id, who.clone());
+            let discount = Perbill::from_parts(
+                limits
+                    .discount_per_point
+                    .deconstruct()
+                    .saturating_mul(points),
+            )
+            .min(limits.max_discount);
+            minimum.saturating_sub(discount.mul_floor(minimum))
+        }
+
+        /// 检查账户的提案数与冷却期
+        fn check_proposer(
+            dao_id: DaoAssetId,
+            who: &T::AccountId,
+        ) -> result::Result<(), DispatchError> {
+            let limits = match ProposerLimitsOf::<T>::get(dao_id) {
+                Some(limits) => limits,
+                None => return Ok(()),
+            };
+            if let Some(last) = LastProposedAt::<T>::get(dao_id, who) {
+                ensure!(
+                    last.saturating_add(limits.cooldown) <= Self::now(),
+                    Error::<T>::ProposalCooldown
+                );
+            }
+            ensure!(
+                limits.max_open_proposals == 0
+                    || OpenProposalsOf::<T>::get(dao_id, who) < limits.max_open_proposals,
+                Error::<T>::TooManyOpenProposals
+            );
+            Ok(())
+        }
+
+        /// 投票时收取原生通证清理押金
+        fn take_reap_deposit(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: &T::AccountId,
+        ) -> result::Result<(), DispatchError> {
+            let deposit = T::ReapReward::get();
+            if deposit == BalanceOf::<T>::from(0u32) {
+                return Ok(());
+            }
+            daoent_assets::Pallet::<T>::reserve(
+                daoent_assets::NATIVE_ASSET_ID,
+                who.clone(),
+                deposit,
+            )?;
+            ReapDepositOf::<T>::insert((dao_id, index), who, deposit);
+            Ok(())
+        }
+
+        /// 移除投票时结算清理押金，`reaper` 为投票者本人时退还，否则支付给清理者
+        fn release_reap_deposit(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            who: &T::AccountId,
+            reaper: &T::AccountId,
+        ) {
+            let deposit = match ReapDepositOf::<T>::take((dao_id, index), who) {
+                Some(deposit) => deposit,
+                None => return,
+            };
+            if reaper == who {
+                let _ = daoent_assets::Pallet::<T>::unreserve(
+                    daoent_assets::NATIVE_ASSET_ID,
+                    who.clone(),
+                    deposit,
+                );
+            } else {
+                let _ = <daoent_assets::Pallet<T> as MultiReservableCurrency<T::AccountId>>::repatriate_reserved(
+                    daoent_assets::NATIVE_ASSET_ID,
+                    who,
+                    reaper,
+                    deposit,
+                    BalanceStatus::Free,
+                );
+            }
+        }
+
+        /// 记录账户的提案
+        fn note_proposer(dao_id: DaoAssetId, who: &T::AccountId) {
+            OpenProposalsOf::<T>::mutate(dao_id, who, |n| *n = n.saturating_add(1));
+            LastProposedAt::<T>::insert(dao_id, who, Self::now());
+        }
+
+        /// 全民公投结束时从奖励池中预留投票奖励
+        fn reserve_vote_rewards(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            total_weight: BalanceOf<T>,
+        ) {
+            let rule = match VoteRewardRuleOf::<T>::get(dao_id) {
+                Some(rule) => rule,
+                None => return,
+            };
+            let pool = daoent_dao::Pallet::<T>::dao_reward_pool(dao_id);
+            let available =
+                <daoent_assets::Pallet<T> as MultiCurrency<T::AccountId>>::free_balance(
+                    dao_id, &pool,
+                )
+                .saturating_sub(CommittedRewards::<T>::get(dao_id));
+            let budget = rule
+                .rate
+                .mul_floor(total_weight)
+                .min(rule.cap)
+                .min(available);
+            if budget == BalanceOf::<T>::from(0u32) {
+                return;
+            }
+
+            CommittedRewards::<T>::mutate(dao_id, |c| *c = c.saturating_add(budget));
+            VoteRewardPotOf::<T>::insert(
+                dao_id,
+                index,
+                VoteRewardPot {
+                    total_weight,
+                    budget,
+                    remaining: budget,
+                },
+            );
+            Self::deposit_event(Event::<T>::VoteRewardsReserved {
+                dao_id,
+                index,
+                budget,
+            });
+        }
+
+        /// 支付一次投票的奖励，奖励与投票权重成正比，拆分账户不会获得更多奖励
+        /// `forget` 在投票被移除时清理领取记录
+        fn pay_vote_reward(
+            who: &T::AccountId,
+            vote: &VoteInfo<
+                DaoAssetId,
+                T::Pledge,
+                T::BlockNumber,
+                BalanceOf<T>,
+                Opinion,
+                ReferendumIndex,
+            >,
+            forget: bool,
+        ) -> BalanceOf<T> {
+            let zero = BalanceOf::<T>::from(0u32);
+            let key = (vote.dao_id, vote.referendum_index);
+            if VoteRewardClaimed::<T>::contains_key(key, who) {
+                if forget {
+                    VoteRewardClaimed::<T>::remove(key, who);
+                }
+                return zero;
+            }
+            let mut pot = match VoteRewardPotOf::<T>::get(vote.dao_id, vote.referendum_index) {
+                Some(pot) => pot,
+                None => return zero,
+            };
+            let amount: BalanceOf<T> = multiply_by_rational_with_rounding(
+                pot.budget.saturated_into(),
+                vote.vote_weight.saturated_into(),
+                pot.total_weight.saturated_into(),
+                Rounding::Down,
+            )
+            .unwrap_or(0)
+            .saturated_into();
+            let amount = amount.min(pot.remaining);
+            if amount == zero
+                || daoent_assets::Pallet::<T>::try_transfer(
+                    vote.dao_id,
+                    daoent_dao::Pallet::<T>::dao_reward_pool(vote.dao_id),
+                    who.clone(),
+                    amount,
+                )
+                .is_err()
+            {
+                return zero;
+            }
+
+            pot.remaining = pot.remaining.saturating_sub(amount);
+            VoteRewardPotOf::<T>::insert(vote.dao_id, vote.referendum_index, pot);
+            CommittedRewards::<T>::mutate(vote.dao_id, |c| *c = c.saturating_sub(amount));
+            if !forget {
+                VoteRewardClaimed::<T>::insert(key, who, ());
+            }
+            Self::deposit_event(Event::<T>::VoteRewardPaid {
+                dao_id: vote.dao_id,
+                index: vote.referendum_index,
+                who: who.clone(),
+                amount,
+            });
+            amount
+        }
+
+        /// 双院制全民公投中按人数计票
+        fn count_member(dao_id: DaoAssetId, index: ReferendumIndex, opinion: &Opinion, add: bool) {
+            if !BicameralOf::<T>::contains_key(dao_id, index) {
+                return;
+            }
+            MemberTallyOf::<T>::mutate(dao_id, index, |t| {
+                let count = match opinion {
+                    Opinion::NO => &mut t.no,
+                    Opinion::YES => &mut t.yes,
+                };
+                *count = if add {
+                    count.saturating_add(1)
+                } else {
+                    count.saturating_sub(1)
+                };
+            });
+        }
+
+        /// Whether the token house, and the member house of a bicameral referendum, approve.
+        /// A house approves with strictly more YES than NO, a tie is rejected.
+        /// With `report` set the tallies of a bicameral referendum are exposed in an event.
+        /// 代币院与成员院是否通过，平票不通过
+        fn houses_pass(
+            dao_id: DaoAssetId,
+            index: ReferendumIndex,
+            tally: &Tally<BalanceOf<T>>,
+            report: bool,
+        ) -> bool {
+            let token_pass = tally.yes > tally.no;
+            let rule = match BicameralOf::<T>::get(dao_id, index) {
+                Some(rule) => rule,
+                None => return token_pass,
+            };
+            let member_tally = MemberTallyOf::<T>::get(dao_id, index);
+            let approved = token_pass && rule.member_house_passes(&member_tally);
+            if report {
+                Self::deposit_event(Event::<T>::BicameralTallied {
+                    dao_id,
+                    index,
+                    token_tally: tally.clone(),
+                    member_tally,
+                    approved,
+                });
+            }
+            approved
+        }
+
+        /// 检查治理参数是否在允许范围内
+        pub fn check_gov_config(
+            config: &GovConfig<T::BlockNumber, BalanceOf<T>>,
+        ) -> result::Result<(), DispatchError> {
+            ensure!(
+                config.max_public_props > 0
+                    && config.max_public_props <= T::MaxPublicPropsLimit::get(),
+                Error::<T>::InvalidMaxPublicProps
+            );
+            ensure!(
+                config.voting_period >= T::MinVotingPeriod::get(),
+                Error::<T>::PeriodTooShort
+            );
+            let max_period = T::MaxGovPeriod::get();
+            ensure!(
+                config.launch_period <= max_period
+                    && config.voting_period <= max_period
+                    && config.reserve_period <= max_period
+                    && config.enactment_period <= max_period,
+                Error::<T>::PeriodTooLong
+            );
+            ensure!(config.vote_model <= 1, Error::<T>::InvalidVoteModel);
+            ensure!(
+                config
+                    .max_proposal_weight
+                    .all_lte(T::MaxProposalWeight::get()),
+                Error::<T>::InvalidMaxProposalWeight
+            );
+
+            Ok(())
+        }
+
         /// 获取当前投票的作用范围
         pub fn try_get_members(
             dao_id: DaoAssetId,
@@ -980,6 +3371,43 @@ pub mod pallet {
 
             Ok(index)
         }
+
+        /// 移除已离开紧急公会的同意者
+        fn retain_guild_approvals(
+            dao_id: DaoAssetId,
+            approvals: &mut BoundedVec<T::AccountId, T::MaxMembers>,
+        ) {
+            let members = EmergencyGuildOf::<T>::get(dao_id)
+                .map(|g| Self::emergency_members(dao_id, g.guild_id))
+                .unwrap_or_default();
+            approvals.retain(|a| members.binary_search(a).is_ok());
+        }
+
+        /// 紧急公会的成员，`COUNCIL_GUILD` 为理事会
+        fn emergency_members(
+            dao_id: DaoAssetId,
+            guild_id: u64,
+        ) -> BoundedVec<T::AccountId, T::MaxMembers> {
+            if guild_id == COUNCIL_GUILD {
+                CouncilOf::<T>::get(dao_id)
+            } else {
+                <daoent_dao::GuildMembers<T>>::get(dao_id, guild_id)
+            }
+        }
+
+        /// 获取用户是否有紧急操作的权利，返回所需的公会成员数量
+        pub fn check_auth_for_emergency(
+            dao_id: DaoAssetId,
+            who: T::AccountId,
+        ) -> result::Result<MemberCount, DispatchError> {
+            let guild =
+                EmergencyGuildOf::<T>::get(dao_id).ok_or(Error::<T>::EmergencyNotEnabled)?;
+            Self::emergency_members(dao_id, guild.guild_id)
+                .binary_search(&who)
+                .map_err(|_| Error::<T>::Gov403)?;
+
+            Ok(guild.threshold)
+        }
     }
 }
 
@@ -991,12 +3419,45 @@ impl<T: Config> Pallet<T> {
     fn inject_referendum(
         dao_id: DaoAssetId,
         proposal: <T as daoent_dao::Config>::RuntimeCall,
-        end: T::BlockNumber,
-        delay: T::BlockNumber,
+        proposal_weight: Weight,
         member_data: MemmberData<u64>,
     ) -> ReferendumIndex {
         let ref_index = Self::referendum_count(dao_id);
         ReferendumCount::<T>::insert(dao_id, ref_index + 1);
+        let now = Self::now();
+        let (end, delay) = match TimePeriodsOf::<T>::get(dao_id) {
+            Some(periods) => {
+                ReferendumMomentsOf::<T>::insert(
+                    dao_id,
+                    ref_index,
+                    ReferendumMoments {
+                        end: Self::moment().saturating_add(periods.voting_period),
+                        delay: periods.enactment_period,
+                    },
+                );
+                // 区块数仅为估计值
+                (
+                    now.saturating_add(Self::ms_to_blocks(periods.voting_period)),
+                    Self::ms_to_blocks(periods.enactment_period),
+                )
+            }
+            None => {
+                let config = GovConfigOf::<T>::get(dao_id);
+                (
+                    now.saturating_add(config.voting_period),
+                    config.enactment_period,
+                )
+            }
+        };
+        let call_id: T::CallId =
+            TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(proposal.clone())
+                .unwrap_or_default();
+        if let Some(rule) = CommitRevealRuleOf::<T>::get(dao_id, call_id) {
+            CommitRevealOf::<T>::insert(dao_id, ref_index, rule);
+        }
+        if let Some(rule) = BicameralRuleOf::<T>::get(dao_id, call_id) {
+            BicameralOf::<T>::insert(dao_id, ref_index, rule);
+        }
         let status = ReferendumStatus {
             end,
             proposal,
@@ -1007,10 +3468,272 @@ impl<T: Config> Pallet<T> {
 
         let item = ReferendumInfo::Ongoing(status);
         <ReferendumInfoOf<T>>::insert(dao_id, ref_index, item);
+        ReferendumWeightOf::<T>::insert(dao_id, ref_index, proposal_weight);
         ref_index
     }
 
     fn now() -> T::BlockNumber {
         frame_system::Pallet::<T>::current_block_number()
     }
+
+    fn moment() -> Moment {
+        T::UnixTime::now().as_millis().saturated_into()
+    }
+
+    fn blocks_to_ms(blocks: T::BlockNumber) -> Moment {
+        blocks
+            .saturated_into::<u64>()
+            .saturating_mul(T::MillisecsPerBlock::get())
+    }
+
+    fn ms_to_blocks(ms: Moment) -> T::BlockNumber {
+        let per_block = T::MillisecsPerBlock::get().max(1);
+        ms.saturating_add(per_block - 1)
+            .saturating_div(per_block)
+            .saturated_into()
+    }
+
+    /// 获取全民公投的时钟
+    pub fn clock(
+        dao_id: DaoAssetId,
+        index: ReferendumIndex,
+        state: &ReferendumStatus<
+            T::BlockNumber,
+            <T as daoent_dao::Config>::RuntimeCall,
+            BalanceOf<T>,
+        >,
+    ) -> ReferendumClock {
+        match ReferendumMomentsOf::<T>::get(dao_id, index) {
+            Some(m) => ReferendumClock {
+                now: Self::moment(),
+                end: m.end,
+                delay: m.delay,
+                unit: T::MillisecsPerBlock::get(),
+            },
+            None => ReferendumClock {
+                now: Self::now().saturated_into(),
+                end: state.end.saturated_into(),
+                delay: state.delay.saturated_into(),
+                unit: 1,
+            },
+        }
+    }
+
+    /// 获取隐秘投票揭示期的结束时间，非隐秘投票为投票结束时间
+    pub fn reveal_end(dao_id: DaoAssetId, index: ReferendumIndex, clock: &ReferendumClock) -> u64 {
+        CommitRevealOf::<T>::get(dao_id, index)
+            .map(|r| {
+                clock
+                    .end
+                    .saturating_add(clock.span(r.reveal_period.saturated_into()))
+            })
+            .unwrap_or(clock.end)
+    }
+
+    /// 获取 DAO 的全部提案及其支持情况
+    pub fn open_proposals(
+        dao_id: DaoAssetId,
+    ) -> Vec<ProposalSummary<T::AccountId, BalanceOf<T>, T::Hash>> {
+        Self::public_props(dao_id)
+            .into_iter()
+            .map(|(index, proposal_hash, _, member_data, proposer)| {
+                let (backers, deposit) = Self::deposit_of(dao_id, index)
+                    .map(|(l, d)| (l.len() as u32, d))
+                    .unwrap_or_default();
+                ProposalSummary {
+                    index,
+                    proposal_hash,
+                    proposer,
+                    member_data,
+                    backers,
+                    backing: deposit.saturating_mul(backers.into()),
+                }
+            })
+            .collect()
+    }
+
+    /// 获取全民公投的状态
+    pub fn referendum_summary(
+        dao_id: DaoAssetId,
+        index: ReferendumIndex,
+    ) -> Option<ReferendumSummary<BalanceOf<T>, T::BlockNumber>> {
+        let summary = match Self::referendum_info(dao_id, index)? {
+            ReferendumInfo::Ongoing(state) => {
+                let clock = Self::clock(dao_id, index, &state);
+                let to_blocks = |units: u64| -> T::BlockNumber {
+                    if clock.unit == 1 {
+                        units.saturated_into()
+                    } else {
+                        Self::ms_to_blocks(units)
+                    }
+                };
+                let call_id: T::CallId =
+                    TryFrom::<<T as daoent_dao::Config>::RuntimeCall>::try_from(state.proposal)
+                        .unwrap_or_default();
+                let min_vote_weight = MinVoteWeightOf::<T>::get(dao_id, call_id);
+                ReferendumSummary::Ongoing {
+                    end: state.end,
+                    enactment: state.end.saturating_add(state.delay),
+                    would_pass: state.tally.yes.saturating_add(state.tally.no) >= min_vote_weight
+                        && Self::houses_pass(dao_id, index, &state.tally, false),
+                    tally: state.tally,
+                    min_vote_weight,
+                    blocks_to_end: to_blocks(clock.end.saturating_sub(clock.now)),
+                    blocks_to_enactment: to_blocks(clock.enactment().saturating_sub(clock.now)),
+                    moments: ReferendumMomentsOf::<T>::get(dao_id, index),
+                }
+            }
+            ReferendumInfo::Finished { approved, end } => {
+                ReferendumSummary::Finished { approved, end }
+            }
+        };
+
+        Some(summary)
+    }
+
+    /// 获取用户的投票与押金
+    pub fn voter_summary(
+        who: T::AccountId,
+    ) -> VoterSummary<T::Pledge, BalanceOf<T>, T::BlockNumber> {
+        let now = Self::now();
+        let votes = Self::votes_of(&who)
+            .into_iter()
+            .map(|v| VoteSummary {
+                dao_id: v.dao_id,
+                referendum_index: v.referendum_index,
+                pledge: v.pledge,
+                opinion: v.opinion,
+                vote_weight: v.vote_weight,
+                unlock_block: v.unlock_block,
+                unlockable: v.unlock_block <= now,
+            })
+            .collect();
+
+        let mut reserved = BalanceOf::<T>::from(0u32);
+        let mut unreservable = BalanceOf::<T>::from(0u32);
+        for (amount, unreserve_block) in Self::reserve_of(&who) {
+            reserved = reserved.saturating_add(amount);
+            if unreserve_block <= now {
+                unreservable = unreservable.saturating_add(amount);
+            }
+        }
+
+        VoterSummary {
+            votes,
+            reserved,
+            unreservable,
+        }
+    }
+
+    /// 在回滚的存储事务中以 DAO 账户执行提案，返回执行结果、权重、事件与余额变化
+    pub fn dry_run(
+        dao_id: DaoAssetId,
+        target: DryRunTarget,
+    ) -> Option<DryRunResult<T::AccountId, BalanceOf<T>>> {
+        let proposal = match target {
+            DryRunTarget::Proposal(index) => Self::public_props(dao_id)
+                .into_iter()
+                .find(|p| p.0 == index)
+                .map(|p| p.2)?,
+            DryRunTarget::Referendum(index) => match Self::referendum_info(dao_id, index)? {
+                ReferendumInfo::Ongoing(state) => state.proposal,
+                _ => return None,
+            },
+        };
+        let dao_account = daoent_dao::Pallet::<T>::try_get_dao_account_id(dao_id).ok()?;
+
+        // 所有 DAO 账户在原生通证、DAO 通证及附加资产上的余额
+        let accounts = daoent_dao::Pallet::<T>::dao_accounts(dao_id);
+        let mut assets = sp_std::vec![daoent_assets::NATIVE_ASSET_ID, dao_id];
+        assets
+            .extend(daoent_assets::AssetsOf::<T>::iter_key_prefix(dao_id).filter(|a| *a != dao_id));
+        let balances = || {
+            let mut v = Vec::new();
+            for account in accounts.iter() {
+                for asset_id in assets.iter().copied() {
+                    v.push(
+                        <daoent_assets::Pallet<T> as MultiCurrency<T::AccountId>>::total_balance(
+                            asset_id, account,
+                        ),
+                    );
+                }
+            }
+            v
+        };
+
+        let before = balances();
+        let info = proposal.get_dispatch_info();
+        let outcome = with_transaction(|| {
+            let skip = frame_system::Pallet::<T>::event_count() as usize;
+            let res = proposal.dispatch_bypass_filter(
+                frame_system::RawOrigin::Signed(dao_account.clone()).into(),
+            );
+            let events = frame_system::Pallet::<T>::events()
+                .into_iter()
+                .skip(skip)
+                .map(|r| r.event.encode())
+                .collect::<Vec<_>>();
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>((res, events, balances())))
+        })
+        .ok()?;
+        let (res, events, after) = outcome;
+
+        let weight = match &res {
+            Ok(post) => post.actual_weight,
+            Err(e) => e.post_info.actual_weight,
+        }
+        .unwrap_or(info.weight);
+
+        let mut balance_changes = Vec::new();
+        for (i, account) in accounts.iter().enumerate() {
+            for (j, asset_id) in assets.iter().enumerate() {
+                let k = i * assets.len() + j;
+                if before[k] != after[k] {
+                    balance_changes.push(BalanceChange {
+                        account: account.clone(),
+                        asset_id: *asset_id,
+                        before: before[k],
+                        after: after[k],
+                    });
+                }
+            }
+        }
+
+        Some(DryRunResult {
+            result: res.map(|_| ()).map_err(|e| e.error),
+            weight,
+            events,
+            balance_changes,
+        })
+    }
+}
+
+/// An elected council is kept in `CouncilOf`, apart from the guilds. It holds the emergency
+/// powers when the DAO set `COUNCIL_GUILD` as its emergency guild. Only DAO members take a seat.
+/// 选举产生的理事会，设置为紧急公会时拥有紧急权限
+impl<T: Config> SetCollectiveMembers<T::AccountId, DispatchError> for Pallet<T> {
+    fn set_members_sorted(
+        dao_id: DaoAssetId,
+        members: &[T::AccountId],
+        _prime: Option<T::AccountId>,
+    ) -> result::Result<(), DispatchError> {
+        daoent_dao::Pallet::<T>::try_get_dao(dao_id)?;
+        let dao_members = <daoent_dao::Members<T>>::get(dao_id);
+        let council: BoundedVec<T::AccountId, T::MaxMembers> = members
+            .iter()
+            .filter(|m| dao_members.binary_search(m).is_ok())
+            .cloned()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| daoent_dao::Error::<T>::TooManyMembers)?;
+
+        CouncilOf::<T>::insert(dao_id, &council);
+        Self::deposit_event(Event::<T>::CouncilUpdated {
+            dao_id,
+            members: council.into_inner(),
+            emergency: EmergencyGuildOf::<T>::get(dao_id)
+                .map_or(false, |g| g.guild_id == COUNCIL_GUILD),
+        });
+        Ok(())
+    }
 }
@@ -0,0 +1,230 @@
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    storage_alias,
+    traits::{GetStorageVersion, StorageVersion},
+};
+
+pub mod v1 {
+    use super::*;
+
+    #[storage_alias]
+    pub type MaxPublicProps<T: Config> = StorageMap<Pallet<T>, Identity, DaoAssetId, u32>;
+
+    #[storage_alias]
+    pub type LaunchPeriod<T: Config> =
+        StorageMap<Pallet<T>, Identity, DaoAssetId, <T as frame_system::Config>::BlockNumber>;
+
+    #[storage_alias]
+    pub type MinimumDeposit<T: Config> = StorageMap<Pallet<T>, Identity, DaoAssetId, BalanceOf<T>>;
+
+    #[storage_alias]
+    pub type VotingPeriod<T: Config> =
+        StorageMap<Pallet<T>, Identity, DaoAssetId, <T as frame_system::Config>::BlockNumber>;
+
+    #[storage_alias]
+    pub type ReservePeriod<T: Config> =
+        StorageMap<Pallet<T>, Identity, DaoAssetId, <T as frame_system::Config>::BlockNumber>;
+
+    #[storage_alias]
+    pub type EnactmentPeriod<T: Config> =
+        StorageMap<Pallet<T>, Identity, DaoAssetId, <T as frame_system::Config>::BlockNumber>;
+
+    #[storage_alias]
+    pub type VoteModel<T: Config> = StorageMap<Pallet<T>, Identity, DaoAssetId, u8>;
+
+    /// `GovConfig` before `max_proposal_weight` was added.
+    /// v1 版本的治理参数
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovConfig<BlockNumber, Balance> {
+        pub max_public_props: u32,
+        pub launch_period: BlockNumber,
+        pub minimum_deposit: Balance,
+        pub voting_period: BlockNumber,
+        pub reserve_period: BlockNumber,
+        pub enactment_period: BlockNumber,
+        pub vote_model: u8,
+    }
+
+    impl<BlockNumber: From<u32>, Balance: From<u32>> Default for GovConfig<BlockNumber, Balance> {
+        fn default() -> Self {
+            GovConfig {
+                max_public_props: 100u32,
+                launch_period: BlockNumber::from(900u32),
+                minimum_deposit: Balance::from(0u32),
+                voting_period: BlockNumber::from(900u32),
+                reserve_period: BlockNumber::from(900u32),
+                enactment_period: BlockNumber::from(900u32),
+                vote_model: 0,
+            }
+        }
+    }
+
+    #[storage_alias]
+    pub type GovConfigOf<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        DaoAssetId,
+        GovConfig<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Move the per-parameter maps into `GovConfigOf`.
+    /// 将分散的治理参数迁移到 `GovConfigOf`
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut count: u64 = 0;
+        for (dao_id, v) in MaxPublicProps::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.max_public_props = v);
+            count += 1;
+        }
+        for (dao_id, v) in LaunchPeriod::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.launch_period = v);
+            count += 1;
+        }
+        for (dao_id, v) in MinimumDeposit::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.minimum_deposit = v);
+            count += 1;
+        }
+        for (dao_id, v) in VotingPeriod::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.voting_period = v);
+            count += 1;
+        }
+        for (dao_id, v) in ReservePeriod::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.reserve_period = v);
+            count += 1;
+        }
+        for (dao_id, v) in EnactmentPeriod::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.enactment_period = v);
+            count += 1;
+        }
+        for (dao_id, v) in VoteModel::<T>::drain() {
+            GovConfigOf::<T>::mutate(dao_id, |c| c.vote_model = v);
+            count += 1;
+        }
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(count * 2 + 1, count * 2 + 1)
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// `GovConfig` before `nft_vote_weight` was added.
+    /// v2 版本的治理参数
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovConfig<BlockNumber, Balance> {
+        pub max_public_props: u32,
+        pub launch_period: BlockNumber,
+        pub minimum_deposit: Balance,
+        pub voting_period: BlockNumber,
+        pub reserve_period: BlockNumber,
+        pub enactment_period: BlockNumber,
+        pub vote_model: u8,
+        pub max_proposal_weight: Weight,
+    }
+
+    #[storage_alias]
+    pub type GovConfigOf<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        DaoAssetId,
+        GovConfig<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>,
+    >;
+
+    /// Add `max_proposal_weight` to every `GovConfig`, defaulting to `MaxProposalWeight`.
+    /// 为治理参数添加提案执行的最大权重
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 2 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut count: u64 = 0;
+        GovConfigOf::<T>::translate::<v1::GovConfig<T::BlockNumber, BalanceOf<T>>, _>(|_, old| {
+            count += 1;
+            Some(GovConfig {
+                max_public_props: old.max_public_props,
+                launch_period: old.launch_period,
+                minimum_deposit: old.minimum_deposit,
+                voting_period: old.voting_period,
+                reserve_period: old.reserve_period,
+                enactment_period: old.enactment_period,
+                vote_model: old.vote_model,
+                max_proposal_weight: T::MaxProposalWeight::get(),
+            })
+        });
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// Add `nft_vote_weight` to every `GovConfig`, keeping one vote per membership NFT.
+    /// 为治理参数添加会员 NFT 的投票权重
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 3 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut count: u64 = 0;
+        GovConfigOf::<T>::translate::<v2::GovConfig<T::BlockNumber, BalanceOf<T>>, _>(|_, old| {
+            count += 1;
+            Some(GovConfig {
+                max_public_props: old.max_public_props,
+                launch_period: old.launch_period,
+                minimum_deposit: old.minimum_deposit,
+                voting_period: old.voting_period,
+                reserve_period: old.reserve_period,
+                enactment_period: old.enactment_period,
+                vote_model: old.vote_model,
+                max_proposal_weight: old.max_proposal_weight,
+                nft_vote_weight: BalanceOf::<T>::from(1u32),
+            })
+        });
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+}
+
+pub mod v4 {
+    use super::*;
+
+    /// Sort the votes of every account by DAO and referendum, so that duplicate votes are found
+    /// by binary search.
+    /// 按 DAO 与全民公投排序所有投票记录
+    pub fn migrate<T: Config>() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 4 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut count: u64 = 0;
+        VotesOf::<T>::translate_values::<
+            Vec<
+                VoteInfo<
+                    DaoAssetId,
+                    T::Pledge,
+                    T::BlockNumber,
+                    BalanceOf<T>,
+                    Opinion,
+                    ReferendumIndex,
+                >,
+            >,
+            _,
+        >(|mut votes| {
+            count += 1;
+            votes.sort_by_key(|v| (v.dao_id, v.referendum_index));
+            Some(votes)
+        });
+
+        StorageVersion::new(4).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+}
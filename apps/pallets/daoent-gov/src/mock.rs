@@ -9,6 +9,7 @@ use daoent_primitives::types::{CallId, DaoAssetId};
 use frame_support::{
     parameter_types,
     traits::{ConstU16, ConstU32, ConstU64, Contains},
+    weights::Weight,
     PalletId, RuntimeDebug,
 };
 use orml_traits::parameter_type_with_key;
@@ -47,6 +48,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage},
 
         DAO: daoent_dao::{ Pallet, Call, Event<T>, Storage },
         DAOAsset: daoent_assets::{ Pallet, Call, Event<T>, Storage },
@@ -130,13 +132,15 @@ impl TryFrom<RuntimeCall> for CallId {
                 daoent_gov::Call::run_proposal { .. } => Ok(406 as CallId),
                 daoent_gov::Call::unlock { .. } => Ok(407 as CallId),
                 daoent_gov::Call::set_min_vote_weight_for_every_call { .. } => Ok(408 as CallId),
-                daoent_gov::Call::set_max_public_props { .. } => Ok(409 as CallId),
-                daoent_gov::Call::set_launch_period { .. } => Ok(410 as CallId),
-                daoent_gov::Call::set_minimum_deposit { .. } => Ok(411 as CallId),
-                daoent_gov::Call::set_voting_period { .. } => Ok(412 as CallId),
-                daoent_gov::Call::set_rerserve_period { .. } => Ok(413 as CallId),
-                daoent_gov::Call::set_runment_period { .. } => Ok(414 as CallId),
-                daoent_gov::Call::update_vote_model { .. } => Ok(415 as CallId),
+                daoent_gov::Call::set_emergency_guild { .. } => Ok(416 as CallId),
+                daoent_gov::Call::set_gov_config { .. } => Ok(417 as CallId),
+                daoent_gov::Call::set_optimistic_rule { .. } => Ok(418 as CallId),
+                daoent_gov::Call::set_commit_reveal_rule { .. } => Ok(419 as CallId),
+                daoent_gov::Call::set_time_periods { .. } => Ok(420 as CallId),
+                daoent_gov::Call::set_bicameral_rule { .. } => Ok(421 as CallId),
+                daoent_gov::Call::set_vote_reward_rule { .. } => Ok(422 as CallId),
+                daoent_gov::Call::fund_reward_pool { .. } => Ok(423 as CallId),
+                daoent_gov::Call::set_proposer_limits { .. } => Ok(424 as CallId),
                 _ => Err(()),
             },
             _ => Err(()),
@@ -189,19 +193,70 @@ impl PledgeTrait<u64, AccountId, u64, u64, DispatchError> for Vote {
         &self,
         _who: &AccountId,
         _dao_id: &u64,
+        _referendum_index: u32,
         vote_model: u8,
     ) -> Result<(u64, u64), DispatchError> {
         Ok((100u64, 100u64))
     }
 
-    fn vote_end_do(&self, _who: &AccountId, _dao_id: &u64) -> Result<(), DispatchError> {
+    fn vote_end_do(
+        &self,
+        _who: &AccountId,
+        _dao_id: &u64,
+        _referendum_index: u32,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn slash_to(
+        &self,
+        _who: &AccountId,
+        _dao_id: &u64,
+        _referendum_index: u32,
+        _beneficiary: &AccountId,
+    ) -> Result<(), DispatchError> {
         Ok(())
     }
+
+    fn dao_tokens(&self) -> Option<u64> {
+        Some(self.0)
+    }
+}
+
+parameter_types! {
+    pub const MaxPublicPropsLimit: u32 = 100;
+    pub const MinVotingPeriod: BlockNumber = 10;
+    pub const MaxGovPeriod: BlockNumber = 100_000;
+    pub const ArchiveAge: BlockNumber = 100;
+    pub const MaxPrunePerBlock: u32 = 2;
+    pub const MaxReapVotes: u32 = 10;
+    pub const ReapReward: Balance = 1;
+    pub const VetoCoolOff: BlockNumber = 1000;
+    pub const MaxProposalWeight: Weight = Weight::from_ref_time(1_000_000_000_000);
+    pub const MillisecsPerBlock: u64 = 6000;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
 }
 
 impl daoent_gov::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Pledge = Vote;
+    type MaxPublicPropsLimit = MaxPublicPropsLimit;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxGovPeriod = MaxGovPeriod;
+    type ArchiveAge = ArchiveAge;
+    type MaxPrunePerBlock = MaxPrunePerBlock;
+    type MaxReapVotes = MaxReapVotes;
+    type ReapReward = ReapReward;
+    type VetoCoolOff = VetoCoolOff;
+    type UnixTime = Timestamp;
+    type MillisecsPerBlock = MillisecsPerBlock;
+    type MaxProposalWeight = MaxProposalWeight;
     type WeightInfo = ();
 }
 
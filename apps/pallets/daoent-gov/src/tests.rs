@@ -2,7 +2,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Get};
 use mock::{RuntimeCall, RuntimeOrigin, *};
 use sp_runtime::traits::BlakeTwo256;
 
@@ -93,24 +93,40 @@ pub fn vote() {
 
 pub fn run() {
     vote();
-    assert!(Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32).is_err());
+    assert!(
+        Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, Weight::MAX)
+            .is_err()
+    );
     frame_system::Pallet::<Test>::set_block_number(
-        10000 + VotingPeriod::<Test>::get(0u64) + EnactmentPeriod::<Test>::get(0u64) - 2,
+        10000
+            + GovConfigOf::<Test>::get(0u64).voting_period
+            + GovConfigOf::<Test>::get(0u64).enactment_period
+            - 2,
+    );
+    assert!(
+        Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, Weight::MAX)
+            .is_err()
     );
-    assert!(Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32).is_err());
     frame_system::Pallet::<Test>::set_block_number(20000);
 
     let ole_min_weight = MinVoteWeightOf::<Test>::get(0u64, 0);
     MinVoteWeightOf::<Test>::insert(0u64, 0, 10000000000);
-    assert!(Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), 0u64, 0u32).is_err());
+    assert!(
+        Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), 0u64, 0u32, Weight::MAX)
+            .is_err()
+    );
     MinVoteWeightOf::<Test>::insert(0u64, 0, ole_min_weight);
 
     assert_ok!(Pallet::<Test>::run_proposal(
         RuntimeOrigin::signed(ALICE),
         DAO_ID,
-        0u32
+        0u32,
+        Weight::MAX
     ));
-    assert!(Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32).is_err());
+    assert!(
+        Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, Weight::MAX)
+            .is_err()
+    );
     assert!(Pallet::<Test>::vote_for_referendum(
         RuntimeOrigin::signed(ALICE),
         DAO_ID,
@@ -170,3 +186,1923 @@ pub fn unlock_should_work() {
         assert_ok!(Pallet::<Test>::unlock(RuntimeOrigin::signed(ALICE), DAO_ID));
     });
 }
+
+pub fn enable_emergency() {
+    let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+    assert!(
+        Pallet::<Test>::set_emergency_guild(RuntimeOrigin::signed(ALICE), DAO_ID, 0, 2).is_err()
+    );
+    assert!(
+        Pallet::<Test>::set_emergency_guild(RuntimeOrigin::signed(root), DAO_ID, 0, 0).is_err()
+    );
+    assert!(
+        Pallet::<Test>::set_emergency_guild(RuntimeOrigin::signed(root), DAO_ID, 5, 2).is_err()
+    );
+    assert_ok!(Pallet::<Test>::set_emergency_guild(
+        RuntimeOrigin::signed(root),
+        DAO_ID,
+        0,
+        2
+    ));
+    daoent_dao::Pallet::<Test>::try_add_guild_member(DAO_ID, 0, BOB).unwrap();
+}
+
+#[test]
+pub fn fast_track_should_work() {
+    new_test_run().execute_with(|| {
+        vote();
+        assert_eq!(
+            Pallet::<Test>::fast_track(RuntimeOrigin::signed(ALICE), DAO_ID, 0, 10, 5),
+            Err(Error::<Test>::EmergencyNotEnabled.into())
+        );
+        enable_emergency();
+
+        assert_eq!(
+            Pallet::<Test>::fast_track(RuntimeOrigin::signed(ALICE), DAO_ID, 0, 10000, 5),
+            Err(Error::<Test>::FastTrackNotShorter.into())
+        );
+        assert_ok!(Pallet::<Test>::fast_track(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0,
+            10,
+            5
+        ));
+        assert_eq!(
+            Pallet::<Test>::fast_track(RuntimeOrigin::signed(ALICE), DAO_ID, 0, 10, 5),
+            Err(Error::<Test>::AlreadyApproved.into())
+        );
+        assert_eq!(
+            Pallet::<Test>::fast_track(RuntimeOrigin::signed(BOB), DAO_ID, 0, 10, 6),
+            Err(Error::<Test>::FastTrackMismatch.into())
+        );
+        assert!(Pallet::<Test>::fast_track(RuntimeOrigin::signed(103), DAO_ID, 0, 10, 5).is_err());
+        assert_ok!(Pallet::<Test>::fast_track(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            10,
+            5
+        ));
+        assert!(FastTrackOf::<Test>::get(DAO_ID, 0).is_none());
+
+        frame_system::Pallet::<Test>::set_block_number(10014);
+        assert!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        )
+        .is_err());
+        frame_system::Pallet::<Test>::set_block_number(10015);
+        assert_ok!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        ));
+    });
+}
+
+#[test]
+pub fn fast_track_should_only_count_guild_members() {
+    new_test_run().execute_with(|| {
+        vote();
+        enable_emergency();
+        assert_ok!(Pallet::<Test>::fast_track(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0,
+            10,
+            5
+        ));
+        assert_eq!(
+            Pallet::<Test>::cancel_fast_track(RuntimeOrigin::signed(103), DAO_ID, 0),
+            Err(Error::<Test>::FastTrackNotStale.into())
+        );
+
+        // alice left the guild, her approval no longer counts
+        daoent_dao::Pallet::<Test>::try_remove_guild_member(DAO_ID, 0, ALICE).unwrap();
+        assert_ok!(Pallet::<Test>::fast_track(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            10,
+            5
+        ));
+        assert_eq!(
+            FastTrackOf::<Test>::get(DAO_ID, 0)
+                .unwrap()
+                .approvals
+                .into_inner(),
+            vec![BOB]
+        );
+
+        // no approver left, anyone can cancel
+        daoent_dao::Pallet::<Test>::try_remove_guild_member(DAO_ID, 0, BOB).unwrap();
+        assert_ok!(Pallet::<Test>::cancel_fast_track(
+            RuntimeOrigin::signed(103),
+            DAO_ID,
+            0
+        ));
+        assert!(FastTrackOf::<Test>::get(DAO_ID, 0).is_none());
+        System::assert_last_event(RuntimeEvent::DAOGov(Event::FastTrackCancelled {
+            dao_id: DAO_ID,
+            index: 0,
+        }));
+        assert_eq!(
+            Pallet::<Test>::cancel_fast_track(RuntimeOrigin::signed(103), DAO_ID, 0),
+            Err(Error::<Test>::FastTrackNotExists.into())
+        );
+
+        // a new fast-track can use other periods
+        daoent_dao::Pallet::<Test>::try_add_guild_member(DAO_ID, 0, ALICE).unwrap();
+        daoent_dao::Pallet::<Test>::try_add_guild_member(DAO_ID, 0, BOB).unwrap();
+        assert_ok!(Pallet::<Test>::fast_track(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0,
+            10,
+            6
+        ));
+        assert_ok!(Pallet::<Test>::fast_track(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            10,
+            6
+        ));
+        assert!(FastTrackOf::<Test>::get(DAO_ID, 0).is_none());
+    });
+}
+
+#[test]
+pub fn elected_council_should_be_kept_apart_from_guilds() {
+    new_test_run().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_dao();
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+
+        // 103 is not a DAO member and takes no seat, guild 0 is left alone
+        assert_ok!(
+            <Pallet<Test> as SetCollectiveMembers<u64, DispatchError>>::set_members_sorted(
+                DAO_ID,
+                &[BOB, 103],
+                None
+            )
+        );
+        assert_eq!(Pallet::<Test>::council_of(DAO_ID).into_inner(), vec![BOB]);
+        assert_eq!(
+            daoent_dao::GuildMembers::<Test>::get(DAO_ID, 0).into_inner(),
+            vec![ALICE]
+        );
+        frame_system::Pallet::<Test>::assert_has_event(
+            Event::<Test>::CouncilUpdated {
+                dao_id: DAO_ID,
+                members: vec![BOB],
+                emergency: false,
+            }
+            .into(),
+        );
+        assert!(Pallet::<Test>::check_auth_for_emergency(DAO_ID, BOB).is_err());
+        assert!(
+            <Pallet<Test> as SetCollectiveMembers<u64, DispatchError>>::set_members_sorted(
+                DAO_ID + 1,
+                &[BOB],
+                None
+            )
+            .is_err()
+        );
+
+        // the council holds the emergency powers once the DAO grants them
+        assert_ok!(Pallet::<Test>::set_emergency_guild(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            COUNCIL_GUILD,
+            1
+        ));
+        assert_eq!(Pallet::<Test>::check_auth_for_emergency(DAO_ID, BOB), Ok(1));
+        assert!(Pallet::<Test>::check_auth_for_emergency(DAO_ID, ALICE).is_err());
+
+        assert_ok!(
+            <Pallet<Test> as SetCollectiveMembers<u64, DispatchError>>::set_members_sorted(
+                DAO_ID,
+                &[ALICE],
+                Some(ALICE)
+            )
+        );
+        frame_system::Pallet::<Test>::assert_has_event(
+            Event::<Test>::CouncilUpdated {
+                dao_id: DAO_ID,
+                members: vec![ALICE],
+                emergency: true,
+            }
+            .into(),
+        );
+        assert_ok!(Pallet::<Test>::check_auth_for_emergency(DAO_ID, ALICE));
+        assert!(Pallet::<Test>::check_auth_for_emergency(DAO_ID, BOB).is_err());
+        assert_eq!(
+            daoent_dao::GuildMembers::<Test>::get(DAO_ID, 0).into_inner(),
+            vec![ALICE]
+        );
+    });
+}
+
+#[test]
+pub fn veto_should_work() {
+    new_test_run().execute_with(|| {
+        vote();
+        enable_emergency();
+        assert_eq!(
+            Pallet::<Test>::veto(RuntimeOrigin::signed(ALICE), DAO_ID, 0),
+            Err(Error::<Test>::VoteNotEnd.into())
+        );
+
+        let end = 10000 + GovConfigOf::<Test>::get(DAO_ID).voting_period;
+        frame_system::Pallet::<Test>::set_block_number(end);
+        assert_ok!(Pallet::<Test>::veto(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0
+        ));
+        assert_ok!(Pallet::<Test>::veto(RuntimeOrigin::signed(BOB), DAO_ID, 0));
+        assert_eq!(
+            ReferendumInfoOf::<Test>::get(DAO_ID, 0),
+            Some(ReferendumInfo::Finished {
+                approved: false,
+                end
+            })
+        );
+        assert!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        )
+        .is_err());
+
+        // the vetoed proposal is cooling off
+        let proposal = RuntimeCall::DAOGov(Call::set_min_vote_weight_for_every_call {
+            dao_id: DAO_ID,
+            call_id: 0,
+            min_vote_weight: 100u64,
+        });
+        assert_eq!(
+            Pallet::<Test>::create_propose(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                MemmberData::GLOBAL,
+                Box::new(proposal.clone()),
+                0u64
+            ),
+            Err(Error::<Test>::ProposalBlacklisted.into())
+        );
+        frame_system::Pallet::<Test>::set_block_number(end + VetoCoolOff::get());
+        assert_ok!(Pallet::<Test>::create_propose(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            MemmberData::GLOBAL,
+            Box::new(proposal),
+            0u64
+        ));
+    });
+}
+
+#[test]
+pub fn set_gov_config_should_work() {
+    new_test_run().execute_with(|| {
+        create_dao();
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        let patch = GovConfigPatch {
+            voting_period: Some(100),
+            vote_model: Some(1),
+            ..Default::default()
+        };
+        assert!(Pallet::<Test>::set_gov_config(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            patch.clone()
+        )
+        .is_err());
+        assert_ok!(Pallet::<Test>::set_gov_config(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            patch
+        ));
+        let config = GovConfigOf::<Test>::get(DAO_ID);
+        assert_eq!(config.voting_period, 100);
+        assert_eq!(config.vote_model, 1);
+        assert_eq!(config.max_public_props, 100);
+        assert_eq!(config.enactment_period, 900);
+
+        for (patch, err) in [
+            (
+                GovConfigPatch {
+                    voting_period: Some(0),
+                    ..Default::default()
+                },
+                Error::<Test>::PeriodTooShort,
+            ),
+            (
+                GovConfigPatch {
+                    enactment_period: Some(MaxGovPeriod::get() + 1),
+                    ..Default::default()
+                },
+                Error::<Test>::PeriodTooLong,
+            ),
+            (
+                GovConfigPatch {
+                    max_public_props: Some(0),
+                    ..Default::default()
+                },
+                Error::<Test>::InvalidMaxPublicProps,
+            ),
+            (
+                GovConfigPatch {
+                    vote_model: Some(2),
+                    ..Default::default()
+                },
+                Error::<Test>::InvalidVoteModel,
+            ),
+        ] {
+            assert_eq!(
+                Pallet::<Test>::set_gov_config(RuntimeOrigin::signed(root), DAO_ID, patch),
+                Err(err.into())
+            );
+        }
+        assert_eq!(GovConfigOf::<Test>::get(DAO_ID), config);
+    });
+}
+
+#[test]
+pub fn migrate_to_v1_should_work() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+        migrations::v1::VotingPeriod::<Test>::insert(DAO_ID, 50);
+        migrations::v1::MinimumDeposit::<Test>::insert(DAO_ID, 7);
+        migrations::v1::VoteModel::<Test>::insert(2u64, 1);
+
+        migrations::v1::migrate::<Test>();
+
+        let config = migrations::v1::GovConfigOf::<Test>::get(DAO_ID);
+        assert_eq!(config.voting_period, 50);
+        assert_eq!(config.minimum_deposit, 7);
+        assert_eq!(config.launch_period, 900);
+        assert_eq!(migrations::v1::GovConfigOf::<Test>::get(2u64).vote_model, 1);
+        assert!(!migrations::v1::VotingPeriod::<Test>::contains_key(DAO_ID));
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+pub fn migrate_to_v2_should_work() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+        migrations::v1::VotingPeriod::<Test>::insert(DAO_ID, 50);
+
+        migrations::v1::migrate::<Test>();
+        migrations::v2::migrate::<Test>();
+
+        let config = migrations::v2::GovConfigOf::<Test>::get(DAO_ID).unwrap();
+        assert_eq!(config.voting_period, 50);
+        assert_eq!(config.max_proposal_weight, MaxProposalWeight::get());
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+pub fn migrate_to_v3_should_work() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+        migrations::v1::VotingPeriod::<Test>::insert(DAO_ID, 50);
+
+        migrations::v1::migrate::<Test>();
+        migrations::v2::migrate::<Test>();
+        migrations::v3::migrate::<Test>();
+
+        let config = GovConfigOf::<Test>::get(DAO_ID);
+        assert_eq!(config.voting_period, 50);
+        assert_eq!(config.max_proposal_weight, MaxProposalWeight::get());
+        assert_eq!(config.nft_vote_weight, 1);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+    });
+}
+
+#[test]
+pub fn proposal_weight_should_be_bounded() {
+    new_test_run().execute_with(|| {
+        create_dao();
+        let proposal = RuntimeCall::DAOGov(Call::set_min_vote_weight_for_every_call {
+            dao_id: DAO_ID,
+            call_id: 0,
+            min_vote_weight: 100u64,
+        });
+        let proposal_weight = proposal.get_dispatch_info().weight;
+
+        GovConfigOf::<Test>::mutate(DAO_ID, |c| {
+            c.max_proposal_weight = proposal_weight - Weight::from_ref_time(1)
+        });
+        assert_eq!(
+            Pallet::<Test>::create_propose(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                MemmberData::GLOBAL,
+                Box::new(proposal.clone()),
+                0u64
+            ),
+            Err(Error::<Test>::ProposalTooHeavy.into())
+        );
+        GovConfigOf::<Test>::mutate(DAO_ID, |c| c.max_proposal_weight = proposal_weight);
+        assert_ok!(Pallet::<Test>::create_propose(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            MemmberData::GLOBAL,
+            Box::new(proposal),
+            0u64
+        ));
+        assert_eq!(
+            ProposalWeightOf::<Test>::get(DAO_ID, 0),
+            Some(proposal_weight)
+        );
+
+        frame_system::Pallet::<Test>::set_block_number(10000);
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        assert_eq!(ProposalWeightOf::<Test>::get(DAO_ID, 0), None);
+        assert_eq!(
+            ReferendumWeightOf::<Test>::get(DAO_ID, 0),
+            Some(proposal_weight)
+        );
+
+        frame_system::Pallet::<Test>::set_block_number(20000);
+        assert_eq!(
+            Pallet::<Test>::run_proposal(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                0u32,
+                proposal_weight - Weight::from_ref_time(1)
+            ),
+            Err(Error::<Test>::WrongProposalWeight.into())
+        );
+        let post =
+            Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, Weight::MAX)
+                .unwrap();
+        assert_eq!(
+            post.actual_weight,
+            Some(<() as weights::WeightInfo>::run_proposal().saturating_add(proposal_weight))
+        );
+        assert_eq!(ReferendumWeightOf::<Test>::get(DAO_ID, 0), None);
+    });
+}
+
+#[test]
+pub fn runtime_api_queries_should_work() {
+    new_test_run().execute_with(|| {
+        second();
+        let proposals = Pallet::<Test>::open_proposals(DAO_ID);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].proposer, ALICE);
+        assert_eq!(proposals[0].backers, 2);
+        assert_eq!(Pallet::<Test>::referendum_summary(DAO_ID, 0), None);
+
+        frame_system::Pallet::<Test>::set_block_number(10000);
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        assert!(Pallet::<Test>::open_proposals(DAO_ID).is_empty());
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+
+        frame_system::Pallet::<Test>::set_block_number(10100);
+        assert_eq!(
+            Pallet::<Test>::referendum_summary(DAO_ID, 0),
+            Some(ReferendumSummary::Ongoing {
+                end: 10900,
+                enactment: 11800,
+                tally: Tally { yes: 100, no: 0 },
+                min_vote_weight: 0,
+                would_pass: true,
+                blocks_to_end: 800,
+                blocks_to_enactment: 1700,
+                moments: None,
+            })
+        );
+
+        let summary = Pallet::<Test>::voter_summary(ALICE);
+        assert_eq!(summary.votes.len(), 1);
+        assert_eq!(summary.votes[0].vote_weight, 100);
+        assert!(summary.votes[0].unlockable);
+        assert_eq!(summary.reserved, 0);
+    });
+}
+
+#[test]
+pub fn dry_run_should_roll_back() {
+    new_test_run().execute_with(|| {
+        second();
+        frame_system::Pallet::<Test>::set_block_number(10000);
+
+        let res = Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Proposal(P_ID)).unwrap();
+        assert_eq!(res.result, Ok(()));
+        assert_eq!(
+            res.events,
+            vec![RuntimeEvent::DAOGov(Event::SetMinVoteWeight(DAO_ID, 0, 100)).encode()]
+        );
+        assert!(res.balance_changes.is_empty());
+        assert_eq!(MinVoteWeightOf::<Test>::get(DAO_ID, 0), 0);
+        assert!(Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Referendum(0)).is_none());
+
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        assert!(Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Proposal(P_ID)).is_none());
+        let res = Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Referendum(0)).unwrap();
+        assert_eq!(res.result, Ok(()));
+        assert_eq!(MinVoteWeightOf::<Test>::get(DAO_ID, 0), 0);
+    });
+}
+
+#[test]
+pub fn dry_run_should_report_every_dao_account() {
+    new_test_run().execute_with(|| {
+        create_dao();
+        frame_system::Pallet::<Test>::set_block_number(0);
+        let dao_account = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        let reward_pool = daoent_dao::Pallet::<Test>::dao_reward_pool(DAO_ID);
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            DAO_ID,
+            ALICE,
+            dao_account,
+            500
+        ));
+        assert_ok!(Pallet::<Test>::create_propose(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            MemmberData::GLOBAL,
+            Box::new(RuntimeCall::DAOGov(Call::fund_reward_pool {
+                dao_id: DAO_ID,
+                amount: 100,
+            })),
+            0u64
+        ));
+
+        let res = Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Proposal(P_ID)).unwrap();
+        assert_eq!(res.result, Ok(()));
+        assert_eq!(
+            res.balance_changes,
+            vec![
+                BalanceChange {
+                    account: dao_account,
+                    asset_id: DAO_ID,
+                    before: 500,
+                    after: 400,
+                },
+                BalanceChange {
+                    account: reward_pool,
+                    asset_id: DAO_ID,
+                    before: 0,
+                    after: 100,
+                },
+            ]
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &reward_pool),
+            0
+        );
+    });
+}
+
+#[test]
+pub fn dry_run_should_report_failed_proposal() {
+    new_test_run().execute_with(|| {
+        create_dao();
+        frame_system::Pallet::<Test>::set_block_number(0);
+        // the DAO account holds no DAO tokens
+        assert_ok!(Pallet::<Test>::create_propose(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            MemmberData::GLOBAL,
+            Box::new(RuntimeCall::DAOGov(Call::fund_reward_pool {
+                dao_id: DAO_ID,
+                amount: 100,
+            })),
+            0u64
+        ));
+
+        let res = Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Proposal(P_ID)).unwrap();
+        assert!(res.result.is_err());
+        assert!(res.balance_changes.is_empty());
+        assert!(Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Proposal(P_ID + 1)).is_none());
+        assert!(Pallet::<Test>::dry_run(DAO_ID + 1, DryRunTarget::Proposal(P_ID)).is_none());
+    });
+}
+
+#[test]
+pub fn dry_run_should_skip_finished_referendum() {
+    new_test_run().execute_with(|| {
+        run();
+        assert!(matches!(
+            ReferendumInfoOf::<Test>::get(DAO_ID, 0),
+            Some(ReferendumInfo::Finished { .. })
+        ));
+        assert!(Pallet::<Test>::dry_run(DAO_ID, DryRunTarget::Referendum(0)).is_none());
+    });
+}
+
+#[test]
+pub fn prune_finished_should_work() {
+    use frame_support::traits::Hooks;
+
+    new_test_run().execute_with(|| {
+        run();
+        assert_eq!(FinishedQueueRange::<Test>::get(), (0, 1));
+
+        Pallet::<Test>::on_initialize(20000 + ArchiveAge::get() - 1);
+        assert!(ReferendumInfoOf::<Test>::get(DAO_ID, 0).is_some());
+
+        Pallet::<Test>::on_initialize(20000 + ArchiveAge::get());
+        assert!(ReferendumInfoOf::<Test>::get(DAO_ID, 0).is_none());
+        assert!(FinishedQueue::<Test>::get(0).is_none());
+        assert_eq!(FinishedQueueRange::<Test>::get(), (1, 1));
+        frame_system::Pallet::<Test>::assert_has_event(
+            Event::<Test>::ReferendumArchived {
+                dao_id: DAO_ID,
+                index: 0,
+                approved: true,
+                end: 20000,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+pub fn reap_votes_should_work() {
+    use frame_support::traits::Hooks;
+
+    new_test_run().execute_with(|| {
+        run();
+        frame_system::Pallet::<Test>::set_block_number(10050);
+        assert_eq!(
+            Pallet::<Test>::reap_votes(RuntimeOrigin::signed(BOB), ALICE),
+            Err(Error::<Test>::NothingToReap.into())
+        );
+
+        // the voter can still unlock until the referendum is archived
+        frame_system::Pallet::<Test>::set_block_number(20000);
+        assert_eq!(
+            Pallet::<Test>::reap_votes(RuntimeOrigin::signed(BOB), ALICE),
+            Err(Error::<Test>::NothingToReap.into())
+        );
+
+        Pallet::<Test>::on_initialize(20000 + ArchiveAge::get());
+        assert!(ReferendumInfoOf::<Test>::get(DAO_ID, 0).is_none());
+        let dao_account = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        let treasury =
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &dao_account);
+        let before = <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &BOB);
+        assert_eq!(
+            ReapDepositOf::<Test>::get((DAO_ID, 0), ALICE),
+            Some(ReapReward::get())
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                0, &ALICE
+            ),
+            ReapReward::get()
+        );
+        assert_ok!(Pallet::<Test>::reap_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE
+        ));
+        assert!(VotesOf::<Test>::get(ALICE).is_empty());
+        assert!(ReapDepositOf::<Test>::get((DAO_ID, 0), ALICE).is_none());
+        // the reward is the deposit of the voter, the DAO account pays nothing
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &BOB),
+            before + ReapReward::get()
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                0, &ALICE
+            ),
+            0
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &dao_account),
+            treasury
+        );
+        assert!(Pallet::<Test>::reap_votes(RuntimeOrigin::signed(BOB), ALICE).is_err());
+    });
+}
+
+#[test]
+pub fn unlock_before_archive_should_keep_reap_deposit() {
+    new_test_run().execute_with(|| {
+        run();
+        frame_system::Pallet::<Test>::set_block_number(20000);
+        let before = <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &ALICE);
+
+        assert_ok!(Pallet::<Test>::unlock(RuntimeOrigin::signed(ALICE), DAO_ID));
+        assert!(VotesOf::<Test>::get(ALICE).is_empty());
+        assert!(ReapDepositOf::<Test>::get((DAO_ID, 0), ALICE).is_none());
+        // the deposit goes back to the voter
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &ALICE),
+            before + ReapReward::get()
+        );
+        assert_eq!(
+            Pallet::<Test>::reap_votes(RuntimeOrigin::signed(BOB), ALICE),
+            Err(Error::<Test>::NothingToReap.into())
+        );
+    });
+}
+
+pub fn submit_optimistic() -> RuntimeCall {
+    frame_system::Pallet::<Test>::set_block_number(1);
+    create_dao();
+    let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+    assert_ok!(Pallet::<Test>::set_optimistic_rule(
+        RuntimeOrigin::signed(root),
+        DAO_ID,
+        408,
+        Some(OptimisticRule {
+            member_data: MemmberData::GLOBAL,
+            bond: 10,
+            challenge_period: 5,
+            objection_threshold: 50,
+        })
+    ));
+
+    let proposal = RuntimeCall::DAOGov(Call::set_min_vote_weight_for_every_call {
+        dao_id: DAO_ID,
+        call_id: 0,
+        min_vote_weight: 100u64,
+    });
+    assert!(Pallet::<Test>::submit_optimistic(
+        RuntimeOrigin::signed(BOB),
+        DAO_ID,
+        Box::new(proposal.clone())
+    )
+    .is_err());
+    assert_ok!(Pallet::<Test>::submit_optimistic(
+        RuntimeOrigin::signed(ALICE),
+        DAO_ID,
+        Box::new(proposal.clone())
+    ));
+    assert_eq!(
+        <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+            DAO_ID, &ALICE
+        ),
+        10
+    );
+    proposal
+}
+
+#[test]
+pub fn optimistic_execute_should_work() {
+    new_test_run().execute_with(|| {
+        submit_optimistic();
+        daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            DAO_ID, ALICE, BOB, 100
+        ));
+        // an objection below the threshold keeps its weight reserved
+        assert_ok!(Pallet::<Test>::object_optimistic(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            20
+        ));
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &BOB
+            ),
+            30
+        );
+        assert!(
+            Pallet::<Test>::object_optimistic(RuntimeOrigin::signed(BOB), DAO_ID, 0, 20).is_err()
+        );
+
+        frame_system::Pallet::<Test>::set_block_number(5);
+        assert_eq!(
+            Pallet::<Test>::execute_optimistic(RuntimeOrigin::signed(BOB), DAO_ID, 0, Weight::MAX),
+            Err(Error::<Test>::ChallengeWindowOpen.into())
+        );
+        frame_system::Pallet::<Test>::set_block_number(6);
+        assert_eq!(
+            Pallet::<Test>::object_optimistic(RuntimeOrigin::signed(ALICE), DAO_ID, 0, 0),
+            Err(Error::<Test>::ChallengeWindowClosed.into())
+        );
+        assert_eq!(
+            Pallet::<Test>::execute_optimistic(
+                RuntimeOrigin::signed(BOB),
+                DAO_ID,
+                0,
+                Weight::zero()
+            ),
+            Err(Error::<Test>::WrongProposalWeight.into())
+        );
+        assert_ok!(Pallet::<Test>::execute_optimistic(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            Weight::MAX
+        ));
+
+        assert_eq!(MinVoteWeightOf::<Test>::get(DAO_ID, 0), 100);
+        assert!(OptimisticProposals::<Test>::get(DAO_ID, 0).is_none());
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &ALICE
+            ),
+            0
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &BOB
+            ),
+            0
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &BOB),
+            100
+        );
+    });
+}
+
+#[test]
+pub fn optimistic_escalation_should_slash_challenger() {
+    new_test_run().execute_with(|| {
+        submit_optimistic();
+        daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            DAO_ID, ALICE, BOB, 100
+        ));
+
+        assert_ok!(Pallet::<Test>::object_optimistic(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            50
+        ));
+        assert!(OptimisticProposals::<Test>::get(DAO_ID, 0).is_none());
+        assert!(EscalatedOf::<Test>::get(DAO_ID, 0).is_some());
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &BOB
+            ),
+            60
+        );
+
+        // the escalated call goes through a normal referendum
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        frame_system::Pallet::<Test>::set_block_number(2000);
+        assert_ok!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        ));
+
+        let dao_account = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &BOB
+            ),
+            0
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &BOB),
+            90
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &dao_account),
+            10
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &ALICE
+            ),
+            0
+        );
+        assert!(EscalatedOf::<Test>::get(DAO_ID, 0).is_none());
+    });
+}
+
+#[test]
+pub fn escalation_below_min_vote_weight_should_slash_proposer() {
+    new_test_run().execute_with(|| {
+        submit_optimistic();
+        daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            DAO_ID, ALICE, BOB, 100
+        ));
+        assert_ok!(Pallet::<Test>::object_optimistic(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0,
+            50
+        ));
+        MinVoteWeightOf::<Test>::insert(DAO_ID, 408, 10000000000);
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+
+        // too little weight voted, the referendum ends rejected instead of staying open
+        frame_system::Pallet::<Test>::set_block_number(2000);
+        assert_ok!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        ));
+        frame_system::Pallet::<Test>::assert_has_event(RuntimeEvent::DAOGov(
+            Event::EnactProposal {
+                dao_id: DAO_ID,
+                index: 0,
+                result: Err(Error::<Test>::VoteWeightTooLow.into()),
+            },
+        ));
+        assert_eq!(
+            ReferendumInfoOf::<Test>::get(DAO_ID, 0),
+            Some(ReferendumInfo::Finished {
+                approved: false,
+                end: 2000
+            })
+        );
+        assert!(EscalatedOf::<Test>::get(DAO_ID, 0).is_none());
+        assert_eq!(MinVoteWeightOf::<Test>::get(DAO_ID, 0), 0);
+
+        let dao_account = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiReservableCurrency<u64>>::reserved_balance(
+                DAO_ID, &BOB
+            ),
+            0
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &BOB),
+            100
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &dao_account),
+            10
+        );
+    });
+}
+
+pub fn start_commit_reveal(slash_unrevealed: bool) -> <Test as frame_system::Config>::Hash {
+    second();
+    let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+    assert_ok!(Pallet::<Test>::set_commit_reveal_rule(
+        RuntimeOrigin::signed(root),
+        DAO_ID,
+        408,
+        Some(CommitRevealRule {
+            reveal_period: 100,
+            slash_unrevealed,
+        })
+    ));
+    frame_system::Pallet::<Test>::set_block_number(10000);
+    assert_ok!(Pallet::<Test>::start_referendum(
+        RuntimeOrigin::signed(ALICE),
+        DAO_ID,
+        P_ID
+    ));
+    assert!(CommitRevealOf::<Test>::get(DAO_ID, 0).is_some());
+
+    assert_eq!(
+        Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ),
+        Err(Error::<Test>::CommitRevealRequired.into())
+    );
+    let commitment = BlakeTwo256::hash_of(&(ALICE, 0u32, Opinion::YES, b"salt".to_vec()));
+    assert_ok!(Pallet::<Test>::commit_vote(
+        RuntimeOrigin::signed(ALICE),
+        DAO_ID,
+        0u32,
+        Vote(100u64),
+        commitment,
+    ));
+    assert_eq!(
+        Pallet::<Test>::commit_vote(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            commitment,
+        ),
+        Err(Error::<Test>::VoteRedundancy.into())
+    );
+    commitment
+}
+
+#[test]
+pub fn commit_reveal_vote_should_work() {
+    new_test_run().execute_with(|| {
+        let commitment = start_commit_reveal(false);
+        daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+        assert_ok!(Pallet::<Test>::commit_vote(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            commitment,
+        ));
+
+        assert_eq!(
+            Pallet::<Test>::reveal_vote(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                0u32,
+                Opinion::YES,
+                b"salt".to_vec(),
+            ),
+            Err(Error::<Test>::NotRevealTime.into())
+        );
+        let end = 10000 + GovConfigOf::<Test>::get(DAO_ID).voting_period;
+        frame_system::Pallet::<Test>::set_block_number(end);
+        assert_eq!(
+            Pallet::<Test>::reveal_vote(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                0u32,
+                Opinion::NO,
+                b"salt".to_vec(),
+            ),
+            Err(Error::<Test>::BadReveal.into())
+        );
+        assert_ok!(Pallet::<Test>::reveal_vote(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Opinion::YES,
+            b"salt".to_vec(),
+        ));
+
+        assert!(Commitments::<Test>::get((DAO_ID, 0u32), ALICE).is_none());
+        assert_eq!(VotesOf::<Test>::get(ALICE).len(), 1);
+        // a copied commitment can not be revealed by another account
+        assert_eq!(
+            Pallet::<Test>::reveal_vote(
+                RuntimeOrigin::signed(BOB),
+                DAO_ID,
+                0u32,
+                Opinion::YES,
+                b"salt".to_vec(),
+            ),
+            Err(Error::<Test>::BadReveal.into())
+        );
+        match ReferendumInfoOf::<Test>::get(DAO_ID, 0).unwrap() {
+            ReferendumInfo::Ongoing(x) => assert_eq!(x.tally.yes, 100),
+            _ => panic!("referendum should be ongoing"),
+        }
+    });
+}
+
+#[test]
+pub fn veto_should_wait_for_reveal() {
+    new_test_run().execute_with(|| {
+        start_commit_reveal(false);
+        enable_emergency();
+
+        let end = 10000 + GovConfigOf::<Test>::get(DAO_ID).voting_period;
+        frame_system::Pallet::<Test>::set_block_number(end);
+        assert_ok!(Pallet::<Test>::reveal_vote(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Opinion::YES,
+            b"salt".to_vec(),
+        ));
+        assert_eq!(
+            Pallet::<Test>::veto(RuntimeOrigin::signed(ALICE), DAO_ID, 0),
+            Err(Error::<Test>::RevealNotEnd.into())
+        );
+        frame_system::Pallet::<Test>::set_block_number(end + 100);
+        assert_ok!(Pallet::<Test>::veto(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0
+        ));
+    });
+}
+
+#[test]
+pub fn clear_unrevealed_should_slash() {
+    new_test_run().execute_with(|| {
+        start_commit_reveal(true);
+
+        let end = 10000 + GovConfigOf::<Test>::get(DAO_ID).voting_period;
+        frame_system::Pallet::<Test>::set_block_number(end + 99);
+        assert_eq!(
+            Pallet::<Test>::clear_unrevealed(RuntimeOrigin::signed(BOB), DAO_ID, 0u32, ALICE),
+            Err(Error::<Test>::RevealNotEnd.into())
+        );
+        frame_system::Pallet::<Test>::set_block_number(end + 100);
+        assert_eq!(
+            Pallet::<Test>::reveal_vote(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                0u32,
+                Opinion::YES,
+                b"salt".to_vec(),
+            ),
+            Err(Error::<Test>::NotRevealTime.into())
+        );
+        assert_ok!(Pallet::<Test>::clear_unrevealed(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32,
+            ALICE
+        ));
+
+        assert!(Commitments::<Test>::get((DAO_ID, 0u32), ALICE).is_none());
+        assert!(VotesOf::<Test>::get(ALICE).is_empty());
+        match ReferendumInfoOf::<Test>::get(DAO_ID, 0).unwrap() {
+            ReferendumInfo::Ongoing(x) => assert_eq!(x.tally.yes, 0),
+            _ => panic!("referendum should be ongoing"),
+        }
+        System::assert_last_event(RuntimeEvent::DAOGov(Event::UnrevealedCleared {
+            dao_id: DAO_ID,
+            index: 0,
+            who: ALICE,
+            slashed: true,
+        }));
+    });
+}
+
+pub fn rage_quit_vote(bob: Opinion) -> u64 {
+    start_referendum();
+    daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+    assert_ok!(Pallet::<Test>::vote_for_referendum(
+        RuntimeOrigin::signed(ALICE),
+        DAO_ID,
+        0u32,
+        Vote(100u64),
+        Opinion::NO,
+    ));
+    assert_ok!(Pallet::<Test>::vote_for_referendum(
+        RuntimeOrigin::signed(BOB),
+        DAO_ID,
+        0u32,
+        Vote(100u64),
+        bob,
+    ));
+    assert_ok!(Pallet::<Test>::vote_for_referendum(
+        RuntimeOrigin::signed(103),
+        DAO_ID,
+        0u32,
+        Vote(100u64),
+        Opinion::YES,
+    ));
+    assert_eq!(
+        Pallet::<Test>::rage_quit(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, 10),
+        Err(Error::<Test>::NotRageQuitTime.into())
+    );
+
+    let end = 10000 + GovConfigOf::<Test>::get(DAO_ID).voting_period;
+    frame_system::Pallet::<Test>::set_block_number(end);
+    end
+}
+
+#[test]
+pub fn rage_quit_should_work() {
+    new_test_run().execute_with(|| {
+        let end = rage_quit_vote(Opinion::YES);
+        assert_eq!(
+            Pallet::<Test>::rage_quit(RuntimeOrigin::signed(BOB), DAO_ID, 0u32, 10),
+            Err(Error::<Test>::NotNoVoter.into())
+        );
+
+        let pool_b = daoent_dao::Pallet::<Test>::dao_asset(DAO_ID);
+        let native_before =
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &ALICE);
+        assert_ok!(Pallet::<Test>::rage_quit(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            60
+        ));
+
+        // pool B holds 10000 native against an issuance of 10099 DAO tokens, rounded down
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &ALICE),
+            9940
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &ALICE),
+            native_before + 59
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &pool_b),
+            10000 - 59
+        );
+        assert_eq!(RageQuitOf::<Test>::get((DAO_ID, 0), ALICE), 60);
+
+        frame_system::Pallet::<Test>::set_block_number(
+            end + GovConfigOf::<Test>::get(DAO_ID).enactment_period,
+        );
+        assert_eq!(
+            Pallet::<Test>::rage_quit(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, 10),
+            Err(Error::<Test>::NotRageQuitTime.into())
+        );
+    });
+}
+
+#[test]
+pub fn rage_quit_should_be_capped_by_vote() {
+    new_test_run().execute_with(|| {
+        rage_quit_vote(Opinion::YES);
+        assert_eq!(
+            Pallet::<Test>::rage_quit(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, 101),
+            Err(Error::<Test>::RageQuitExceedsVote.into())
+        );
+        assert_ok!(Pallet::<Test>::rage_quit(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            60
+        ));
+        // the cap counts what was already burned
+        assert_eq!(
+            Pallet::<Test>::rage_quit(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, 41),
+            Err(Error::<Test>::RageQuitExceedsVote.into())
+        );
+        assert_ok!(Pallet::<Test>::rage_quit(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            40
+        ));
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &ALICE),
+            9900
+        );
+    });
+}
+
+#[test]
+pub fn rage_quit_should_be_capped_by_pledged_tokens() {
+    new_test_run().execute_with(|| {
+        start_referendum();
+        daoent_dao::Pallet::<Test>::try_add_member(DAO_ID, BOB).unwrap();
+        // the vote weighs 100, but only 50 DAO tokens are pledged
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(50u64),
+            Opinion::NO,
+        ));
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(103),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        frame_system::Pallet::<Test>::set_block_number(
+            10000 + GovConfigOf::<Test>::get(DAO_ID).voting_period,
+        );
+
+        assert_eq!(
+            Pallet::<Test>::rage_quit(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, 51),
+            Err(Error::<Test>::RageQuitExceedsVote.into())
+        );
+        assert_ok!(Pallet::<Test>::rage_quit(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            50
+        ));
+    });
+}
+
+#[test]
+pub fn rage_quit_should_price_on_total_issuance() {
+    new_test_run().execute_with(|| {
+        rage_quit_vote(Opinion::YES);
+        // pool B holds most of the supply, which must not inflate the payout
+        let pool_b = daoent_dao::Pallet::<Test>::dao_asset(DAO_ID);
+        assert_ok!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::deposit(DAO_ID, &pool_b, 89_901)
+        );
+        let native_before =
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &ALICE);
+
+        assert_ok!(Pallet::<Test>::rage_quit(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            100
+        ));
+        // 100 of 100000 DAO tokens against 10000 native
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &ALICE),
+            native_before + 10
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(0, &pool_b),
+            10000 - 10
+        );
+    });
+}
+
+#[test]
+pub fn rage_quit_should_fail_for_rejected_referendum() {
+    new_test_run().execute_with(|| {
+        rage_quit_vote(Opinion::NO);
+        assert_eq!(
+            Pallet::<Test>::rage_quit(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, 10),
+            Err(Error::<Test>::VoteEndButNotPass.into())
+        );
+        assert_eq!(
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &ALICE),
+            10000
+        );
+    });
+}
+
+#[test]
+pub fn time_based_periods_should_work() {
+    new_test_run().execute_with(|| {
+        second();
+        assert_eq!(
+            Pallet::<Test>::set_time_periods(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                Some(TimePeriods {
+                    launch_period: 60_000,
+                    voting_period: 6_000,
+                    reserve_period: 60_000,
+                    enactment_period: 12_000,
+                })
+            ),
+            Err(Error::<Test>::PeriodTooShort.into())
+        );
+        let periods = TimePeriods {
+            launch_period: 60_000,
+            voting_period: 120_000,
+            reserve_period: 60_000,
+            enactment_period: 12_000,
+        };
+        assert_ok!(Pallet::<Test>::set_time_periods(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            Some(periods.clone())
+        ));
+        assert_eq!(Pallet::<Test>::time_periods(DAO_ID), Some(periods));
+
+        pallet_timestamp::Pallet::<Test>::set_timestamp(1_000_000);
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        assert_eq!(
+            Pallet::<Test>::referendum_moments(DAO_ID, 0),
+            Some(ReferendumMoments {
+                end: 1_120_000,
+                delay: 12_000
+            })
+        );
+        assert_eq!(Pallet::<Test>::next_launch_at(DAO_ID), 1_060_000);
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+
+        // 区块推进但时间未到
+        frame_system::Pallet::<Test>::set_block_number(1_000_000);
+        assert_eq!(
+            Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, Weight::MAX),
+            Err(Error::<Test>::VoteNotEnd.into())
+        );
+
+        pallet_timestamp::Pallet::<Test>::set_timestamp(1_120_000);
+        assert_eq!(
+            Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), DAO_ID, 0u32, Weight::MAX),
+            Err(Error::<Test>::InDelayTime.into())
+        );
+        pallet_timestamp::Pallet::<Test>::set_timestamp(1_132_000);
+        assert_ok!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        ));
+        assert_eq!(Pallet::<Test>::referendum_moments(DAO_ID, 0), None);
+    });
+}
+
+#[test]
+pub fn bicameral_referendum_should_work() {
+    new_test_run().execute_with(|| {
+        second();
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        assert_ok!(Pallet::<Test>::set_bicameral_rule(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            408,
+            Some(BicameralRule::BothHouses)
+        ));
+        frame_system::Pallet::<Test>::set_block_number(10000);
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        assert_eq!(
+            BicameralOf::<Test>::get(DAO_ID, 0),
+            Some(BicameralRule::BothHouses)
+        );
+
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::NO,
+        ));
+        // 平票不通过
+        assert_eq!(
+            MemberTallyOf::<Test>::get(DAO_ID, 0),
+            Tally { yes: 1, no: 1 }
+        );
+        assert!(matches!(
+            Pallet::<Test>::referendum_summary(DAO_ID, 0),
+            Some(ReferendumSummary::Ongoing {
+                would_pass: false,
+                ..
+            })
+        ));
+
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(103),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        assert_ok!(Pallet::<Test>::cancel_vote(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32
+        ));
+        assert_eq!(
+            MemberTallyOf::<Test>::get(DAO_ID, 0),
+            Tally { yes: 2, no: 0 }
+        );
+
+        frame_system::Pallet::<Test>::set_block_number(20000);
+        assert_ok!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        ));
+        frame_system::Pallet::<Test>::assert_has_event(RuntimeEvent::DAOGov(
+            Event::BicameralTallied {
+                dao_id: DAO_ID,
+                index: 0,
+                token_tally: Tally { yes: 200, no: 0 },
+                member_tally: Tally { yes: 2, no: 0 },
+                approved: true,
+            },
+        ));
+        assert!(!MemberTallyOf::<Test>::contains_key(DAO_ID, 0));
+        assert_eq!(BicameralOf::<Test>::get(DAO_ID, 0), None);
+    });
+}
+
+#[test]
+pub fn tie_should_not_pass() {
+    new_test_run().execute_with(|| {
+        start_referendum();
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::NO,
+        ));
+        // 代币院平票
+        assert!(matches!(
+            Pallet::<Test>::referendum_summary(DAO_ID, 0),
+            Some(ReferendumSummary::Ongoing {
+                would_pass: false,
+                ..
+            })
+        ));
+        assert_ok!(Pallet::<Test>::vote_for_referendum(
+            RuntimeOrigin::signed(103),
+            DAO_ID,
+            0u32,
+            Vote(100u64),
+            Opinion::YES,
+        ));
+        assert!(matches!(
+            Pallet::<Test>::referendum_summary(DAO_ID, 0),
+            Some(ReferendumSummary::Ongoing {
+                would_pass: true,
+                ..
+            })
+        ));
+
+        // 成员院平票既不通过也不否决
+        let tie = Tally { yes: 1, no: 1 };
+        assert!(!BicameralRule::BothHouses.member_house_passes(&tie));
+        assert!(BicameralRule::MemberVeto.member_house_passes(&tie));
+        assert!(!BicameralRule::MemberVeto.member_house_passes(&Tally { yes: 1, no: 2 }));
+        assert!(BicameralRule::BothHouses.member_house_passes(&Tally { yes: 2, no: 1 }));
+    });
+}
+
+#[test]
+pub fn vote_rewards_should_work() {
+    new_test_run().execute_with(|| {
+        second();
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        let pool = daoent_dao::Pallet::<Test>::dao_reward_pool(DAO_ID);
+        let balance = |who: u64| {
+            <daoent_assets::Pallet<Test> as MultiCurrency<u64>>::free_balance(DAO_ID, &who)
+        };
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            DAO_ID, ALICE, root, 1000
+        ));
+        assert_ok!(Pallet::<Test>::set_vote_reward_rule(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            Some(VoteRewardRule {
+                rate: Perbill::from_percent(50),
+                cap: 60,
+            })
+        ));
+        assert_ok!(Pallet::<Test>::fund_reward_pool(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            1000
+        ));
+        assert_eq!(balance(pool), 1000);
+
+        frame_system::Pallet::<Test>::set_block_number(10000);
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        for who in [ALICE, BOB] {
+            assert_ok!(Pallet::<Test>::vote_for_referendum(
+                RuntimeOrigin::signed(who),
+                DAO_ID,
+                0u32,
+                Vote(100u64),
+                Opinion::YES,
+            ));
+        }
+        frame_system::Pallet::<Test>::set_block_number(20000);
+        assert_ok!(Pallet::<Test>::run_proposal(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0u32,
+            Weight::MAX
+        ));
+        // 200 * 50% 超过上限，按上限预留
+        assert_eq!(
+            Pallet::<Test>::vote_reward_pot(DAO_ID, 0),
+            Some(VoteRewardPot {
+                total_weight: 200,
+                budget: 60,
+                remaining: 60,
+            })
+        );
+        assert_eq!(Pallet::<Test>::committed_rewards(DAO_ID), 60);
+
+        let alice_before = balance(ALICE);
+        assert_ok!(Pallet::<Test>::claim_vote_rewards(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0
+        ));
+        assert_eq!(balance(ALICE), alice_before + 30);
+        assert_eq!(
+            Pallet::<Test>::claim_vote_rewards(RuntimeOrigin::signed(ALICE), DAO_ID, 0),
+            Err(Error::<Test>::NoVoteRewards.into())
+        );
+
+        let bob_before = balance(BOB);
+        assert_ok!(Pallet::<Test>::unlock(RuntimeOrigin::signed(BOB), DAO_ID));
+        assert_eq!(balance(BOB), bob_before + 30);
+        assert_ok!(Pallet::<Test>::unlock(RuntimeOrigin::signed(ALICE), DAO_ID));
+        assert_eq!(balance(ALICE), alice_before + 30);
+        assert!(!VoteRewardClaimed::<Test>::contains_key((DAO_ID, 0), ALICE));
+
+        assert_eq!(Pallet::<Test>::committed_rewards(DAO_ID), 0);
+        assert_eq!(balance(pool), 940);
+    });
+}
+
+#[test]
+pub fn claim_vote_rewards_should_be_paged() {
+    new_test_run().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_dao();
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        assert_ok!(daoent_assets::Pallet::<Test>::try_transfer(
+            DAO_ID, ALICE, root, 1000
+        ));
+        assert_ok!(Pallet::<Test>::set_vote_reward_rule(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            Some(VoteRewardRule {
+                rate: Perbill::from_percent(50),
+                cap: 60,
+            })
+        ));
+        assert_ok!(Pallet::<Test>::fund_reward_pool(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            1000
+        ));
+
+        let proposal = RuntimeCall::DAOGov(Call::set_min_vote_weight_for_every_call {
+            dao_id: DAO_ID,
+            call_id: 0,
+            min_vote_weight: 0u64,
+        });
+        for index in 0..12u32 {
+            Pallet::<Test>::inject_referendum(
+                DAO_ID,
+                proposal.clone(),
+                Weight::zero(),
+                MemmberData::GLOBAL,
+            );
+            assert_ok!(Pallet::<Test>::vote_for_referendum(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                index,
+                Vote(100u64),
+                Opinion::YES,
+            ));
+        }
+        frame_system::Pallet::<Test>::set_block_number(20000);
+        for index in 0..12u32 {
+            assert_ok!(Pallet::<Test>::run_proposal(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                index,
+                Weight::MAX
+            ));
+        }
+
+        // MaxReapVotes is 10
+        assert_ok!(Pallet::<Test>::claim_vote_rewards(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            0
+        ));
+        System::assert_last_event(RuntimeEvent::DAOGov(Event::VoteRewardsClaimed {
+            dao_id: DAO_ID,
+            who: ALICE,
+            count: 10,
+            total: 500,
+            next: Some(10),
+        }));
+        assert_ok!(Pallet::<Test>::claim_vote_rewards(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            10
+        ));
+        System::assert_last_event(RuntimeEvent::DAOGov(Event::VoteRewardsClaimed {
+            dao_id: DAO_ID,
+            who: ALICE,
+            count: 2,
+            total: 100,
+            next: None,
+        }));
+        assert_eq!(
+            Pallet::<Test>::claim_vote_rewards(RuntimeOrigin::signed(ALICE), DAO_ID, 10),
+            Err(Error::<Test>::NoVoteRewards.into())
+        );
+        assert_eq!(Pallet::<Test>::committed_rewards(DAO_ID), 0);
+    });
+}
+
+#[test]
+pub fn votes_should_be_sorted_by_dao_and_referendum() {
+    new_test_run().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_dao();
+        let proposal = RuntimeCall::DAOGov(Call::set_min_vote_weight_for_every_call {
+            dao_id: DAO_ID,
+            call_id: 0,
+            min_vote_weight: 0u64,
+        });
+        for _ in 0..3 {
+            Pallet::<Test>::inject_referendum(
+                DAO_ID,
+                proposal.clone(),
+                Weight::zero(),
+                MemmberData::GLOBAL,
+            );
+        }
+
+        for index in [2u32, 0, 1] {
+            assert_ok!(Pallet::<Test>::vote_for_referendum(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                index,
+                Vote(100u64),
+                Opinion::YES,
+            ));
+        }
+        for index in [2u32, 0, 1] {
+            assert_eq!(
+                Pallet::<Test>::vote_for_referendum(
+                    RuntimeOrigin::signed(ALICE),
+                    DAO_ID,
+                    index,
+                    Vote(100u64),
+                    Opinion::NO,
+                ),
+                Err(Error::<Test>::VoteRedundancy.into())
+            );
+        }
+        assert_eq!(
+            VotesOf::<Test>::get(ALICE)
+                .iter()
+                .map(|v| v.referendum_index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        match ReferendumInfoOf::<Test>::get(DAO_ID, 1).unwrap() {
+            ReferendumInfo::Ongoing(x) => {
+                assert_eq!(x.tally.yes, 100);
+                assert_eq!(x.tally.no, 0);
+            }
+            _ => panic!("referendum should be ongoing"),
+        }
+    });
+}
+
+#[test]
+pub fn migrate_to_v4_should_sort_votes() {
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_run().execute_with(|| {
+        StorageVersion::new(3).put::<Pallet<Test>>();
+        let vote = |referendum_index: u32| VoteInfo {
+            dao_id: DAO_ID,
+            pledge: Vote(100u64),
+            opinion: Opinion::YES,
+            vote_weight: 100u64,
+            unlock_block: 100u64,
+            referendum_index,
+        };
+        VotesOf::<Test>::insert(ALICE, vec![vote(2), vote(0), vote(1)]);
+
+        migrations::v4::migrate::<Test>();
+
+        assert_eq!(VotesOf::<Test>::get(ALICE), vec![vote(0), vote(1), vote(2)]);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 4);
+    });
+}
+
+#[test]
+pub fn proposer_limits_should_work() {
+    new_test_run().execute_with(|| {
+        create_dao();
+        let root = daoent_dao::Pallet::<Test>::dao_account(DAO_ID);
+        assert_ok!(Pallet::<Test>::set_proposer_limits(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            Some(ProposerLimits {
+                max_open_proposals: 1,
+                cooldown: 100,
+                discount_per_point: Perbill::from_percent(10),
+                max_discount: Perbill::from_percent(50),
+            })
+        ));
+        let proposal = || {
+            Box::new(RuntimeCall::DAOGov(
+                Call::set_min_vote_weight_for_every_call {
+                    dao_id: DAO_ID,
+                    call_id: 0,
+                    min_vote_weight: 100u64,
+                },
+            ))
+        };
+        let create = || {
+            Pallet::<Test>::create_propose(
+                RuntimeOrigin::signed(ALICE),
+                DAO_ID,
+                MemmberData::GLOBAL,
+                proposal(),
+                0u64,
+            )
+        };
+        frame_system::Pallet::<Test>::set_block_number(1);
+        assert_ok!(create());
+        assert_eq!(Pallet::<Test>::open_proposals_of(DAO_ID, ALICE), 1);
+        assert_eq!(create(), Err(Error::<Test>::ProposalCooldown.into()));
+        frame_system::Pallet::<Test>::set_block_number(101);
+        assert_eq!(create(), Err(Error::<Test>::TooManyOpenProposals.into()));
+
+        // 附议同样受限
+        assert_ok!(Pallet::<Test>::recreate(
+            RuntimeOrigin::signed(BOB),
+            DAO_ID,
+            0u32
+        ));
+        assert_eq!(
+            Pallet::<Test>::recreate(RuntimeOrigin::signed(BOB), DAO_ID, 0u32),
+            Err(Error::<Test>::ProposalCooldown.into())
+        );
+
+        // 提案进入公投后释放名额
+        frame_system::Pallet::<Test>::set_block_number(10000);
+        assert_ok!(Pallet::<Test>::start_referendum(
+            RuntimeOrigin::signed(ALICE),
+            DAO_ID,
+            P_ID
+        ));
+        assert_eq!(Pallet::<Test>::open_proposals_of(DAO_ID, ALICE), 0);
+        assert_eq!(Pallet::<Test>::open_proposals_of(DAO_ID, BOB), 0);
+        assert_ok!(create());
+
+        // 贡献点降低最小押金，最多打五折
+        GovConfigOf::<Test>::mutate(DAO_ID, |c| c.minimum_deposit = 1000);
+        assert_eq!(Pallet::<Test>::minimum_deposit_of(DAO_ID, &BOB), 1000);
+        daoent_dao::MemberPoint::<Test>::insert(DAO_ID, BOB, 3);
+        assert_eq!(Pallet::<Test>::minimum_deposit_of(DAO_ID, &BOB), 700);
+        daoent_dao::MemberPoint::<Test>::insert(DAO_ID, BOB, 30);
+        assert_eq!(Pallet::<Test>::minimum_deposit_of(DAO_ID, &BOB), 500);
+
+        assert_ok!(Pallet::<Test>::set_proposer_limits(
+            RuntimeOrigin::signed(root),
+            DAO_ID,
+            None
+        ));
+        assert_eq!(Pallet::<Test>::minimum_deposit_of(DAO_ID, &BOB), 1000);
+    });
+}
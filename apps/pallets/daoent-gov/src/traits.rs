@@ -1,13 +1,37 @@
 use super::*;
 
 pub trait PledgeTrait<VoteWeight, AccountId, DaoId, BlockNumber, DispatchError> {
+    /// Pledge for a vote in `referendum_index`, returning the vote weight and how long the
+    /// pledge stays locked.
     fn try_vote(
         &self,
         who: &AccountId,
         dao_id: &DaoId,
+        referendum_index: ReferendumIndex,
         vote_model: u8,
     ) -> result::Result<(VoteWeight, BlockNumber), DispatchError>;
-    fn vote_end_do(&self, who: &AccountId, dao_id: &DaoId) -> result::Result<(), DispatchError>;
+
+    /// Release the pledge taken by `try_vote` for the same referendum.
+    fn vote_end_do(
+        &self,
+        who: &AccountId,
+        dao_id: &DaoId,
+        referendum_index: ReferendumIndex,
+    ) -> result::Result<(), DispatchError>;
+
+    /// Hand the pledge taken by `try_vote` over to `beneficiary` instead of releasing it.
+    fn slash_to(
+        &self,
+        who: &AccountId,
+        dao_id: &DaoId,
+        referendum_index: ReferendumIndex,
+        beneficiary: &AccountId,
+    ) -> result::Result<(), DispatchError>;
+
+    /// The DAO tokens the pledge holds, `None` when it pledges anything else.
+    fn dao_tokens(&self) -> Option<VoteWeight> {
+        None
+    }
 }
 
 pub trait ConvertInto<A> {
@@ -15,12 +15,27 @@ pub trait WeightInfo {
     fn run_proposal() -> Weight;
     fn unlock() -> Weight;
     fn set_min_vote_weight_for_every_call() -> Weight;
-    fn set_max_public_props() -> Weight;
-    fn set_launch_period() -> Weight;
-    fn set_minimum_deposit() -> Weight;
-    fn set_voting_period() -> Weight;
-    fn set_rerserve_period() -> Weight;
-    fn set_runment_period() -> Weight;
+    fn set_emergency_guild() -> Weight;
+    fn fast_track() -> Weight;
+    fn veto() -> Weight;
+    fn cancel_fast_track() -> Weight;
+    fn set_gov_config() -> Weight;
+    fn reap_votes() -> Weight;
+    fn set_optimistic_rule() -> Weight;
+    fn submit_optimistic() -> Weight;
+    fn object_optimistic() -> Weight;
+    fn execute_optimistic() -> Weight;
+    fn set_commit_reveal_rule() -> Weight;
+    fn commit_vote() -> Weight;
+    fn reveal_vote() -> Weight;
+    fn clear_unrevealed() -> Weight;
+    fn rage_quit() -> Weight;
+    fn set_time_periods() -> Weight;
+    fn set_bicameral_rule() -> Weight;
+    fn set_vote_reward_rule() -> Weight;
+    fn fund_reward_pool() -> Weight;
+    fn claim_vote_rewards() -> Weight;
+    fn set_proposer_limits() -> Weight;
 }
 
 /// Weights for daos_square using the Substrate node and recommended hardware.
@@ -32,6 +47,9 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare PublicPropCount (r:1 w:1)
             // Storage: DaoSquare PublicProps (r:1 w:1)
             // Storage: DaoSquare MaxPublicProps (r:1 w:0)
+            // Storage: DaoSquare ProposerLimitsOf (r:1 w:0)
+            // Storage: DaoSquare OpenProposalsOf (r:1 w:1)
+            // Storage: DaoSquare LastProposedAt (r:1 w:1)
             // Storage: DaoSquare DepositOf (r:0 w:1)
         fn propose() -> Weight {
             Weight::from_ref_time(20_0000_0000)
@@ -83,33 +101,149 @@ pub struct DaosWeight<T>(PhantomData<T>);
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare MaxPublicProps (r:0 w:1)
-        fn set_max_public_props() -> Weight {
+            // Storage: CreateDao Guilds (r:1 w:0)
+            // Storage: DaoSquare EmergencyGuildOf (r:0 w:1)
+        fn set_emergency_guild() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare EmergencyGuildOf (r:1 w:0)
+            // Storage: CreateDao GuildMembers (r:1 w:0)
+            // Storage: DaoSquare VotingPeriod (r:1 w:0)
+            // Storage: DaoSquare EnactmentPeriod (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare FastTrackOf (r:1 w:1)
+        fn fast_track() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare EmergencyGuildOf (r:1 w:0)
+            // Storage: CreateDao GuildMembers (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare VetoApprovalsOf (r:1 w:1)
+            // Storage: DaoSquare Blacklist (r:0 w:1)
+        fn veto() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare FastTrackOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:0)
+            // Storage: DaoSquare EmergencyGuildOf (r:1 w:0)
+            // Storage: CreateDao GuildMembers (r:1 w:0)
+        fn cancel_fast_track() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare GovConfigOf (r:1 w:1)
+        fn set_gov_config() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare VotesOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:32 w:0)
+            // Storage: DaoSquare ReapDepositOf (r:32 w:32)
+            // Storage: System Account (r:33 w:33)
+        fn reap_votes() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare OptimisticRuleOf (r:0 w:1)
+        fn set_optimistic_rule() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare OptimisticRuleOf (r:1 w:0)
+            // Storage: CreateDao Members (r:1 w:0)
+            // Storage: DaoSquare Blacklist (r:1 w:0)
+            // Storage: Tokens Accounts (r:1 w:1)
+            // Storage: DaoSquare OptimisticCount (r:1 w:1)
+            // Storage: DaoSquare OptimisticProposals (r:0 w:1)
+        fn submit_optimistic() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare OptimisticProposals (r:1 w:1)
+            // Storage: CreateDao Members (r:1 w:0)
+            // Storage: Tokens Accounts (r:1 w:1)
+            // Storage: DaoSquare OptimisticRuleOf (r:1 w:0)
+            // Storage: DaoSquare GovConfigOf (r:1 w:0)
+            // Storage: DaoSquare ReferendumCount (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:0 w:1)
+            // Storage: DaoSquare EscalatedOf (r:0 w:1)
+        fn object_optimistic() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare OptimisticProposals (r:1 w:1)
+            // Storage: Tokens Accounts (r:1 w:1)
+            // Storage: CreateDao Daos (r:1 w:0)
+        fn execute_optimistic() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare CommitRevealRuleOf (r:0 w:1)
+        fn set_commit_reveal_rule() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare CommitRevealOf (r:1 w:0)
+            // Storage: DaoSquare Commitments (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:0)
+            // Storage: CreateDao Members (r:1 w:0)
+            // Storage: DaoSquare GovConfigOf (r:1 w:0)
+            // Storage: Tokens Accounts (r:1 w:1)
+        fn commit_vote() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare CommitRevealOf (r:1 w:0)
+            // Storage: DaoSquare Commitments (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare VotesOf (r:1 w:1)
+        fn reveal_vote() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare CommitRevealOf (r:1 w:1)
+            // Storage: DaoSquare Commitments (r:2 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:2 w:0)
+            // Storage: Tokens Accounts (r:2 w:2)
+        fn clear_unrevealed() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:0)
+            // Storage: DaoSquare CommitRevealOf (r:1 w:0)
+            // Storage: DaoSquare BicameralOf (r:1 w:0)
+            // Storage: DaoSquare VotesOf (r:1 w:0)
+            // Storage: DaoSquare RageQuitOf (r:1 w:1)
+            // Storage: Tokens TotalIssuance (r:1 w:1)
+            // Storage: Tokens Accounts (r:9 w:1)
+            // Storage: System Account (r:2 w:2)
+        fn rage_quit() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare LaunchPeriod (r:0 w:1)
-        fn set_launch_period() -> Weight {
+            // Storage: DaoSquare TimePeriodsOf (r:0 w:1)
+        fn set_time_periods() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare MinimumDeposit (r:0 w:1)
-        fn set_minimum_deposit() -> Weight {
+            // Storage: DaoSquare BicameralRuleOf (r:0 w:1)
+        fn set_bicameral_rule() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare VotingPeriod (r:0 w:1)
-        fn set_voting_period() -> Weight {
+            // Storage: DaoSquare VoteRewardRuleOf (r:0 w:1)
+        fn set_vote_reward_rule() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare ReservePeriod (r:0 w:1)
-        fn set_rerserve_period() -> Weight {
+            // Storage: DaoAssets Metadata (r:1 w:0)
+            // Storage: Tokens Accounts (r:2 w:2)
+        fn fund_reward_pool() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare VotesOf (r:1 w:0)
+            // Storage: DaoSquare VoteRewardClaimed (r:10 w:10)
+            // Storage: DaoSquare VoteRewardPotOf (r:10 w:10)
+            // Storage: DaoSquare CommittedRewards (r:10 w:10)
+            // Storage: Tokens Accounts (r:11 w:11)
+        fn claim_vote_rewards() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare EnactmentPeriod (r:0 w:1)
-        fn set_runment_period() -> Weight {
+            // Storage: DaoSquare ProposerLimitsOf (r:0 w:1)
+        fn set_proposer_limits() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
     }
@@ -122,6 +256,9 @@ pub struct DaosWeight<T>(PhantomData<T>);
             // Storage: DaoSquare PublicPropCount (r:1 w:1)
             // Storage: DaoSquare PublicProps (r:1 w:1)
             // Storage: DaoSquare MaxPublicProps (r:1 w:0)
+            // Storage: DaoSquare ProposerLimitsOf (r:1 w:0)
+            // Storage: DaoSquare OpenProposalsOf (r:1 w:1)
+            // Storage: DaoSquare LastProposedAt (r:1 w:1)
             // Storage: DaoSquare DepositOf (r:0 w:1)
         fn propose() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
@@ -173,33 +310,149 @@ pub struct DaosWeight<T>(PhantomData<T>);
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare MaxPublicProps (r:0 w:1)
-        fn set_max_public_props() -> Weight {
+            // Storage: CreateDao Guilds (r:1 w:0)
+            // Storage: DaoSquare EmergencyGuildOf (r:0 w:1)
+        fn set_emergency_guild() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare EmergencyGuildOf (r:1 w:0)
+            // Storage: CreateDao GuildMembers (r:1 w:0)
+            // Storage: DaoSquare VotingPeriod (r:1 w:0)
+            // Storage: DaoSquare EnactmentPeriod (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare FastTrackOf (r:1 w:1)
+        fn fast_track() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare EmergencyGuildOf (r:1 w:0)
+            // Storage: CreateDao GuildMembers (r:1 w:0)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare VetoApprovalsOf (r:1 w:1)
+            // Storage: DaoSquare Blacklist (r:0 w:1)
+        fn veto() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare FastTrackOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:0)
+            // Storage: DaoSquare EmergencyGuildOf (r:1 w:0)
+            // Storage: CreateDao GuildMembers (r:1 w:0)
+        fn cancel_fast_track() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare LaunchPeriod (r:0 w:1)
-        fn set_launch_period() -> Weight {
+            // Storage: DaoSquare GovConfigOf (r:1 w:1)
+        fn set_gov_config() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare VotesOf (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:32 w:0)
+            // Storage: DaoSquare ReapDepositOf (r:32 w:32)
+            // Storage: System Account (r:33 w:33)
+        fn reap_votes() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare MinimumDeposit (r:0 w:1)
-        fn set_minimum_deposit() -> Weight {
+            // Storage: DaoSquare OptimisticRuleOf (r:0 w:1)
+        fn set_optimistic_rule() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare OptimisticRuleOf (r:1 w:0)
+            // Storage: CreateDao Members (r:1 w:0)
+            // Storage: DaoSquare Blacklist (r:1 w:0)
+            // Storage: Tokens Accounts (r:1 w:1)
+            // Storage: DaoSquare OptimisticCount (r:1 w:1)
+            // Storage: DaoSquare OptimisticProposals (r:0 w:1)
+        fn submit_optimistic() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
+            // Storage: DaoSquare OptimisticProposals (r:1 w:1)
+            // Storage: CreateDao Members (r:1 w:0)
+            // Storage: Tokens Accounts (r:1 w:1)
+            // Storage: DaoSquare OptimisticRuleOf (r:1 w:0)
+            // Storage: DaoSquare GovConfigOf (r:1 w:0)
+            // Storage: DaoSquare ReferendumCount (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:0 w:1)
+            // Storage: DaoSquare EscalatedOf (r:0 w:1)
+        fn object_optimistic() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare OptimisticProposals (r:1 w:1)
+            // Storage: Tokens Accounts (r:1 w:1)
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare VotingPeriod (r:0 w:1)
-        fn set_voting_period() -> Weight {
+        fn execute_optimistic() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare ReservePeriod (r:0 w:1)
-        fn set_rerserve_period() -> Weight {
+            // Storage: DaoSquare CommitRevealRuleOf (r:0 w:1)
+        fn set_commit_reveal_rule() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare CommitRevealOf (r:1 w:0)
+            // Storage: DaoSquare Commitments (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:0)
+            // Storage: CreateDao Members (r:1 w:0)
+            // Storage: DaoSquare GovConfigOf (r:1 w:0)
+            // Storage: Tokens Accounts (r:1 w:1)
+        fn commit_vote() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare CommitRevealOf (r:1 w:0)
+            // Storage: DaoSquare Commitments (r:1 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:1)
+            // Storage: DaoSquare VotesOf (r:1 w:1)
+        fn reveal_vote() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare CommitRevealOf (r:1 w:1)
+            // Storage: DaoSquare Commitments (r:2 w:1)
+            // Storage: DaoSquare ReferendumInfoOf (r:2 w:0)
+            // Storage: Tokens Accounts (r:2 w:2)
+        fn clear_unrevealed() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare ReferendumInfoOf (r:1 w:0)
+            // Storage: DaoSquare CommitRevealOf (r:1 w:0)
+            // Storage: DaoSquare BicameralOf (r:1 w:0)
+            // Storage: DaoSquare VotesOf (r:1 w:0)
+            // Storage: DaoSquare RageQuitOf (r:1 w:1)
+            // Storage: Tokens TotalIssuance (r:1 w:1)
+            // Storage: Tokens Accounts (r:9 w:1)
+            // Storage: System Account (r:2 w:2)
+        fn rage_quit() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
             // Storage: CreateDao Daos (r:1 w:0)
-            // Storage: DaoSquare EnactmentPeriod (r:0 w:1)
-        fn set_runment_period() -> Weight {
+            // Storage: DaoSquare TimePeriodsOf (r:0 w:1)
+        fn set_time_periods() -> Weight {
                 Weight::from_ref_time(20_0000_0000)
         }
-    }
\ No newline at end of file
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare BicameralRuleOf (r:0 w:1)
+        fn set_bicameral_rule() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare VoteRewardRuleOf (r:0 w:1)
+        fn set_vote_reward_rule() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoAssets Metadata (r:1 w:0)
+            // Storage: Tokens Accounts (r:2 w:2)
+        fn fund_reward_pool() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: DaoSquare VotesOf (r:1 w:0)
+            // Storage: DaoSquare VoteRewardClaimed (r:10 w:10)
+            // Storage: DaoSquare VoteRewardPotOf (r:10 w:10)
+            // Storage: DaoSquare CommittedRewards (r:10 w:10)
+            // Storage: Tokens Accounts (r:11 w:11)
+        fn claim_vote_rewards() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+            // Storage: CreateDao Daos (r:1 w:0)
+            // Storage: DaoSquare ProposerLimitsOf (r:0 w:1)
+        fn set_proposer_limits() -> Weight {
+                Weight::from_ref_time(20_0000_0000)
+        }
+    }
@@ -3,7 +3,9 @@ use super::*;
 
 use crate as daoent_guild;
 use daoent_gov::traits::PledgeTrait;
-use frame_support::{construct_runtime, parameter_types, traits::Contains, PalletId};
+use frame_support::{
+    construct_runtime, parameter_types, traits::Contains, weights::Weight, PalletId,
+};
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::{
@@ -86,20 +88,67 @@ impl PledgeTrait<u64, AccountId, u64, u64, DispatchError> for Vote {
         &self,
         _who: &AccountId,
         _dao_id: &u64,
+        _referendum_index: u32,
         _vote_model: u8,
     ) -> Result<(u64, u64), DispatchError> {
         Ok((100u64, 100u64))
     }
 
-    fn vote_end_do(&self, _who: &AccountId, _dao_id: &u64) -> Result<(), DispatchError> {
+    fn vote_end_do(
+        &self,
+        _who: &AccountId,
+        _dao_id: &u64,
+        _referendum_index: u32,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn slash_to(
+        &self,
+        _who: &AccountId,
+        _dao_id: &u64,
+        _referendum_index: u32,
+        _beneficiary: &AccountId,
+    ) -> Result<(), DispatchError> {
         Ok(())
     }
 }
 
+parameter_types! {
+    pub const MaxPublicPropsLimit: u32 = 100;
+    pub const MinVotingPeriod: BlockNumber = 10;
+    pub const MaxGovPeriod: BlockNumber = 100_000;
+    pub const ArchiveAge: BlockNumber = 100;
+    pub const MaxPrunePerBlock: u32 = 2;
+    pub const MaxReapVotes: u32 = 10;
+    pub const ReapReward: Balance = 1;
+    pub const VetoCoolOff: BlockNumber = 1000;
+    pub const MaxProposalWeight: Weight = Weight::from_ref_time(1_000_000_000_000);
+    pub const MillisecsPerBlock: u64 = 6000;
+}
+
+pub struct MockUnixTime;
+impl frame_support::traits::UnixTime for MockUnixTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::default()
+    }
+}
+
 impl daoent_gov::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Pledge = Vote;
     type WeightInfo = ();
+    type MaxPublicPropsLimit = MaxPublicPropsLimit;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxGovPeriod = MaxGovPeriod;
+    type ArchiveAge = ArchiveAge;
+    type MaxPrunePerBlock = MaxPrunePerBlock;
+    type MaxReapVotes = MaxReapVotes;
+    type ReapReward = ReapReward;
+    type VetoCoolOff = VetoCoolOff;
+    type UnixTime = MockUnixTime;
+    type MillisecsPerBlock = MillisecsPerBlock;
+    type MaxProposalWeight = MaxProposalWeight;
 }
 
 impl daoent_guild::Config for Test {
@@ -104,7 +104,8 @@ pub fn test_guild_join_request() {
         assert!(daoent_gov::Pallet::<Test>::run_proposal(
             RuntimeOrigin::signed(ALICE),
             dao_id,
-            0u32
+            0u32,
+            frame_support::weights::Weight::MAX
         )
         .is_err());
 
@@ -114,7 +115,8 @@ pub fn test_guild_join_request() {
         assert!(daoent_gov::Pallet::<Test>::run_proposal(
             RuntimeOrigin::signed(ALICE),
             dao_id,
-            0u32
+            0u32,
+            frame_support::weights::Weight::MAX
         )
         .is_ok());
 
@@ -14,7 +14,7 @@ use sp_std::result;
 use orml_traits::MultiCurrency;
 
 use daoent_dao::{self};
-use daoent_primitives::types::{DaoAssetId, ProjectId, TaskId};
+use daoent_primitives::types::{AssetId, DaoAssetId, ProjectId, TaskId};
 
 pub use pallet::*;
 
@@ -88,9 +88,9 @@ pub struct TaskInfo<AccountId, Balance, TaskStatus> {
     /// creator of DAO
     /// 创建者
     pub creator: AccountId,
-    /// rewards
+    /// rewards, in any asset owned by the DAO
     /// 奖金
-    pub rewards: Vec<(DaoAssetId, Balance)>,
+    pub rewards: Vec<(AssetId, Balance)>,
     // 最大协作数量
     pub max_assignee: u8,
     /// assignes info
@@ -344,10 +344,15 @@ pub mod pallet {
             skills: Option<Vec<u8>>,
             assignees: Option<Vec<T::AccountId>>,
             amount: BalanceOf<T>,
+            asset_id: Option<AssetId>,
         ) -> DispatchResultWithPostInfo {
             let me = ensure_signed(origin)?;
             Self::check_auth_for_project(dao_id, project_id, me.clone())?;
 
+            // 奖金默认使用 DAO 通证
+            let asset_id = asset_id.unwrap_or(dao_id);
+            daoent_assets::Pallet::<T>::ensure_dao_asset(dao_id, asset_id)?;
+
             // 初始化任务 id
             let task_id = NextTaskId::<T>::get();
             let mut task = TaskInfo {
@@ -358,7 +363,7 @@ pub mod pallet {
                 point,
                 priority,
                 creator: me.clone(),
-                rewards: [(dao_id, amount)].into(),
+                rewards: [(asset_id, amount)].into(),
                 max_assignee: 1,
                 assignees: [].into(),
                 reviewers: [].into(),
@@ -377,7 +382,7 @@ pub mod pallet {
 
             // 预备资金
             daoent_assets::Pallet::<T>::reserve(
-                dao_id,
+                asset_id,
                 daoent_dao::Pallet::<T>::dao_project(dao_id, project_id),
                 amount,
             )?;
@@ -657,19 +662,19 @@ pub mod pallet {
             );
 
             let project_account = daoent_dao::Pallet::<T>::dao_project(dao_id, task.project_id);
-            let total = task.rewards[0].1;
+            let (asset_id, total) = task.rewards[0];
             let total_u64: u64 = total.saturated_into::<u64>();
             let amount_u64 =
                 total_u64 / <usize as TryInto<u64>>::try_into(task.assignees.len()).unwrap();
             let amount: BalanceOf<T> = amount_u64.saturated_into();
 
             // 解锁预备资金
-            daoent_assets::Pallet::<T>::unreserve(dao_id, project_account.clone(), total)?;
+            daoent_assets::Pallet::<T>::unreserve(asset_id, project_account.clone(), total)?;
 
             // 为所有的贡献者转帐
             for assignee in task.assignees.iter() {
                 daoent_assets::Pallet::<T>::try_transfer(
-                    dao_id,
+                    asset_id,
                     project_account.clone(),
                     assignee.clone(),
                     amount,
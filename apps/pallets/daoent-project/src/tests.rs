@@ -105,18 +105,24 @@ pub fn project_join_reques() -> DaoAssetId {
     ));
 
     //
-    assert!(
-        daoent_gov::Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), dao_id, 0u32)
-            .is_err()
-    );
+    assert!(daoent_gov::Pallet::<Test>::run_proposal(
+        RuntimeOrigin::signed(ALICE),
+        dao_id,
+        0u32,
+        frame_support::weights::Weight::MAX
+    )
+    .is_err());
 
     frame_system::Pallet::<Test>::set_block_number(20000);
 
     // 运行代码
-    assert!(
-        daoent_gov::Pallet::<Test>::run_proposal(RuntimeOrigin::signed(ALICE), dao_id, 0u32)
-            .is_ok()
-    );
+    assert!(daoent_gov::Pallet::<Test>::run_proposal(
+        RuntimeOrigin::signed(ALICE),
+        dao_id,
+        0u32,
+        frame_support::weights::Weight::MAX
+    )
+    .is_ok());
 
     let ms = daoent_dao::ProjectMembers::<Test>::get(dao_id, PROJECT_INDEX);
     println!("项目成员 => {:?}", ms);
@@ -165,6 +171,7 @@ pub fn test_task() {
             Some(vec![1]),
             Some(vec![]),
             10,
+            None,
         )
         .unwrap();
 
@@ -21,6 +21,12 @@ pub type CallId = u32;
 /// 资源ID
 pub type DaoAssetId = u64;
 
+/// AssetId, an alias of `DaoAssetId` rather than a newtype: both index the same orml-tokens
+/// currency space, a DAO token uses the id of its DAO and additional assets are numbered from
+/// `MaxCreatableId` on.
+/// 资产ID, 是 DaoAssetId 的别名而非新类型, 两者共用 orml-tokens 的同一ID空间
+pub type AssetId = DaoAssetId;
+
 /// ProjectId
 /// 项目ID
 pub type ProjectId = u64;